@@ -0,0 +1,149 @@
+// crates/crane_core/src/bin/chart_lint.rs
+//
+// Lint load chart files from the command line:
+//
+//   cargo run -p crane_core --bin chart_lint -- [--spec <id>] [--library <dir>]
+//       [--max-gap <m>] [--format csv|json|table|matrix-csv|matrix-text]
+//       [--strict] [FILE]...
+//
+// `--spec` looks the crane up in the spec library (assets/cranes, or
+// `CRANE_SPEC_DIR`, unless `--library` is given). With no files the spec's own charts are checked.
+// Exits 1 when errors are found (or warnings with `--strict`), 2 on bad usage.
+
+use crane_core::{
+    ChartDiagnostic, ChartFormat, ChartValidator, CraneSpec, Severity, SpecRegistry,
+    ValidationOptions, default_spec_dir,
+};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+struct Args {
+    files: Vec<PathBuf>,
+    spec: Option<CraneSpec>,
+    format: Option<ChartFormat>,
+    options: ValidationOptions,
+    strict: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        files: Vec::new(),
+        spec: None,
+        format: None,
+        options: ValidationOptions::default(),
+        strict: false,
+    };
+
+    let mut spec_id = None;
+    let mut library = default_spec_dir();
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--spec" => {
+                spec_id = Some(iter.next().ok_or("--spec needs a crane id")?);
+            }
+            "--library" => {
+                library = PathBuf::from(iter.next().ok_or("--library needs a directory")?);
+            }
+            "--max-gap" => {
+                let gap = iter.next().ok_or("--max-gap needs a value in metres")?;
+                args.options.max_radius_gap_m =
+                    gap.parse().map_err(|_| format!("Invalid gap '{}'", gap))?;
+            }
+            "--format" => {
+                let format = iter.next().ok_or("--format needs a value")?;
+                args.format = Some(match format.as_str() {
+                    "csv" => ChartFormat::Csv,
+                    "json" => ChartFormat::Json,
+                    "table" => ChartFormat::Table,
+                    "matrix-csv" => ChartFormat::MatrixCsv,
+                    "matrix-text" => ChartFormat::MatrixText,
+                    other => return Err(format!("Unknown format '{}'", other)),
+                });
+            }
+            "--strict" => args.strict = true,
+            flag if flag.starts_with("--") => return Err(format!("Unknown option '{}'", flag)),
+            file => args.files.push(PathBuf::from(file)),
+        }
+    }
+
+    if let Some(id) = spec_id {
+        let registry = SpecRegistry::load(&library);
+        for issue in registry.issues() {
+            eprintln!("chart_lint: skipped {}", issue);
+        }
+        let spec = registry
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| format!("Unknown crane '{}' in {}", id, library.display()))?;
+        args.spec = Some(spec);
+    }
+
+    if args.files.is_empty() && args.spec.is_none() {
+        return Err("Nothing to check: give chart files and/or --spec <id>".to_string());
+    }
+    Ok(args)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("chart_lint: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let validator = ChartValidator::new(args.options);
+    let mut failures = 0;
+    let mut diagnostics: Vec<(String, ChartDiagnostic)> = Vec::new();
+
+    if args.files.is_empty()
+        && let Some(spec) = &args.spec
+    {
+        for diagnostic in validator.validate_spec(spec) {
+            diagnostics.push((spec.id.clone(), diagnostic));
+        }
+    }
+
+    for path in &args.files {
+        let name = path.display().to_string();
+        let data = match std::fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(e) => {
+                eprintln!("{}: {}", name, e);
+                failures += 1;
+                continue;
+            }
+        };
+
+        let format = args
+            .format
+            .unwrap_or_else(|| ChartFormat::detect(path, &data));
+        let report = validator.validate_source(&data, format, args.spec.as_ref());
+        println!("{}: {} charts read", name, report.charts.len());
+        for diagnostic in report.diagnostics {
+            diagnostics.push((name.clone(), diagnostic));
+        }
+    }
+
+    for (name, diagnostic) in &diagnostics {
+        println!("{}: {}", name, diagnostic);
+    }
+
+    let count = |severity| {
+        diagnostics
+            .iter()
+            .filter(|(_, d)| d.severity == severity)
+            .count()
+    };
+    let errors = count(Severity::Error) + failures;
+    let warnings = count(Severity::Warning);
+
+    println!("{} errors, {} warnings", errors, warnings);
+    if errors > 0 || (args.strict && warnings > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,246 @@
+// crates/crane_core/src/capacity_contour.rs
+
+use nalgebra::Point3;
+
+use crate::crane_data::CraneConfiguration;
+
+/// Sampling settings for a capacity contour
+#[derive(Debug, Clone)]
+pub struct ContourOptions {
+    /// Width of each swing sector
+    pub sector_step_deg: f32,
+    /// Boom angle (or trolley) sampling within a sector
+    pub angle_step_deg: f32,
+    pub trolley_step_m: f32,
+}
+
+impl Default for ContourOptions {
+    fn default() -> Self {
+        Self {
+            sector_step_deg: 5.0,
+            angle_step_deg: 0.5,
+            trolley_step_m: 0.25,
+        }
+    }
+}
+
+/// Radii at which the load can be placed within one swing sector
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContourSector {
+    /// Sector start and end, relative to the crane heading
+    pub start_deg: f32,
+    pub end_deg: f32,
+    /// Placeable radius band, None when the load is over capacity
+    /// everywhere in the sector
+    pub radius_m: Option<(f32, f32)>,
+}
+
+/// Where a load can be placed around the crane at its current configuration
+#[derive(Debug, Clone)]
+pub struct CapacityContour {
+    pub load_kg: f32,
+    /// Slew centre and heading the sectors are measured from
+    pub centre: Point3<f32>,
+    pub heading_deg: f32,
+    pub sectors: Vec<ContourSector>,
+}
+
+impl CapacityContour {
+    /// Sector covering a swing angle (relative to the heading)
+    pub fn sector_at(&self, swing_deg: f32) -> Option<&ContourSector> {
+        let swing = swing_deg.rem_euclid(360.0);
+        self.sectors
+            .iter()
+            .find(|s| swing >= s.start_deg && swing < s.end_deg)
+    }
+
+    /// Largest placement radius at a swing angle
+    pub fn max_radius_at(&self, swing_deg: f32) -> Option<f32> {
+        self.sector_at(swing_deg)?.radius_m.map(|(_, max)| max)
+    }
+
+    /// Whether the load can be set down at a world position
+    pub fn contains(&self, point: Point3<f32>) -> bool {
+        let offset = point - self.centre;
+        let radius = offset.x.hypot(offset.y);
+        let swing = offset.x.atan2(offset.y).to_degrees() - self.heading_deg;
+        self.sector_at(swing)
+            .and_then(|s| s.radius_m)
+            .is_some_and(|(min, max)| radius >= min && radius <= max)
+    }
+
+    /// Largest placement radius in any sector
+    pub fn max_radius(&self) -> f32 {
+        self.sectors
+            .iter()
+            .filter_map(|s| s.radius_m.map(|(_, max)| max))
+            .fold(0.0, f32::max)
+    }
+
+    /// World position on the ground at a swing angle and radius
+    pub fn ground_point(&self, swing_deg: f32, radius_m: f32) -> Point3<f32> {
+        let angle = (swing_deg + self.heading_deg).to_radians();
+        Point3::new(
+            self.centre.x + radius_m * angle.sin(),
+            self.centre.y + radius_m * angle.cos(),
+            self.centre.z,
+        )
+    }
+}
+
+/// Maps the placeable area for a load around a configured crane
+pub struct CapacityContourCalculator;
+
+impl CapacityContourCalculator {
+    /// Placeable radius band in each swing sector
+    ///
+    /// Boom length, jib, supports and counterweight stay as configured;
+    /// radius is varied by luffing (or trolley travel). Each sector takes
+    /// the more restrictive of its two edges, so over-side and over-rear
+    /// derating applies across the whole sector.
+    pub fn calculate(
+        config: &CraneConfiguration,
+        load_kg: f32,
+        options: &ContourOptions,
+    ) -> CapacityContour {
+        let step = options.sector_step_deg.clamp(0.5, 90.0);
+        let count = (360.0 / step).round() as usize;
+        let step = 360.0 / count as f32;
+
+        let sectors = (0..count)
+            .map(|i| {
+                let start_deg = i as f32 * step;
+                let end_deg = start_deg + step;
+                let start = Self::radius_band(config, load_kg, start_deg, options);
+                let end = Self::radius_band(config, load_kg, end_deg, options);
+                let radius_m = match (start, end) {
+                    (Some((min_a, max_a)), Some((min_b, max_b))) => {
+                        Some((min_a.max(min_b), max_a.min(max_b))).filter(|(min, max)| min <= max)
+                    }
+                    _ => None,
+                };
+                ContourSector {
+                    start_deg,
+                    end_deg,
+                    radius_m,
+                }
+            })
+            .collect();
+
+        CapacityContour {
+            load_kg,
+            centre: config.position,
+            heading_deg: config.heading_deg,
+            sectors,
+        }
+    }
+
+    /// Smallest and largest radius with capacity for the load at one slew
+    fn radius_band(
+        config: &CraneConfiguration,
+        load_kg: f32,
+        swing_deg: f32,
+        options: &ContourOptions,
+    ) -> Option<(f32, f32)> {
+        let mut sampled = config.clone();
+        sampled.swing_angle_deg = swing_deg;
+
+        let mut band: Option<(f32, f32)> = None;
+        let mut record = |sampled: &CraneConfiguration| {
+            let radius = sampled.get_radius();
+            if sampled
+                .get_capacity_at_radius(radius)
+                .is_some_and(|capacity| capacity >= load_kg)
+            {
+                band = Some(band.map_or((radius, radius), |(min, max)| {
+                    (min.min(radius), max.max(radius))
+                }));
+            }
+        };
+
+        match (&config.spec.tower, config.trolley_radius_m) {
+            (Some(tower), Some(_)) => {
+                let min = tower.min_trolley_radius_m;
+                let count = ((config.boom_length_m - min) / options.trolley_step_m.max(0.05))
+                    .ceil()
+                    .max(1.0) as usize;
+                for i in 0..=count {
+                    sampled.trolley_radius_m =
+                        Some(min + (config.boom_length_m - min) * i as f32 / count as f32);
+                    record(&sampled);
+                }
+            }
+            _ => {
+                let (min, max) = (
+                    config.spec.min_boom_angle_deg,
+                    config.spec.max_boom_angle_deg,
+                );
+                let count = ((max - min) / options.angle_step_deg.max(0.05))
+                    .ceil()
+                    .max(1.0) as usize;
+                for i in 0..=count {
+                    sampled.boom_angle_deg = min + (max - min) * i as f32 / count as f32;
+                    record(&sampled);
+                }
+            }
+        }
+        band
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{OutriggerPosition, test_crane};
+
+    #[test]
+    fn test_over_rear_derating_shrinks_sector() {
+        let mut config = test_crane(30.0, 60.0);
+        config.spec.capacity_chart.over_side_factor = 0.85;
+        config.spec.capacity_chart.over_rear_factor = 0.6;
+        let contour = CapacityContourCalculator::calculate(&config, 15_000.0, &Default::default());
+
+        assert_eq!(contour.sectors.len(), 72);
+        let front = contour.max_radius_at(10.0).unwrap();
+        let side = contour.max_radius_at(90.0).unwrap();
+        let rear = contour.max_radius_at(180.0).unwrap();
+        assert!(front > side, "front {} side {}", front, side);
+        assert!(side > rear, "side {} rear {}", side, rear);
+        assert_eq!(contour.max_radius(), front);
+    }
+
+    #[test]
+    fn test_contour_follows_outriggers_and_load() {
+        let full = test_crane(30.0, 60.0);
+        let mut short_right = test_crane(30.0, 60.0);
+        for leg in [OutriggerPosition::FrontRight, OutriggerPosition::RearRight] {
+            short_right.outriggers.set_extension_pct(leg, 60.0).unwrap();
+        }
+
+        let options = ContourOptions::default();
+        let full_span = CapacityContourCalculator::calculate(&full, 15_000.0, &options);
+        let short = CapacityContourCalculator::calculate(&short_right, 15_000.0, &options);
+        let right = |c: &CapacityContour| c.max_radius_at(90.0).unwrap_or(0.0);
+        let left = |c: &CapacityContour| c.max_radius_at(270.0).unwrap_or(0.0);
+        assert!(right(&short) < right(&full_span));
+        assert!((left(&short) - left(&full_span)).abs() < 0.5);
+
+        let too_heavy = CapacityContourCalculator::calculate(&full, 500_000.0, &options);
+        assert!(too_heavy.sectors.iter().all(|s| s.radius_m.is_none()));
+    }
+
+    #[test]
+    fn test_contains_world_point() {
+        let mut config = test_crane(30.0, 60.0);
+        config.position = Point3::new(100.0, 50.0, 2.0);
+        config.heading_deg = 90.0;
+        let contour = CapacityContourCalculator::calculate(&config, 10_000.0, &Default::default());
+
+        let (min, max) = contour.sector_at(2.0).unwrap().radius_m.unwrap();
+        let inside = contour.ground_point(2.0, (min + max) / 2.0);
+        // Heading 90° puts swing 0 along +X
+        assert!(inside.x > config.position.x + min - 0.1);
+        assert!(contour.contains(inside));
+        assert!(!contour.contains(contour.ground_point(2.0, max + 1.0)));
+    }
+}
@@ -0,0 +1,908 @@
+// crates/crane_core/src/collision.rs
+
+use crate::crane_data::{CraneConfiguration, CraneState};
+use crate::placement::SiteObstacle;
+use nalgebra::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+
+const GJK_MAX_ITERATIONS: usize = 64;
+const GJK_TOLERANCE: f32 = 1e-5;
+
+/// Convex collision primitive in world (or load-local) coordinates
+#[derive(Debug, Clone)]
+pub enum CollisionShape {
+    /// Oriented box
+    Box {
+        center: Point3<f32>,
+        half_extents: Vector3<f32>,
+        rotation: UnitQuaternion<f32>,
+    },
+    /// Segment swept by a sphere (booms, ropes, slings)
+    Capsule {
+        a: Point3<f32>,
+        b: Point3<f32>,
+        radius: f32,
+    },
+    Sphere {
+        center: Point3<f32>,
+        radius: f32,
+    },
+    /// Single mesh face
+    Triangle([Point3<f32>; 3]),
+}
+
+impl CollisionShape {
+    /// Axis-aligned box, optionally turned about the vertical
+    pub fn aligned_box(center: Point3<f32>, dimensions: Vector3<f32>, yaw_deg: f32) -> Self {
+        Self::Box {
+            center,
+            half_extents: dimensions / 2.0,
+            rotation: UnitQuaternion::from_axis_angle(&Vector3::z_axis(), yaw_deg.to_radians()),
+        }
+    }
+
+    /// Support point of the shape's core (without the rounding radius)
+    fn support(&self, direction: &Vector3<f32>) -> Point3<f32> {
+        match self {
+            Self::Box {
+                center,
+                half_extents,
+                rotation,
+            } => {
+                let local = rotation.inverse_transform_vector(direction);
+                let corner = Vector3::new(
+                    half_extents.x.copysign(local.x),
+                    half_extents.y.copysign(local.y),
+                    half_extents.z.copysign(local.z),
+                );
+                center + rotation.transform_vector(&corner)
+            }
+            Self::Capsule { a, b, .. } => {
+                if a.coords.dot(direction) >= b.coords.dot(direction) {
+                    *a
+                } else {
+                    *b
+                }
+            }
+            Self::Sphere { center, .. } => *center,
+            Self::Triangle(vertices) => *vertices
+                .iter()
+                .max_by(|p, q| p.coords.dot(direction).total_cmp(&q.coords.dot(direction)))
+                .unwrap(),
+        }
+    }
+
+    /// Rounding radius added around the core
+    fn margin(&self) -> f32 {
+        match self {
+            Self::Capsule { radius, .. } | Self::Sphere { radius, .. } => *radius,
+            _ => 0.0,
+        }
+    }
+
+    /// A point inside the shape
+    fn center(&self) -> Point3<f32> {
+        match self {
+            Self::Box { center, .. } | Self::Sphere { center, .. } => *center,
+            Self::Capsule { a, b, .. } => nalgebra::center(a, b),
+            Self::Triangle([a, b, c]) => Point3::from((a.coords + b.coords + c.coords) / 3.0),
+        }
+    }
+
+    /// World-aligned bounds
+    pub fn aabb(&self) -> Aabb {
+        match self {
+            Self::Box {
+                center,
+                half_extents,
+                rotation,
+            } => {
+                let m = rotation.to_rotation_matrix().into_inner().abs();
+                let extent = m * half_extents;
+                Aabb {
+                    min: center - extent,
+                    max: center + extent,
+                }
+            }
+            Self::Capsule { a, b, radius } => Aabb {
+                min: a.inf(b) - Vector3::repeat(*radius),
+                max: a.sup(b) + Vector3::repeat(*radius),
+            },
+            Self::Sphere { center, radius } => Aabb {
+                min: center - Vector3::repeat(*radius),
+                max: center + Vector3::repeat(*radius),
+            },
+            Self::Triangle([a, b, c]) => Aabb {
+                min: a.inf(b).inf(c),
+                max: a.sup(b).sup(c),
+            },
+        }
+    }
+
+    /// Shape moved by a rigid transform
+    pub fn transformed(&self, iso: &Isometry3<f32>) -> Self {
+        match self {
+            Self::Box {
+                center,
+                half_extents,
+                rotation,
+            } => Self::Box {
+                center: iso * center,
+                half_extents: *half_extents,
+                rotation: iso.rotation * rotation,
+            },
+            Self::Capsule { a, b, radius } => Self::Capsule {
+                a: iso * a,
+                b: iso * b,
+                radius: *radius,
+            },
+            Self::Sphere { center, radius } => Self::Sphere {
+                center: iso * center,
+                radius: *radius,
+            },
+            Self::Triangle(vertices) => Self::Triangle(vertices.map(|v| iso * v)),
+        }
+    }
+
+    /// Separation between two shapes (zero or negative when they touch)
+    pub fn distance(&self, other: &CollisionShape) -> f32 {
+        gjk_distance(self, other) - self.margin() - other.margin()
+    }
+}
+
+/// Axis-aligned bounding box
+#[derive(Debug, Clone, Copy)]
+pub struct Aabb {
+    pub min: Point3<f32>,
+    pub max: Point3<f32>,
+}
+
+impl Aabb {
+    pub fn merged(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.inf(&other.min),
+            max: self.max.sup(&other.max),
+        }
+    }
+
+    /// Lower bound on the distance between anything inside the two boxes
+    pub fn distance(&self, other: &Aabb) -> f32 {
+        let gap = (other.min - self.max)
+            .sup(&(self.min - other.max))
+            .sup(&Vector3::zeros());
+        gap.norm()
+    }
+}
+
+/// Site obstacle made of convex pieces (a box, or mesh triangles)
+#[derive(Debug, Clone)]
+pub struct CollisionObstacle {
+    pub name: String,
+    pub shapes: Vec<CollisionShape>,
+}
+
+impl CollisionObstacle {
+    pub fn from_box(
+        name: &str,
+        center: Point3<f32>,
+        dimensions: Vector3<f32>,
+        yaw_deg: f32,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            shapes: vec![CollisionShape::aligned_box(center, dimensions, yaw_deg)],
+        }
+    }
+
+    /// Triangle mesh (e.g. an imported building or pipe rack)
+    pub fn from_mesh(name: &str, vertices: &[Point3<f32>], triangles: &[[usize; 3]]) -> Self {
+        Self {
+            name: name.to_string(),
+            shapes: triangles
+                .iter()
+                .map(|t| CollisionShape::Triangle(t.map(|i| vertices[i])))
+                .collect(),
+        }
+    }
+
+    pub fn aabb(&self) -> Option<Aabb> {
+        self.shapes
+            .iter()
+            .map(|s| s.aabb())
+            .reduce(|a, b| a.merged(&b))
+    }
+}
+
+impl From<&SiteObstacle> for CollisionObstacle {
+    fn from(obstacle: &SiteObstacle) -> Self {
+        Self::from_box(&obstacle.name, obstacle.position, obstacle.dimensions, 0.0)
+    }
+}
+
+/// Load hanging on the hook
+#[derive(Debug, Clone)]
+pub struct CollisionLoad {
+    /// Load geometry, origin at the bottom centre of the load
+    pub shapes: Vec<CollisionShape>,
+    /// Height of the top of the load above its origin
+    pub height_m: f32,
+    /// Hook to top of load
+    pub rigging_height_m: f32,
+    /// Sling attachment points in load coordinates
+    pub attachment_points: Vec<Point3<f32>>,
+    /// Pendulum sway allowance; the load is checked swung this far each way
+    pub sway_angle_deg: f32,
+}
+
+impl CollisionLoad {
+    /// Box load slung from its four top corners
+    pub fn from_box(dimensions: Vector3<f32>, rigging_height_m: f32) -> Self {
+        let (hx, hy, h) = (dimensions.x / 2.0, dimensions.y / 2.0, dimensions.z);
+        Self {
+            shapes: vec![CollisionShape::aligned_box(
+                Point3::new(0.0, 0.0, h / 2.0),
+                dimensions,
+                0.0,
+            )],
+            height_m: h,
+            rigging_height_m,
+            attachment_points: vec![
+                Point3::new(-hx, -hy, h),
+                Point3::new(hx, -hy, h),
+                Point3::new(hx, hy, h),
+                Point3::new(-hx, hy, h),
+            ],
+            sway_angle_deg: 0.0,
+        }
+    }
+
+    /// Load poses to check: hanging plumb, plus swung each way when sway is allowed
+    fn poses(&self, hook: Point3<f32>, yaw_deg: f32) -> Vec<Isometry3<f32>> {
+        let drop = Translation3::new(0.0, 0.0, -(self.rigging_height_m + self.height_m));
+        let yaw = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), yaw_deg.to_radians());
+        let hanging = Isometry3::from_parts(drop, UnitQuaternion::identity())
+            * Isometry3::from_parts(Translation3::identity(), yaw);
+        let at_hook =
+            Isometry3::from_parts(Translation3::from(hook.coords), UnitQuaternion::identity());
+
+        let mut poses = vec![at_hook * hanging];
+        if self.sway_angle_deg > 0.0 {
+            let angle = self.sway_angle_deg.to_radians();
+            for axis in [
+                Vector3::x_axis(),
+                Vector3::y_axis(),
+                -Vector3::x_axis(),
+                -Vector3::y_axis(),
+            ] {
+                let sway = Isometry3::from_parts(
+                    Translation3::identity(),
+                    UnitQuaternion::from_axis_angle(&axis, angle),
+                );
+                poses.push(at_hook * sway * hanging);
+            }
+        }
+        poses
+    }
+}
+
+/// Crane part that can strike an obstacle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollisionPart {
+    Boom,
+    Jib,
+    HoistRope,
+    Rigging,
+    Load,
+}
+
+impl std::fmt::Display for CollisionPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boom => write!(f, "Boom"),
+            Self::Jib => write!(f, "Jib"),
+            Self::HoistRope => write!(f, "Hoist rope"),
+            Self::Rigging => write!(f, "Rigging"),
+            Self::Load => write!(f, "Load"),
+        }
+    }
+}
+
+/// Crane member sizes and check settings
+#[derive(Debug, Clone)]
+pub struct CollisionOptions {
+    /// Half-width of the boom modelled as a capsule
+    pub boom_radius_m: f32,
+    pub jib_radius_m: f32,
+    pub rope_radius_m: f32,
+    pub sling_radius_m: f32,
+    /// Clearance at or below which a contact is reported as a collision
+    pub required_clearance_m: f32,
+    /// Largest boom tip or hook movement between checked states
+    pub resolution_m: f32,
+}
+
+impl Default for CollisionOptions {
+    fn default() -> Self {
+        Self {
+            boom_radius_m: 0.6,
+            jib_radius_m: 0.3,
+            rope_radius_m: 0.05,
+            sling_radius_m: 0.05,
+            required_clearance_m: 0.0,
+            resolution_m: 0.25,
+        }
+    }
+}
+
+/// One state along a motion
+#[derive(Debug, Clone, Copy)]
+pub struct MotionSample {
+    pub time_s: f32,
+    pub state: CraneState,
+    /// Load rotation about the vertical (tag lines)
+    pub load_yaw_deg: f32,
+}
+
+/// Part and obstacle within the required clearance
+#[derive(Debug, Clone)]
+pub struct Contact {
+    pub part: CollisionPart,
+    pub obstacle: String,
+    pub clearance_m: f32,
+}
+
+/// First moment anything collides
+#[derive(Debug, Clone)]
+pub struct CollisionEvent {
+    pub time_s: f32,
+    pub contacts: Vec<Contact>,
+}
+
+/// Closest approach to one obstacle over the motion
+#[derive(Debug, Clone)]
+pub struct ObstacleClearance {
+    pub obstacle: String,
+    pub min_clearance_m: f32,
+    /// Part that came closest
+    pub part: CollisionPart,
+    pub time_s: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollisionReport {
+    pub first_collision: Option<CollisionEvent>,
+    pub clearances: Vec<ObstacleClearance>,
+    pub is_clear: bool,
+}
+
+pub struct CollisionChecker;
+
+impl CollisionChecker {
+    /// Collision geometry of the crane in its current state
+    pub fn crane_parts(
+        config: &CraneConfiguration,
+        load: Option<&CollisionLoad>,
+        load_yaw_deg: f32,
+        options: &CollisionOptions,
+    ) -> Vec<(CollisionPart, CollisionShape)> {
+        let pivot = config.position + Vector3::new(0.0, 0.0, config.spec.boom_pivot_height_m);
+        let boom_tip = config.get_boom_tip_position();
+        let sheave = config.get_sheave_position();
+        let hook = config.get_hook_position();
+
+        let mut parts = vec![(
+            CollisionPart::Boom,
+            CollisionShape::Capsule {
+                a: pivot,
+                b: boom_tip,
+                radius: options.boom_radius_m,
+            },
+        )];
+
+        if let Some(jib_tip) = config.get_jib_tip_position() {
+            parts.push((
+                CollisionPart::Jib,
+                CollisionShape::Capsule {
+                    a: boom_tip,
+                    b: jib_tip,
+                    radius: options.jib_radius_m,
+                },
+            ));
+        }
+
+        parts.push((
+            CollisionPart::HoistRope,
+            CollisionShape::Capsule {
+                a: sheave,
+                b: hook,
+                radius: options.rope_radius_m,
+            },
+        ));
+
+        if let Some(load) = load {
+            for pose in load.poses(hook, load_yaw_deg) {
+                for point in &load.attachment_points {
+                    parts.push((
+                        CollisionPart::Rigging,
+                        CollisionShape::Capsule {
+                            a: hook,
+                            b: pose * point,
+                            radius: options.sling_radius_m,
+                        },
+                    ));
+                }
+                for shape in &load.shapes {
+                    parts.push((CollisionPart::Load, shape.transformed(&pose)));
+                }
+            }
+        }
+
+        parts
+    }
+
+    /// Closest part to each obstacle in the configuration's current state
+    pub fn check_state(
+        config: &CraneConfiguration,
+        load: Option<&CollisionLoad>,
+        load_yaw_deg: f32,
+        obstacles: &[CollisionObstacle],
+        options: &CollisionOptions,
+    ) -> Vec<(CollisionPart, f32)> {
+        let parts = Self::crane_parts(config, load, load_yaw_deg, options);
+
+        obstacles
+            .iter()
+            .map(|obstacle| Self::closest_part(&parts, obstacle))
+            .collect()
+    }
+
+    /// Check a motion sampled finely enough that nothing moves more than
+    /// `options.resolution_m` between checks
+    pub fn check_motion(
+        config: &CraneConfiguration,
+        motion: &[MotionSample],
+        load: Option<&CollisionLoad>,
+        obstacles: &[CollisionObstacle],
+        options: &CollisionOptions,
+    ) -> CollisionReport {
+        let mut clearances: Vec<ObstacleClearance> = obstacles
+            .iter()
+            .map(|o| ObstacleClearance {
+                obstacle: o.name.clone(),
+                min_clearance_m: f32::INFINITY,
+                part: CollisionPart::Boom,
+                time_s: 0.0,
+            })
+            .collect();
+        let mut first_collision: Option<CollisionEvent> = None;
+        let mut posed = config.clone();
+
+        for sample in Self::refine(config, motion, options.resolution_m) {
+            posed.apply_state(&sample.state);
+            let parts = Self::crane_parts(&posed, load, sample.load_yaw_deg, options);
+
+            let mut contacts = Vec::new();
+            for (obstacle, clearance) in obstacles.iter().zip(clearances.iter_mut()) {
+                let (part, distance) = Self::closest_part(&parts, obstacle);
+
+                if distance < clearance.min_clearance_m {
+                    clearance.min_clearance_m = distance;
+                    clearance.part = part;
+                    clearance.time_s = sample.time_s;
+                }
+
+                if first_collision.is_none() {
+                    contacts.extend(Self::contacts(
+                        &parts,
+                        obstacle,
+                        options.required_clearance_m,
+                    ));
+                }
+            }
+
+            if first_collision.is_none() && !contacts.is_empty() {
+                first_collision = Some(CollisionEvent {
+                    time_s: sample.time_s,
+                    contacts,
+                });
+            }
+        }
+
+        CollisionReport {
+            is_clear: first_collision.is_none(),
+            first_collision,
+            clearances,
+        }
+    }
+
+    /// Insert interpolated samples so boom tip and hook steps stay under `resolution_m`
+    fn refine(
+        config: &CraneConfiguration,
+        motion: &[MotionSample],
+        resolution_m: f32,
+    ) -> Vec<MotionSample> {
+        let Some(first) = motion.first() else {
+            return Vec::new();
+        };
+
+        let mut posed = config.clone();
+        let mut markers = |state: &CraneState| {
+            posed.apply_state(state);
+            (posed.get_sheave_position(), posed.get_hook_position())
+        };
+
+        let mut samples = vec![*first];
+        let mut previous = markers(&first.state);
+
+        for pair in motion.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+            let next = markers(&to.state);
+            let travel = (next.0 - previous.0)
+                .norm()
+                .max((next.1 - previous.1).norm());
+            let steps = (travel / resolution_m.max(0.01)).ceil().max(1.0) as usize;
+
+            for step in 1..=steps {
+                let t = step as f32 / steps as f32;
+                samples.push(MotionSample {
+                    time_s: from.time_s + (to.time_s - from.time_s) * t,
+                    state: from.state.lerp(&to.state, t),
+                    load_yaw_deg: from.load_yaw_deg + (to.load_yaw_deg - from.load_yaw_deg) * t,
+                });
+            }
+            previous = next;
+        }
+
+        samples
+    }
+
+    fn closest_part(
+        parts: &[(CollisionPart, CollisionShape)],
+        obstacle: &CollisionObstacle,
+    ) -> (CollisionPart, f32) {
+        let Some(bounds) = obstacle.aabb() else {
+            return (CollisionPart::Boom, f32::INFINITY);
+        };
+
+        let mut best = (CollisionPart::Boom, f32::INFINITY);
+        for (part, shape) in parts {
+            let part_bounds = shape.aabb();
+            if part_bounds.distance(&bounds) >= best.1 {
+                continue;
+            }
+            for piece in &obstacle.shapes {
+                if part_bounds.distance(&piece.aabb()) >= best.1 {
+                    continue;
+                }
+                let distance = shape.distance(piece);
+                if distance < best.1 {
+                    best = (*part, distance);
+                }
+            }
+        }
+        best
+    }
+
+    /// Closest approach of every part within `required_clearance_m` of the obstacle
+    fn contacts(
+        parts: &[(CollisionPart, CollisionShape)],
+        obstacle: &CollisionObstacle,
+        required_clearance_m: f32,
+    ) -> Vec<Contact> {
+        let mut contacts: Vec<Contact> = Vec::new();
+
+        for (part, shape) in parts {
+            let part_bounds = shape.aabb();
+            for piece in &obstacle.shapes {
+                if part_bounds.distance(&piece.aabb()) > required_clearance_m {
+                    continue;
+                }
+                let distance = shape.distance(piece);
+                if distance > required_clearance_m {
+                    continue;
+                }
+
+                match contacts.iter_mut().find(|c| c.part == *part) {
+                    Some(contact) => contact.clearance_m = contact.clearance_m.min(distance),
+                    None => contacts.push(Contact {
+                        part: *part,
+                        obstacle: obstacle.name.clone(),
+                        clearance_m: distance,
+                    }),
+                }
+            }
+        }
+
+        contacts
+    }
+}
+
+/// Distance between the cores of two convex shapes (GJK)
+fn gjk_distance(a: &CollisionShape, b: &CollisionShape) -> f32 {
+    let support = |d: &Vector3<f32>| a.support(d) - b.support(&-d);
+
+    let mut v = a.center() - b.center();
+    if v.norm_squared() < f32::EPSILON {
+        v = Vector3::x();
+    }
+    v = support(&v);
+    let mut simplex = vec![v];
+
+    for _ in 0..GJK_MAX_ITERATIONS {
+        let vv = v.norm_squared();
+        if vv < GJK_TOLERANCE * GJK_TOLERANCE {
+            return 0.0;
+        }
+
+        let w = support(&-v);
+        if vv - v.dot(&w) <= GJK_TOLERANCE * vv.max(1.0) {
+            break;
+        }
+
+        simplex.push(w);
+        let (closest, reduced) = closest_on_simplex(&simplex);
+        v = closest;
+        simplex = reduced;
+
+        if simplex.len() == 4 {
+            // Origin enclosed by the Minkowski difference
+            return 0.0;
+        }
+    }
+
+    v.norm()
+}
+
+/// Closest point to the origin on a simplex, with the vertices that support it
+fn closest_on_simplex(simplex: &[Vector3<f32>]) -> (Vector3<f32>, Vec<Vector3<f32>>) {
+    match *simplex {
+        [a] => (a, vec![a]),
+        [a, b] => closest_on_segment(a, b),
+        [a, b, c] => closest_on_triangle(a, b, c),
+        [a, b, c, d] => closest_on_tetrahedron(a, b, c, d),
+        _ => unreachable!("simplex has at most four vertices"),
+    }
+}
+
+fn closest_on_segment(a: Vector3<f32>, b: Vector3<f32>) -> (Vector3<f32>, Vec<Vector3<f32>>) {
+    let ab = b - a;
+    let denom = ab.norm_squared();
+    if denom < f32::EPSILON {
+        return (a, vec![a]);
+    }
+
+    let t = -a.dot(&ab) / denom;
+    if t <= 0.0 {
+        (a, vec![a])
+    } else if t >= 1.0 {
+        (b, vec![b])
+    } else {
+        (a + ab * t, vec![a, b])
+    }
+}
+
+/// Voronoi-region closest point on a triangle (Ericson, Real-Time Collision Detection 5.1.5)
+fn closest_on_triangle(
+    a: Vector3<f32>,
+    b: Vector3<f32>,
+    c: Vector3<f32>,
+) -> (Vector3<f32>, Vec<Vector3<f32>>) {
+    let ab = b - a;
+    let ac = c - a;
+
+    let ap = -a;
+    let d1 = ab.dot(&ap);
+    let d2 = ac.dot(&ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return (a, vec![a]);
+    }
+
+    let bp = -b;
+    let d3 = ab.dot(&bp);
+    let d4 = ac.dot(&bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return (b, vec![b]);
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return (a + ab * v, vec![a, b]);
+    }
+
+    let cp = -c;
+    let d5 = ab.dot(&cp);
+    let d6 = ac.dot(&cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return (c, vec![c]);
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return (a + ac * w, vec![a, c]);
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return (b + (c - b) * w, vec![b, c]);
+    }
+
+    let denom = va + vb + vc;
+    if denom.abs() < f32::EPSILON {
+        // Degenerate triangle: fall back to its longest edge
+        return closest_on_segment(a, b);
+    }
+    let v = vb / denom;
+    let w = vc / denom;
+    (a + ab * v + ac * w, vec![a, b, c])
+}
+
+fn closest_on_tetrahedron(
+    a: Vector3<f32>,
+    b: Vector3<f32>,
+    c: Vector3<f32>,
+    d: Vector3<f32>,
+) -> (Vector3<f32>, Vec<Vector3<f32>>) {
+    // Origin outside the face (p, q, r) opposite `s`
+    let outside = |p: Vector3<f32>, q: Vector3<f32>, r: Vector3<f32>, s: Vector3<f32>| {
+        let normal = (q - p).cross(&(r - p));
+        let sign_origin = (-p).dot(&normal);
+        let sign_opposite = (s - p).dot(&normal);
+        sign_origin * sign_opposite < 0.0 || sign_opposite.abs() < f32::EPSILON
+    };
+
+    let faces = [(a, b, c, d), (a, c, d, b), (a, d, b, c), (b, d, c, a)];
+    let mut best: Option<(Vector3<f32>, Vec<Vector3<f32>>)> = None;
+
+    for (p, q, r, s) in faces {
+        if !outside(p, q, r, s) {
+            continue;
+        }
+        let candidate = closest_on_triangle(p, q, r);
+        if best
+            .as_ref()
+            .is_none_or(|(v, _)| candidate.0.norm_squared() < v.norm_squared())
+        {
+            best = Some(candidate);
+        }
+    }
+
+    best.unwrap_or((Vector3::zeros(), vec![a, b, c, d]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::test_crane;
+
+    #[test]
+    fn test_shape_distances() {
+        let unit_box = CollisionShape::aligned_box(Point3::origin(), Vector3::repeat(2.0), 0.0);
+        let sphere = CollisionShape::Sphere {
+            center: Point3::new(4.0, 0.0, 0.0),
+            radius: 1.0,
+        };
+        assert!((unit_box.distance(&sphere) - 2.0).abs() < 1e-3);
+
+        // Box turned 45°: corner now reaches sqrt(2) along x
+        let turned = CollisionShape::aligned_box(Point3::origin(), Vector3::repeat(2.0), 45.0);
+        assert!((turned.distance(&sphere) - (3.0 - 2f32.sqrt())).abs() < 1e-3);
+
+        let capsule = CollisionShape::Capsule {
+            a: Point3::new(-5.0, 3.0, 0.0),
+            b: Point3::new(5.0, 3.0, 0.0),
+            radius: 0.5,
+        };
+        assert!((unit_box.distance(&capsule) - 1.5).abs() < 1e-3);
+
+        let overlapping =
+            CollisionShape::aligned_box(Point3::new(1.5, 0.0, 0.0), Vector3::repeat(2.0), 0.0);
+        assert!(unit_box.distance(&overlapping) <= 0.0);
+
+        let triangle = CollisionShape::Triangle([
+            Point3::new(-10.0, -10.0, 3.0),
+            Point3::new(10.0, -10.0, 3.0),
+            Point3::new(0.0, 10.0, 3.0),
+        ]);
+        assert!((unit_box.distance(&triangle) - 2.0).abs() < 1e-3);
+    }
+
+    fn swing_motion(config: &CraneConfiguration, from_deg: f32, to_deg: f32) -> Vec<MotionSample> {
+        let mut state = CraneState::from(config);
+        state.swing_angle_deg = from_deg;
+        let start = MotionSample {
+            time_s: 0.0,
+            state,
+            load_yaw_deg: 0.0,
+        };
+        state.swing_angle_deg = to_deg;
+        let end = MotionSample {
+            time_s: 60.0,
+            state,
+            load_yaw_deg: 0.0,
+        };
+        vec![start, end]
+    }
+
+    fn crane() -> CraneConfiguration {
+        let mut config = test_crane(30.0, 60.0);
+        config.hoist_length_m = 20.0;
+        config
+    }
+
+    #[test]
+    fn test_swing_hits_load_against_wall() {
+        let config = crane();
+        let load = CollisionLoad::from_box(Vector3::new(3.0, 2.0, 2.0), 2.0);
+
+        // Wall 15m out to the right, reaching up past the hanging load
+        let wall = CollisionObstacle::from_box(
+            "Wall",
+            Point3::new(15.0, 0.0, 7.0),
+            Vector3::new(4.0, 1.0, 14.0),
+            0.0,
+        );
+
+        let report = CollisionChecker::check_motion(
+            &config,
+            &swing_motion(&config, 0.0, 180.0),
+            Some(&load),
+            std::slice::from_ref(&wall),
+            &CollisionOptions::default(),
+        );
+
+        let event = report.first_collision.expect("load should hit the wall");
+        assert!(event.time_s > 0.0 && event.time_s < 30.0);
+        assert!(event.contacts.iter().any(|c| c.part == CollisionPart::Load));
+        assert!(report.clearances[0].min_clearance_m <= 0.0);
+    }
+
+    #[test]
+    fn test_boom_clears_low_obstacle_and_reports_clearance() {
+        let config = crane();
+
+        // Low shed under the boom, well below the hook
+        let shed = CollisionObstacle::from_box(
+            "Shed",
+            Point3::new(0.0, 8.0, 1.0),
+            Vector3::new(4.0, 4.0, 2.0),
+            0.0,
+        );
+
+        let report = CollisionChecker::check_motion(
+            &config,
+            &swing_motion(&config, -20.0, 20.0),
+            None,
+            std::slice::from_ref(&shed),
+            &CollisionOptions::default(),
+        );
+
+        assert!(report.is_clear);
+        let clearance = &report.clearances[0];
+        assert!(clearance.min_clearance_m > 0.0 && clearance.min_clearance_m.is_finite());
+    }
+
+    #[test]
+    fn test_mesh_obstacle() {
+        let config = crane();
+
+        // Vertical wall of two triangles crossing the boom line
+        let vertices = [
+            Point3::new(-5.0, 10.0, 0.0),
+            Point3::new(5.0, 10.0, 0.0),
+            Point3::new(5.0, 10.0, 40.0),
+            Point3::new(-5.0, 10.0, 40.0),
+        ];
+        let wall = CollisionObstacle::from_mesh("Facade", &vertices, &[[0, 1, 2], [0, 2, 3]]);
+
+        let hits = CollisionChecker::check_state(
+            &config,
+            None,
+            0.0,
+            &[wall],
+            &CollisionOptions::default(),
+        );
+        assert!(hits[0].1 <= 0.0);
+    }
+}
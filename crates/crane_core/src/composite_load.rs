@@ -0,0 +1,390 @@
+// crates/crane_core/src/composite_load.rs
+
+use crate::rigging::{Load, PickPoint};
+use nalgebra::{Point3, Vector3};
+use serde::{Deserialize, Serialize};
+
+/// Axis a cylindrical part is aligned with (load coordinates)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CylinderAxis {
+    X, // Along load length
+    Y, // Along load width
+    Z, // Vertical (standing vessel)
+}
+
+/// Geometry of a single part of a composite load
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PartShape {
+    /// Rectangular block (length, width, height) centred on the part position
+    Box { dimensions: Vector3<f32> },
+    /// Cylinder centred on the part position
+    Cylinder {
+        radius_m: f32,
+        length_m: f32,
+        axis: CylinderAxis,
+    },
+    /// Lumped mass with no volume (motors, valves, fluid contents)
+    PointMass,
+}
+
+impl PartShape {
+    /// Half extents of the shape's axis-aligned bounding box
+    pub fn half_extents(&self) -> Vector3<f32> {
+        match self {
+            PartShape::Box { dimensions } => dimensions / 2.0,
+            PartShape::Cylinder {
+                radius_m,
+                length_m,
+                axis,
+            } => {
+                let half_length = length_m / 2.0;
+                match axis {
+                    CylinderAxis::X => Vector3::new(half_length, *radius_m, *radius_m),
+                    CylinderAxis::Y => Vector3::new(*radius_m, half_length, *radius_m),
+                    CylinderAxis::Z => Vector3::new(*radius_m, *radius_m, half_length),
+                }
+            }
+            PartShape::PointMass => Vector3::zeros(),
+        }
+    }
+}
+
+/// A named part of a composite load (vessel, pump, skid frame, ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadPart {
+    pub name: String,
+    pub shape: PartShape,
+    /// Geometric centre of the part relative to load origin
+    pub position: Point3<f32>,
+    pub mass_kg: f32,
+    /// Offset of the part's CoG from its geometric centre (e.g. part-filled vessel)
+    pub cog_offset: Vector3<f32>,
+    /// ± uncertainty of the part's CoG along each axis (m)
+    pub cog_tolerance_m: Vector3<f32>,
+}
+
+impl LoadPart {
+    pub fn new(name: &str, shape: PartShape, position: Point3<f32>, mass_kg: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            shape,
+            position,
+            mass_kg,
+            cog_offset: Vector3::zeros(),
+            cog_tolerance_m: Vector3::zeros(),
+        }
+    }
+
+    /// Rectangular part (length, width, height)
+    pub fn new_box(
+        name: &str,
+        position: Point3<f32>,
+        dimensions: Vector3<f32>,
+        mass_kg: f32,
+    ) -> Self {
+        Self::new(name, PartShape::Box { dimensions }, position, mass_kg)
+    }
+
+    /// Cylindrical part (vessels, pipe spools)
+    pub fn new_cylinder(
+        name: &str,
+        position: Point3<f32>,
+        radius_m: f32,
+        length_m: f32,
+        axis: CylinderAxis,
+        mass_kg: f32,
+    ) -> Self {
+        Self::new(
+            name,
+            PartShape::Cylinder {
+                radius_m,
+                length_m,
+                axis,
+            },
+            position,
+            mass_kg,
+        )
+    }
+
+    /// Lumped mass at a point
+    pub fn new_point_mass(name: &str, position: Point3<f32>, mass_kg: f32) -> Self {
+        Self::new(name, PartShape::PointMass, position, mass_kg)
+    }
+
+    pub fn with_cog_offset(mut self, offset: Vector3<f32>) -> Self {
+        self.cog_offset = offset;
+        self
+    }
+
+    pub fn with_cog_tolerance(mut self, tolerance_m: Vector3<f32>) -> Self {
+        self.cog_tolerance_m = tolerance_m;
+        self
+    }
+
+    /// Centre of gravity of this part relative to load origin
+    pub fn center_of_gravity(&self) -> Point3<f32> {
+        self.position + self.cog_offset
+    }
+
+    /// Axis-aligned bounds of this part (min, max)
+    pub fn bounds(&self) -> (Point3<f32>, Point3<f32>) {
+        let half = self.shape.half_extents();
+        (self.position - half, self.position + half)
+    }
+}
+
+/// Box the true centre of gravity is expected to lie within
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CogEnvelope {
+    pub min: Point3<f32>,
+    pub max: Point3<f32>,
+}
+
+impl CogEnvelope {
+    /// Envelope of ± tolerance around a nominal CoG
+    pub fn around(nominal: Point3<f32>, tolerance_m: Vector3<f32>) -> Self {
+        let tolerance = tolerance_m.abs();
+        Self {
+            min: nominal - tolerance,
+            max: nominal + tolerance,
+        }
+    }
+
+    /// Nominal (centre) CoG of the envelope
+    pub fn center(&self) -> Point3<f32> {
+        nalgebra::center(&self.min, &self.max)
+    }
+
+    /// Size of the envelope along each axis
+    pub fn size(&self) -> Vector3<f32> {
+        self.max - self.min
+    }
+
+    pub fn contains(&self, point: Point3<f32>) -> bool {
+        (0..3).all(|i| point[i] >= self.min[i] && point[i] <= self.max[i])
+    }
+
+    /// The eight corners of the envelope
+    pub fn corners(&self) -> [Point3<f32>; 8] {
+        let (lo, hi) = (self.min, self.max);
+        [
+            Point3::new(lo.x, lo.y, lo.z),
+            Point3::new(hi.x, lo.y, lo.z),
+            Point3::new(lo.x, hi.y, lo.z),
+            Point3::new(hi.x, hi.y, lo.z),
+            Point3::new(lo.x, lo.y, hi.z),
+            Point3::new(hi.x, lo.y, hi.z),
+            Point3::new(lo.x, hi.y, hi.z),
+            Point3::new(hi.x, hi.y, hi.z),
+        ]
+    }
+}
+
+/// Load assembled from parts with computed weight and CoG
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositeLoad {
+    pub name: String,
+    pub parts: Vec<LoadPart>,
+    /// Pick points where slings attach (relative to load origin)
+    pub pick_points: Vec<PickPoint>,
+    /// Additional ± tolerance on the assembled CoG (e.g. drawing estimate)
+    pub cog_tolerance_m: Vector3<f32>,
+}
+
+impl CompositeLoad {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            parts: Vec::new(),
+            pick_points: Vec::new(),
+            cog_tolerance_m: Vector3::zeros(),
+        }
+    }
+
+    pub fn add_part(&mut self, part: LoadPart) {
+        self.parts.push(part);
+    }
+
+    pub fn add_pick_point(&mut self, id: &str, position: Point3<f32>) {
+        self.pick_points.push(PickPoint {
+            id: id.to_string(),
+            position,
+            active: true,
+        });
+    }
+
+    /// Get part by name
+    pub fn get_part(&self, name: &str) -> Option<&LoadPart> {
+        self.parts.iter().find(|p| p.name == name)
+    }
+
+    /// Total weight of all parts
+    pub fn total_weight_kg(&self) -> f32 {
+        self.parts.iter().map(|p| p.mass_kg).sum()
+    }
+
+    /// Mass-weighted centre of gravity of all parts
+    ///
+    /// Returns None if the load has no mass
+    pub fn center_of_gravity(&self) -> Option<Point3<f32>> {
+        let total = self.total_weight_kg();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let moment: Vector3<f32> = self
+            .parts
+            .iter()
+            .map(|p| p.center_of_gravity().coords * p.mass_kg)
+            .sum();
+
+        Some(Point3::from(moment / total))
+    }
+
+    /// Axis-aligned bounding box of all parts (min, max)
+    pub fn bounding_box(&self) -> Option<(Point3<f32>, Point3<f32>)> {
+        let mut parts = self.parts.iter().map(|p| p.bounds());
+        let (mut min, mut max) = parts.next()?;
+
+        for (lo, hi) in parts {
+            min = min.inf(&lo);
+            max = max.sup(&hi);
+        }
+
+        Some((min, max))
+    }
+
+    /// Overall dimensions (length, width, height)
+    pub fn dimensions(&self) -> Vector3<f32> {
+        self.bounding_box()
+            .map(|(min, max)| max - min)
+            .unwrap_or_else(Vector3::zeros)
+    }
+
+    /// Worst-case CoG envelope
+    ///
+    /// Each part's tolerance contributes in proportion to its share of the
+    /// total mass; contributions are summed linearly (conservative) and the
+    /// load-level tolerance is added on top.
+    pub fn cog_envelope(&self) -> Option<CogEnvelope> {
+        let cog = self.center_of_gravity()?;
+        let total = self.total_weight_kg();
+
+        let part_tolerance: Vector3<f32> = self
+            .parts
+            .iter()
+            .map(|p| p.cog_tolerance_m.abs() * (p.mass_kg / total))
+            .sum();
+
+        Some(CogEnvelope::around(
+            cog,
+            part_tolerance + self.cog_tolerance_m.abs(),
+        ))
+    }
+
+    /// Build a `Load` for `RiggingCalculator`
+    pub fn to_load(&self) -> Result<Load, String> {
+        if self.parts.is_empty() {
+            return Err(format!("Composite load '{}' has no parts", self.name));
+        }
+
+        if let Some(part) = self.parts.iter().find(|p| p.mass_kg < 0.0) {
+            return Err(format!("Part '{}' has negative mass", part.name));
+        }
+
+        let center_of_gravity = self
+            .center_of_gravity()
+            .ok_or_else(|| format!("Composite load '{}' has zero total mass", self.name))?;
+
+        Ok(Load {
+            weight_kg: self.total_weight_kg(),
+            center_of_gravity,
+            dimensions: self.dimensions(),
+            pick_points: self.pick_points.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_skid() -> CompositeLoad {
+        let mut skid = CompositeLoad::new("Pump skid");
+        skid.add_part(LoadPart::new_box(
+            "Base frame",
+            Point3::new(0.0, 0.0, 0.15),
+            Vector3::new(6.0, 2.5, 0.3),
+            2000.0,
+        ));
+        skid.add_part(LoadPart::new_cylinder(
+            "Vessel",
+            Point3::new(1.5, 0.0, 1.3),
+            0.7,
+            3.0,
+            CylinderAxis::X,
+            3000.0,
+        ));
+        skid.add_part(LoadPart::new_point_mass(
+            "Pump",
+            Point3::new(-2.0, 0.0, 0.8),
+            1000.0,
+        ));
+        skid
+    }
+
+    #[test]
+    fn test_total_weight_and_cog() {
+        let skid = test_skid();
+        assert_eq!(skid.total_weight_kg(), 6000.0);
+
+        let cog = skid.center_of_gravity().unwrap();
+        // x = (0*2000 + 1.5*3000 - 2*1000) / 6000
+        assert!((cog.x - 2500.0 / 6000.0).abs() < 1e-4);
+        assert!(cog.y.abs() < 1e-4);
+        // z = (0.15*2000 + 1.3*3000 + 0.8*1000) / 6000
+        assert!((cog.z - 5000.0 / 6000.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let skid = test_skid();
+        let (min, max) = skid.bounding_box().unwrap();
+
+        assert!((min.x + 3.0).abs() < 1e-4);
+        assert!((max.x - 3.0).abs() < 1e-4);
+        assert!(min.z.abs() < 1e-4);
+        assert!((max.z - 2.0).abs() < 1e-4);
+        assert!((skid.dimensions().y - 2.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_cog_envelope() {
+        let mut skid = test_skid();
+        skid.parts[1].cog_tolerance_m = Vector3::new(0.2, 0.0, 0.0);
+        skid.cog_tolerance_m = Vector3::new(0.05, 0.05, 0.05);
+
+        let envelope = skid.cog_envelope().unwrap();
+        let cog = skid.center_of_gravity().unwrap();
+
+        // Vessel is half the mass -> contributes 0.1m, plus 0.05m load tolerance
+        assert!((envelope.size().x - 0.3).abs() < 1e-4);
+        assert!((envelope.size().y - 0.1).abs() < 1e-4);
+        assert!(envelope.contains(cog));
+        assert!((envelope.center() - cog).norm() < 1e-4);
+    }
+
+    #[test]
+    fn test_to_load() {
+        let mut skid = test_skid();
+        skid.add_pick_point("front", Point3::new(2.5, 0.0, 0.3));
+        skid.add_pick_point("rear", Point3::new(-2.5, 0.0, 0.3));
+
+        let load = skid.to_load().unwrap();
+        assert_eq!(load.weight_kg, 6000.0);
+        assert_eq!(load.pick_points.len(), 2);
+        assert_eq!(load.center_of_gravity, skid.center_of_gravity().unwrap());
+
+        assert!(CompositeLoad::new("empty").to_load().is_err());
+    }
+}
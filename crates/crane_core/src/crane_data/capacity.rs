@@ -1,13 +1,37 @@
 // crates/crane_core/src/crane_data/capacity.rs - COMPLETE REWRITE
 
+use super::chart_validation::{ChartDiagnostic, DiagnosticKind, Severity, SourceLocation};
+use super::provenance::ChartProvenance;
+use super::units::{ChartUnits, fields_to_metric, has_imperial_fields};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// What limits a chart capacity (shading on printed charts)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CapacityLimit {
+    /// Boom and structural strength
+    Structural,
+    /// Tipping stability
+    Stability,
+}
+
 /// Single point on a load chart
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CapacityPoint {
     pub radius_m: f32,
     pub capacity_kg: f32,
+
+    /// Governing limit, when the chart marks it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<CapacityLimit>,
+
+    /// Footnote markers on the chart cell
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub footnotes: Vec<String>,
+
+    /// Where the point was read from, for diagnostics
+    #[serde(skip)]
+    pub source: Option<SourceLocation>,
 }
 
 /// Load chart for a specific boom length and configuration
@@ -18,6 +42,10 @@ pub struct LoadChart {
 
     /// Optional configuration notes
     pub notes: Option<String>,
+
+    /// Telescoping mode this chart applies to (None = any mode)
+    #[serde(default)]
+    pub telescoping_mode: Option<String>,
 }
 
 impl LoadChart {
@@ -26,15 +54,40 @@ impl LoadChart {
             boom_length_m,
             points: Vec::new(),
             notes: None,
+            telescoping_mode: None,
+        }
+    }
+
+    /// Create an empty chart for a telescoping mode
+    pub fn for_telescoping_mode(boom_length_m: f32, mode: &str) -> Self {
+        Self {
+            telescoping_mode: Some(mode.to_string()),
+            ..Self::new(boom_length_m)
+        }
+    }
+
+    /// Key used to index this chart in a `CapacityChart`
+    pub fn key(&self) -> String {
+        match &self.telescoping_mode {
+            Some(mode) => format!("{}:{:.1}", mode, self.boom_length_m),
+            None => format!("{:.1}", self.boom_length_m),
         }
     }
 
     /// Add a capacity point
     pub fn add_point(&mut self, radius_m: f32, capacity_kg: f32) {
-        self.points.push(CapacityPoint {
+        self.push_point(CapacityPoint {
             radius_m,
             capacity_kg,
+            limit: None,
+            footnotes: Vec::new(),
+            source: None,
         });
+    }
+
+    /// Add a capacity point with its chart markings
+    pub fn push_point(&mut self, point: CapacityPoint) {
+        self.points.push(point);
 
         // Keep points sorted by radius
         self.points
@@ -122,6 +175,10 @@ pub struct CapacityChart {
     /// Configuration-specific factors
     pub outrigger_intermediate_factor: f32, // When not fully extended
     pub on_tires_factor: f32, // Very low ~0.3-0.5
+
+    /// Source document, revision and content hash
+    #[serde(default)]
+    pub provenance: Option<ChartProvenance>,
 }
 
 impl CapacityChart {
@@ -133,13 +190,33 @@ impl CapacityChart {
             dynamic_factor: 0.85,
             outrigger_intermediate_factor: 0.85,
             on_tires_factor: 0.40,
+            provenance: None,
         }
     }
 
-    /// Add a load chart for a specific boom length
+    /// Add a load chart for a specific boom length (and telescoping mode)
     pub fn add_chart(&mut self, chart: LoadChart) {
-        let key = format!("{:.1}", chart.boom_length_m);
-        self.charts.insert(key, chart);
+        self.charts.insert(chart.key(), chart);
+    }
+
+    /// Re-key every chart after boom lengths were converted
+    pub fn rekey(&mut self) {
+        let charts: Vec<LoadChart> = self.charts.drain().map(|(_, chart)| chart).collect();
+        for chart in charts {
+            self.add_chart(chart);
+        }
+    }
+
+    /// Telescoping modes that have dedicated charts
+    pub fn telescoping_modes(&self) -> Vec<String> {
+        let mut modes: Vec<String> = self
+            .charts
+            .values()
+            .filter_map(|c| c.telescoping_mode.clone())
+            .collect();
+        modes.sort();
+        modes.dedup();
+        modes
     }
 
     /// Get capacity for given configuration
@@ -150,9 +227,32 @@ impl CapacityChart {
         swing_angle_deg: f32,
         outrigger_extension_pct: f32,
         on_tires: bool,
+    ) -> Option<f32> {
+        self.get_capacity_for_mode(
+            None,
+            boom_length_m,
+            radius_m,
+            swing_angle_deg,
+            outrigger_extension_pct,
+            on_tires,
+        )
+    }
+
+    /// Get capacity using the charts for a telescoping mode
+    ///
+    /// Falls back to mode-independent charts when the mode has none. Without
+    /// a mode only mode-independent charts are used.
+    pub fn get_capacity_for_mode(
+        &self,
+        telescoping_mode: Option<&str>,
+        boom_length_m: f32,
+        radius_m: f32,
+        swing_angle_deg: f32,
+        outrigger_extension_pct: f32,
+        on_tires: bool,
     ) -> Option<f32> {
         // Find chart for boom length (or interpolate between charts)
-        let chart = self.find_chart_for_boom_length(boom_length_m)?;
+        let chart = self.find_chart_for_boom_length(boom_length_m, telescoping_mode)?;
 
         // Get base capacity from chart
         let mut capacity = chart.get_capacity_at_radius(radius_m)?;
@@ -191,8 +291,26 @@ impl CapacityChart {
     }
 
     /// Find chart for boom length (exact match or closest)
-    fn find_chart_for_boom_length(&self, boom_length_m: f32) -> Option<&LoadChart> {
-        let key = format!("{:.1}", boom_length_m);
+    fn find_chart_for_boom_length(
+        &self,
+        boom_length_m: f32,
+        telescoping_mode: Option<&str>,
+    ) -> Option<&LoadChart> {
+        // Restrict to the mode's charts, or mode-independent ones if it has none
+        let has_mode_charts = telescoping_mode.is_some_and(|mode| {
+            self.charts
+                .values()
+                .any(|c| c.telescoping_mode.as_deref() == Some(mode))
+        });
+        let candidates = self.charts.iter().filter(|(_, c)| match telescoping_mode {
+            Some(mode) if has_mode_charts => c.telescoping_mode.as_deref() == Some(mode),
+            _ => c.telescoping_mode.is_none(),
+        });
+
+        let mut key = format!("{:.1}", boom_length_m);
+        if has_mode_charts && let Some(mode) = telescoping_mode {
+            key = format!("{}:{}", mode, key);
+        }
 
         // Try exact match first
         if let Some(chart) = self.charts.get(&key) {
@@ -203,7 +321,7 @@ impl CapacityChart {
         let mut closest: Option<(&String, &LoadChart)> = None;
         let mut min_diff = f32::MAX;
 
-        for (k, chart) in &self.charts {
+        for (k, chart) in candidates {
             let diff = (chart.boom_length_m - boom_length_m).abs();
             if diff < min_diff {
                 min_diff = diff;
@@ -280,42 +398,82 @@ impl LoadChartParser {
     /// 30.0,5.0,80000
     /// 30.0,10.0,40000
     /// ```
+    /// Units named in the header (`boom_length_ft,radius_ft,capacity_lb`)
+    /// are converted to metres and kilograms.
     pub fn parse_csv(csv_data: &str) -> Result<Vec<LoadChart>, String> {
+        let (charts, diagnostics) = Self::read_csv(csv_data);
+        first_error(&diagnostics)?;
+        Ok(charts)
+    }
+
+    /// Read long-form CSV, reporting every bad line instead of stopping
+    pub fn read_csv(csv_data: &str) -> (Vec<LoadChart>, Vec<ChartDiagnostic>) {
         let mut charts_map: HashMap<String, LoadChart> = HashMap::new();
+        let mut diagnostics = Vec::new();
+        let units = ChartUnits::detect(csv_data.lines().next().unwrap_or(""));
 
         for (line_num, line) in csv_data.lines().enumerate() {
             // Skip header and empty lines
             if line_num == 0 || line.trim().is_empty() {
                 continue;
             }
+            let line_num = line_num + 1;
 
             let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
             if parts.len() < 3 {
-                return Err(format!(
-                    "Line {}: Expected 3 columns, got {}",
-                    line_num + 1,
-                    parts.len()
-                ));
+                diagnostics.push(
+                    ChartDiagnostic::error(
+                        DiagnosticKind::Parse,
+                        format!("Expected 3 columns, got {}", parts.len()),
+                    )
+                    .at(Some(SourceLocation::line(line_num))),
+                );
+                continue;
             }
 
-            let boom_length: f32 = parts[0].parse().map_err(|_| {
-                format!("Line {}: Invalid boom length '{}'", line_num + 1, parts[0])
-            })?;
-            let radius: f32 = parts[1]
-                .parse()
-                .map_err(|_| format!("Line {}: Invalid radius '{}'", line_num + 1, parts[1]))?;
-            let capacity: f32 = parts[2]
-                .parse()
-                .map_err(|_| format!("Line {}: Invalid capacity '{}'", line_num + 1, parts[2]))?;
+            let mut values = [0.0_f32; 3];
+            let mut valid = true;
+            let columns = [
+                ("boom length", units.length),
+                ("radius", units.length),
+                ("capacity", units.mass),
+            ];
+            for (index, (name, unit)) in columns.iter().enumerate() {
+                match unit.parse_value(parts[index]) {
+                    Some(value) => values[index] = value,
+                    None => {
+                        diagnostics.push(
+                            ChartDiagnostic::error(
+                                DiagnosticKind::Parse,
+                                format!("Invalid {} '{}'", name, parts[index]),
+                            )
+                            .at(Some(SourceLocation::cell(line_num, index + 1))),
+                        );
+                        valid = false;
+                    }
+                }
+            }
+            if !valid {
+                continue;
+            }
 
+            let [boom_length, radius, capacity] = values;
             let key = format!("{:.1}", boom_length);
             let chart = charts_map
                 .entry(key)
                 .or_insert_with(|| LoadChart::new(boom_length));
-            chart.add_point(radius, capacity);
+            chart.push_point(CapacityPoint {
+                radius_m: radius,
+                capacity_kg: capacity,
+                limit: None,
+                footnotes: Vec::new(),
+                source: Some(SourceLocation::line(line_num)),
+            });
         }
 
-        Ok(charts_map.into_values().collect())
+        let mut charts: Vec<LoadChart> = charts_map.into_values().collect();
+        charts.sort_by(|a, b| a.boom_length_m.total_cmp(&b.boom_length_m));
+        (charts, diagnostics)
     }
 
     /// Parse JSON format load chart
@@ -334,16 +492,43 @@ impl LoadChartParser {
     ///   ]
     /// }
     /// ```
+    /// Imperial files use `boom_length_ft`, `radius_ft` and `capacity_lb`.
     pub fn parse_json(json_data: &str) -> Result<Vec<LoadChart>, String> {
+        let (charts, diagnostics) = Self::read_json(json_data);
+        first_error(&diagnostics)?;
+        Ok(charts)
+    }
+
+    /// Read JSON charts, locating a syntax or schema error
+    pub fn read_json(json_data: &str) -> (Vec<LoadChart>, Vec<ChartDiagnostic>) {
         #[derive(Deserialize)]
         struct ChartData {
             charts: Vec<LoadChart>,
         }
 
-        let data: ChartData =
-            serde_json::from_str(json_data).map_err(|e| format!("JSON parse error: {}", e))?;
-
-        Ok(data.charts)
+        // Imperial field names (`radius_ft`, `capacity_lb`) are renamed and
+        // converted first; schema errors then have no line to point at
+        let parsed = match serde_json::from_str::<serde_json::Value>(json_data) {
+            Ok(mut value) if has_imperial_fields(&value) => {
+                fields_to_metric(&mut value);
+                serde_json::from_value::<ChartData>(value)
+            }
+            _ => serde_json::from_str::<ChartData>(json_data),
+        };
+
+        match parsed {
+            Ok(data) => (data.charts, Vec::new()),
+            Err(e) => (
+                Vec::new(),
+                vec![
+                    ChartDiagnostic::error(
+                        DiagnosticKind::Parse,
+                        format!("JSON parse error: {}", e),
+                    )
+                    .at((e.line() > 0).then(|| SourceLocation::cell(e.line(), e.column()))),
+                ],
+            ),
+        }
     }
 
     /// Parse manufacturer-style table format
@@ -361,11 +546,23 @@ impl LoadChartParser {
     /// 3.0        90000
     /// 5.0        70000
     /// ```
+    /// `BOOM LENGTH: 100ft` and `Radius(ft)  Capacity(lb)` headings read
+    /// the rows in feet and pounds.
     pub fn parse_table(table_data: &str) -> Result<Vec<LoadChart>, String> {
+        let (charts, diagnostics) = Self::read_table(table_data);
+        first_error(&diagnostics)?;
+        Ok(charts)
+    }
+
+    /// Read a table, reporting data lines it cannot use as warnings
+    pub fn read_table(table_data: &str) -> (Vec<LoadChart>, Vec<ChartDiagnostic>) {
         let mut charts = Vec::new();
+        let mut diagnostics = Vec::new();
         let mut current_chart: Option<LoadChart> = None;
+        let mut units = ChartUnits::metric();
 
-        for line in table_data.lines() {
+        for (line_num, line) in table_data.lines().enumerate() {
+            let line_num = line_num + 1;
             let line = line.trim();
 
             // Check for boom length header
@@ -377,36 +574,64 @@ impl LoadChartParser {
                     charts.push(chart);
                 }
 
-                // Parse boom length
-                let boom_str = line
-                    .split(':')
-                    .nth(1)
-                    .ok_or_else(|| "Invalid BOOM LENGTH line".to_string())?
-                    .trim()
-                    .trim_end_matches('m');
-
-                let boom_length: f32 = boom_str
-                    .parse()
-                    .map_err(|_| format!("Invalid boom length: {}", boom_str))?;
-
-                current_chart = Some(LoadChart::new(boom_length));
+                // Parse boom length, in metres unless marked `ft`
+                let boom_text = line.split(':').nth(1).unwrap_or("").trim();
+                let boom_str = boom_text.trim_end_matches(|c: char| c.is_alphabetic() || c == ' ');
+                let length_unit = ChartUnits::detect(boom_text).length;
+
+                match length_unit.parse_value(boom_str) {
+                    Some(boom_length) => current_chart = Some(LoadChart::new(boom_length)),
+                    None => diagnostics.push(
+                        ChartDiagnostic::error(
+                            DiagnosticKind::Parse,
+                            format!("Invalid boom length: {}", boom_str),
+                        )
+                        .at(Some(SourceLocation::line(line_num))),
+                    ),
+                }
                 continue;
             }
 
-            // Skip header lines
-            if line.to_uppercase().contains("RADIUS") || line.is_empty() {
+            // Column headings name the units of the rows below
+            if line.to_uppercase().contains("RADIUS") {
+                units = ChartUnits::detect(line);
+                continue;
+            }
+            if line.is_empty() {
                 continue;
             }
 
             // Parse data line
-            if let Some(chart) = &mut current_chart {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() >= 2
-                    && let (Ok(radius), Ok(capacity)) =
-                        (parts[0].parse::<f32>(), parts[1].parse::<f32>())
-                {
-                    chart.add_point(radius, capacity);
-                }
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let values = match parts.as_slice() {
+                [radius, capacity, ..] => units
+                    .length
+                    .parse_value(radius)
+                    .zip(units.mass.parse_value(capacity)),
+                _ => None,
+            };
+            match (&mut current_chart, values) {
+                (Some(chart), Some((radius, capacity))) => chart.push_point(CapacityPoint {
+                    radius_m: radius,
+                    capacity_kg: capacity,
+                    limit: None,
+                    footnotes: Vec::new(),
+                    source: Some(SourceLocation::line(line_num)),
+                }),
+                (None, _) => diagnostics.push(
+                    ChartDiagnostic::warning(
+                        DiagnosticKind::Skipped,
+                        format!("'{}' is not under a BOOM LENGTH heading", line),
+                    )
+                    .at(Some(SourceLocation::line(line_num))),
+                ),
+                (Some(_), None) => diagnostics.push(
+                    ChartDiagnostic::warning(
+                        DiagnosticKind::Skipped,
+                        format!("'{}' is not a radius and capacity", line),
+                    )
+                    .at(Some(SourceLocation::line(line_num))),
+                ),
             }
         }
 
@@ -417,22 +642,41 @@ impl LoadChartParser {
             charts.push(chart);
         }
 
-        Ok(charts)
+        (charts, diagnostics)
+    }
+}
+
+/// First error as the `Line N: ...` string the strict parsers return
+pub(crate) fn first_error(diagnostics: &[ChartDiagnostic]) -> Result<(), String> {
+    match diagnostics.iter().find(|d| d.severity == Severity::Error) {
+        Some(diagnostic) => Err(match diagnostic.location {
+            Some(location) => format!("Line {}: {}", location.line, diagnostic.message),
+            None => diagnostic.message.clone(),
+        }),
+        None => Ok(()),
     }
 }
 
 /// Builder for creating capacity charts programmatically
 pub struct CapacityChartBuilder {
     chart: CapacityChart,
+    provenance: Option<ChartProvenance>,
 }
 
 impl CapacityChartBuilder {
     pub fn new() -> Self {
         Self {
             chart: CapacityChart::new(),
+            provenance: None,
         }
     }
 
+    /// Record the source document; the content hash is stamped on build
+    pub fn with_provenance(mut self, provenance: ChartProvenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
     pub fn with_over_side_factor(mut self, factor: f32) -> Self {
         self.chart.over_side_factor = factor;
         self
@@ -477,7 +721,26 @@ impl CapacityChartBuilder {
         Ok(self)
     }
 
-    pub fn build(self) -> CapacityChart {
+    pub fn add_charts_from_matrix_csv(mut self, csv_data: &str) -> Result<Self, String> {
+        let import = LoadChartParser::parse_matrix_csv(csv_data)?;
+        for chart in import.charts {
+            self.chart.add_chart(chart);
+        }
+        Ok(self)
+    }
+
+    pub fn add_charts_from_matrix_text(mut self, text_data: &str) -> Result<Self, String> {
+        let import = LoadChartParser::parse_matrix_text(text_data)?;
+        for chart in import.charts {
+            self.chart.add_chart(chart);
+        }
+        Ok(self)
+    }
+
+    pub fn build(mut self) -> CapacityChart {
+        if let Some(provenance) = self.provenance {
+            self.chart.set_provenance(provenance);
+        }
         self.chart
     }
 }
@@ -488,42 +751,10 @@ impl Default for CapacityChartBuilder {
     }
 }
 
-/// Example load charts for testing
-impl CapacityChart {
-    /// Liebherr LTM 1100-5.2 example capacity chart
-    pub fn example_liebherr_ltm_1100() -> Self {
-        let csv_data = r#"boom_length,radius,capacity
-30.0,3.0,100000
-30.0,5.0,80000
-30.0,10.0,40000
-30.0,15.0,25000
-30.0,20.0,15000
-30.0,25.0,10000
-40.0,3.0,90000
-40.0,5.0,70000
-40.0,10.0,35000
-40.0,20.0,12000
-40.0,30.0,7000
-40.0,35.0,5000
-50.0,3.0,80000
-50.0,10.0,30000
-50.0,20.0,10000
-50.0,30.0,6000
-50.0,40.0,4000
-50.0,45.0,3000"#;
-
-        CapacityChartBuilder::new()
-            .with_over_side_factor(0.85)
-            .with_over_rear_factor(0.75)
-            .add_charts_from_csv(csv_data)
-            .unwrap()
-            .build()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crane_data::Unit;
 
     #[test]
     fn test_load_chart_interpolation() {
@@ -584,6 +815,26 @@ Radius(m)  Capacity(kg)
         assert_eq!(charts[0].points.len(), 2);
     }
 
+    #[test]
+    fn test_imperial_chart_import() {
+        let csv = "boom_length_ft,radius_ft,capacity_lb\n100,10,20000\n100,20,12000";
+        let table = "BOOM LENGTH: 100ft\nRadius(ft)  Capacity(lb)\n10  20000\n20  12000";
+        let json = r#"{"charts": [{"boom_length_ft": 100, "notes": null, "points": [
+            {"radius_ft": 10, "capacity_lb": 20000}, {"radius_ft": 20, "capacity_lb": 12000}]}]}"#;
+
+        let from_csv = LoadChartParser::parse_csv(csv).unwrap();
+        let from_table = LoadChartParser::parse_table(table).unwrap();
+        let from_json = LoadChartParser::parse_json(json).unwrap();
+
+        let chart = &from_csv[0];
+        assert_eq!(chart.boom_length_m, 30.48);
+        assert_eq!(chart.points[0].radius_m, 3.048);
+        assert_eq!(chart.points[0].capacity_kg, Unit::Pound.to_metric(20_000.0));
+        for other in [&from_table[0], &from_json[0]] {
+            assert_eq!(other.content_hash(), chart.content_hash());
+        }
+    }
+
     #[test]
     fn test_swing_factor() {
         let chart = CapacityChart::new();
@@ -631,4 +882,44 @@ Radius(m)  Capacity(kg)
         let capacity = chart.get_capacity_interpolated(35.0, 10.0).unwrap();
         assert!((capacity - 90_000.0).abs() < 1.0);
     }
+
+    #[test]
+    fn test_capacity_by_telescoping_mode() {
+        let mut chart = CapacityChart::new();
+
+        let mut generic = LoadChart::new(30.0);
+        generic.add_point(10.0, 50_000.0);
+
+        let mut t1 = LoadChart::for_telescoping_mode(30.0, "T1");
+        t1.add_point(10.0, 60_000.0);
+
+        chart.add_chart(generic);
+        chart.add_chart(t1);
+        assert_eq!(chart.charts.len(), 2);
+        assert_eq!(chart.telescoping_modes(), vec!["T1".to_string()]);
+
+        let capacity = |mode| {
+            chart
+                .get_capacity_for_mode(mode, 30.0, 10.0, 0.0, 1.0, false)
+                .unwrap()
+        };
+        assert_eq!(capacity(Some("T1")), 60_000.0);
+        // Modes without charts fall back to mode-independent charts
+        assert_eq!(capacity(Some("T2")), 50_000.0);
+        assert_eq!(capacity(None), 50_000.0);
+
+        // A mode-less query never picks up another mode's chart
+        let mut mode_only = CapacityChart::new();
+        let mut t2 = LoadChart::for_telescoping_mode(40.0, "T2");
+        t2.add_point(10.0, 45_000.0);
+        mode_only.add_chart(t2);
+        assert_eq!(
+            mode_only.get_capacity_for_mode(None, 40.0, 10.0, 0.0, 1.0, false),
+            None
+        );
+        assert_eq!(
+            mode_only.get_capacity_for_mode(Some("T2"), 40.0, 10.0, 0.0, 1.0, false),
+            Some(45_000.0)
+        );
+    }
 }
@@ -0,0 +1,555 @@
+// crates/crane_core/src/crane_data/chart_validation.rs
+
+use super::capacity::{LoadChart, LoadChartParser};
+use super::spec::CraneSpec;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// Where a value came from in chart source text (1-based)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub line: usize,
+    /// Cell (CSV column or matrix column), when known
+    pub cell: Option<usize>,
+}
+
+impl SourceLocation {
+    pub fn line(line: usize) -> Self {
+        Self { line, cell: None }
+    }
+
+    pub fn cell(line: usize, cell: usize) -> Self {
+        Self {
+            line,
+            cell: Some(cell),
+        }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cell {
+            Some(cell) => write!(f, "line {}, cell {}", self.line, cell),
+            None => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// Text that could not be read
+    Parse,
+    /// Line that was skipped without being understood
+    Skipped,
+    EmptyChart,
+    DuplicateChart,
+    DuplicatePoint,
+    /// Capacity rises with radius
+    NonMonotonic,
+    RadiusGap,
+    InvalidValue,
+    ExceedsMaxCapacity,
+    ExceedsMaxRadius,
+    BoomLengthOutOfRange,
+    /// Tower chart rates the jib tip below the spec's tip load
+    BelowTipLoad,
+}
+
+/// One problem found in a load chart
+#[derive(Debug, Clone)]
+pub struct ChartDiagnostic {
+    pub severity: Severity,
+    pub kind: DiagnosticKind,
+    pub location: Option<SourceLocation>,
+    /// Chart key (`mode:boom` or boom length)
+    pub chart: Option<String>,
+    pub message: String,
+}
+
+impl ChartDiagnostic {
+    pub fn error(kind: DiagnosticKind, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            kind,
+            location: None,
+            chart: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(kind: DiagnosticKind, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(kind, message)
+        }
+    }
+
+    pub fn at(mut self, location: Option<SourceLocation>) -> Self {
+        self.location = location;
+        self
+    }
+
+    fn in_chart(mut self, chart: &LoadChart) -> Self {
+        self.chart = Some(chart.key());
+        self
+    }
+}
+
+impl fmt::Display for ChartDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}", severity)?;
+        if let Some(location) = &self.location {
+            write!(f, " at {}", location)?;
+        }
+        if let Some(chart) = &self.chart {
+            write!(f, " [{}]", chart)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Source layouts the validator can read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartFormat {
+    /// Long-form `boom_length,radius,capacity`
+    Csv,
+    Json,
+    /// `BOOM LENGTH:` blocks
+    Table,
+    MatrixCsv,
+    MatrixText,
+}
+
+impl ChartFormat {
+    /// Guess the format from a file name and its contents
+    pub fn detect(path: &Path, data: &str) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        let first_line = data
+            .lines()
+            .find(|l| !l.trim().is_empty())
+            .unwrap_or("")
+            .to_lowercase();
+
+        match extension.as_str() {
+            "json" => ChartFormat::Json,
+            "csv" if first_line.starts_with("boom_length") => ChartFormat::Csv,
+            "csv" => ChartFormat::MatrixCsv,
+            _ if data.to_uppercase().contains("BOOM LENGTH:") => ChartFormat::Table,
+            _ => ChartFormat::MatrixText,
+        }
+    }
+}
+
+/// Charts read from a source and everything wrong with them
+#[derive(Debug, Clone, Default)]
+pub struct ChartReport {
+    pub charts: Vec<LoadChart>,
+    pub diagnostics: Vec<ChartDiagnostic>,
+}
+
+impl ChartReport {
+    pub fn error_count(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+}
+
+/// Thresholds for chart linting
+#[derive(Debug, Clone, Copy)]
+pub struct ValidationOptions {
+    /// Largest radius step between chart points before warning (m)
+    pub max_radius_gap_m: f32,
+    /// Capacity rise allowed between points before it counts (kg)
+    pub capacity_tolerance_kg: f32,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            max_radius_gap_m: 6.0,
+            capacity_tolerance_kg: 1.0,
+        }
+    }
+}
+
+/// Checks load charts, optionally against the spec they belong to
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChartValidator {
+    pub options: ValidationOptions,
+}
+
+impl ChartValidator {
+    pub fn new(options: ValidationOptions) -> Self {
+        Self { options }
+    }
+
+    /// Read chart source in any format and lint the result
+    pub fn validate_source(
+        &self,
+        data: &str,
+        format: ChartFormat,
+        spec: Option<&CraneSpec>,
+    ) -> ChartReport {
+        let (charts, mut diagnostics) = match format {
+            ChartFormat::Csv => LoadChartParser::read_csv(data),
+            ChartFormat::Table => LoadChartParser::read_table(data),
+            ChartFormat::MatrixCsv => {
+                let (import, diagnostics) = LoadChartParser::read_matrix_csv(data);
+                (import.charts, diagnostics)
+            }
+            ChartFormat::MatrixText => {
+                let (import, diagnostics) = LoadChartParser::read_matrix_text(data);
+                (import.charts, diagnostics)
+            }
+            ChartFormat::Json => LoadChartParser::read_json(data),
+        };
+
+        diagnostics.extend(self.validate_charts(&charts, spec));
+        ChartReport {
+            charts,
+            diagnostics,
+        }
+    }
+
+    /// Lint the main (and over-side) charts of a spec
+    pub fn validate_spec(&self, spec: &CraneSpec) -> Vec<ChartDiagnostic> {
+        let mut charts: Vec<LoadChart> = spec.capacity_chart.charts.values().cloned().collect();
+        charts.sort_by_key(|c| c.key());
+        let mut diagnostics = self.validate_charts(&charts, Some(spec));
+
+        if let Some(over_side) = spec
+            .crawler
+            .as_ref()
+            .and_then(|c| c.over_side_chart.as_ref())
+        {
+            let mut charts: Vec<LoadChart> = over_side.charts.values().cloned().collect();
+            charts.sort_by_key(|c| c.key());
+            diagnostics.extend(self.validate_charts(&charts, Some(spec)));
+        }
+
+        // Every jib length must carry the rated tip load at its tip, and
+        // the full-length jib must be charted out to its tip
+        if let Some(tower) = &spec.tower {
+            let mut full_tip_rated = false;
+            for chart in &charts {
+                let tip_radius = tower.tip_radius_m(chart.boom_length_m, spec.min_boom_angle_deg);
+                let Some(kg) = chart.get_capacity_at_radius(tip_radius) else {
+                    continue;
+                };
+                if (chart.boom_length_m - spec.boom_length_range.1).abs() < 0.05 {
+                    full_tip_rated = true;
+                }
+                if kg < tower.tip_load_kg - self.options.capacity_tolerance_kg {
+                    diagnostics.push(
+                        ChartDiagnostic::error(
+                            DiagnosticKind::BelowTipLoad,
+                            format!(
+                                "{:.0} kg at the {:.1}m jib tip is below the {} tip load of {:.0} kg",
+                                kg, chart.boom_length_m, spec.model, tower.tip_load_kg
+                            ),
+                        )
+                        .in_chart(chart),
+                    );
+                }
+            }
+            if !full_tip_rated {
+                diagnostics.push(ChartDiagnostic::error(
+                    DiagnosticKind::BelowTipLoad,
+                    format!(
+                        "No chart rates the {:.1}m jib tip",
+                        spec.boom_length_range.1
+                    ),
+                ));
+            }
+        }
+        diagnostics
+    }
+
+    /// Lint parsed charts
+    pub fn validate_charts(
+        &self,
+        charts: &[LoadChart],
+        spec: Option<&CraneSpec>,
+    ) -> Vec<ChartDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // Charts with the same key replace each other in a CapacityChart
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for chart in charts {
+            let count = seen.entry(chart.key()).or_default();
+            *count += 1;
+            if *count == 2 {
+                diagnostics.push(
+                    ChartDiagnostic::error(
+                        DiagnosticKind::DuplicateChart,
+                        format!("More than one chart for {}", chart.key()),
+                    )
+                    .in_chart(chart),
+                );
+            }
+        }
+
+        for chart in charts {
+            self.validate_chart(chart, spec, &mut diagnostics);
+        }
+        diagnostics
+    }
+
+    fn validate_chart(
+        &self,
+        chart: &LoadChart,
+        spec: Option<&CraneSpec>,
+        diagnostics: &mut Vec<ChartDiagnostic>,
+    ) {
+        let first_location = chart.points.iter().find_map(|p| p.source);
+
+        if chart.points.is_empty() {
+            diagnostics.push(
+                ChartDiagnostic::error(DiagnosticKind::EmptyChart, "Chart has no points")
+                    .in_chart(chart),
+            );
+        }
+
+        if let Some(spec) = spec {
+            let (min, max) = spec.boom_length_range;
+            if chart.boom_length_m < min - 0.05 || chart.boom_length_m > max + 0.05 {
+                diagnostics.push(
+                    ChartDiagnostic::error(
+                        DiagnosticKind::BoomLengthOutOfRange,
+                        format!(
+                            "Boom length {:.1}m is outside {} range ({:.1}m - {:.1}m)",
+                            chart.boom_length_m, spec.model, min, max
+                        ),
+                    )
+                    .at(first_location)
+                    .in_chart(chart),
+                );
+            }
+        }
+
+        for point in &chart.points {
+            if point.radius_m <= 0.0 || point.capacity_kg <= 0.0 {
+                diagnostics.push(
+                    ChartDiagnostic::error(
+                        DiagnosticKind::InvalidValue,
+                        format!(
+                            "{:.0} kg at {:.1}m is not a usable capacity point",
+                            point.capacity_kg, point.radius_m
+                        ),
+                    )
+                    .at(point.source)
+                    .in_chart(chart),
+                );
+            }
+
+            let Some(spec) = spec else {
+                continue;
+            };
+            if point.capacity_kg > spec.max_capacity_kg {
+                diagnostics.push(
+                    ChartDiagnostic::error(
+                        DiagnosticKind::ExceedsMaxCapacity,
+                        format!(
+                            "{:.0} kg at {:.1}m exceeds the {} maximum of {:.0} kg",
+                            point.capacity_kg, point.radius_m, spec.model, spec.max_capacity_kg
+                        ),
+                    )
+                    .at(point.source)
+                    .in_chart(chart),
+                );
+            }
+            if point.radius_m > spec.max_radius_m {
+                diagnostics.push(
+                    ChartDiagnostic::error(
+                        DiagnosticKind::ExceedsMaxRadius,
+                        format!(
+                            "Radius {:.1}m exceeds the {} maximum of {:.1}m",
+                            point.radius_m, spec.model, spec.max_radius_m
+                        ),
+                    )
+                    .at(point.source)
+                    .in_chart(chart),
+                );
+            }
+        }
+
+        // Points are kept sorted by radius
+        for pair in chart.points.windows(2) {
+            let (previous, point) = (&pair[0], &pair[1]);
+            let step = point.radius_m - previous.radius_m;
+
+            if step.abs() < 1e-3 {
+                let same = (point.capacity_kg - previous.capacity_kg).abs() < 1e-3;
+                let diagnostic = if same {
+                    ChartDiagnostic::warning(
+                        DiagnosticKind::DuplicatePoint,
+                        format!("Radius {:.1}m is listed twice", point.radius_m),
+                    )
+                } else {
+                    ChartDiagnostic::error(
+                        DiagnosticKind::DuplicatePoint,
+                        format!(
+                            "Radius {:.1}m is listed with {:.0} kg and {:.0} kg",
+                            point.radius_m, previous.capacity_kg, point.capacity_kg
+                        ),
+                    )
+                };
+                diagnostics.push(diagnostic.at(point.source).in_chart(chart));
+                continue;
+            }
+
+            if point.capacity_kg > previous.capacity_kg + self.options.capacity_tolerance_kg {
+                diagnostics.push(
+                    ChartDiagnostic::error(
+                        DiagnosticKind::NonMonotonic,
+                        format!(
+                            "Capacity rises from {:.0} kg at {:.1}m to {:.0} kg at {:.1}m",
+                            previous.capacity_kg,
+                            previous.radius_m,
+                            point.capacity_kg,
+                            point.radius_m
+                        ),
+                    )
+                    .at(point.source)
+                    .in_chart(chart),
+                );
+            }
+
+            if step > self.options.max_radius_gap_m {
+                diagnostics.push(
+                    ChartDiagnostic::warning(
+                        DiagnosticKind::RadiusGap,
+                        format!(
+                            "{:.1}m gap between {:.1}m and {:.1}m",
+                            step, previous.radius_m, point.radius_m
+                        ),
+                    )
+                    .at(point.source)
+                    .in_chart(chart),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::test_spec;
+
+    fn kinds(report: &ChartReport) -> Vec<DiagnosticKind> {
+        report.diagnostics.iter().map(|d| d.kind).collect()
+    }
+
+    #[test]
+    fn test_csv_reports_every_problem() {
+        let csv = "\
+boom_length,radius,capacity
+30.0,3.0,100000
+30.0,5.0,abc
+30.0,10.0,40000
+30.0,12.0,45000
+30.0,12.0,45000
+30.0,25.0,10000
+99.0,3.0,500000
+";
+        let spec = test_spec("liebherr_ltm_1100_5_2");
+        let report = ChartValidator::default().validate_source(csv, ChartFormat::Csv, Some(&spec));
+        let kinds = kinds(&report);
+
+        assert!(kinds.contains(&DiagnosticKind::Parse));
+        assert!(kinds.contains(&DiagnosticKind::NonMonotonic));
+        assert!(kinds.contains(&DiagnosticKind::DuplicatePoint));
+        assert!(kinds.contains(&DiagnosticKind::RadiusGap));
+        assert!(kinds.contains(&DiagnosticKind::ExceedsMaxCapacity));
+        assert!(kinds.contains(&DiagnosticKind::BoomLengthOutOfRange));
+
+        // Bad cell is located at its line and column
+        let parse = report
+            .diagnostics
+            .iter()
+            .find(|d| d.kind == DiagnosticKind::Parse)
+            .unwrap();
+        assert_eq!(parse.location, Some(SourceLocation::cell(3, 3)));
+
+        let rising = report
+            .diagnostics
+            .iter()
+            .find(|d| d.kind == DiagnosticKind::NonMonotonic)
+            .unwrap();
+        assert_eq!(rising.location.map(|l| l.line), Some(5));
+        assert!(rising.to_string().contains("line 5"));
+    }
+
+    #[test]
+    fn test_matrix_and_table_locations() {
+        let text = "
+Radius(m)   30.0   40.0
+3.0         100    90
+10.0        40     x5
+20.0        50     20
+";
+        let report = ChartValidator::default().validate_source(text, ChartFormat::MatrixText, None);
+        let parse = &report.diagnostics[0];
+        assert_eq!(parse.kind, DiagnosticKind::Parse);
+        assert_eq!(parse.location, Some(SourceLocation::cell(4, 3)));
+        let rising = report
+            .diagnostics
+            .iter()
+            .find(|d| d.kind == DiagnosticKind::NonMonotonic)
+            .unwrap();
+        assert_eq!(rising.location, Some(SourceLocation::cell(5, 2)));
+
+        // Table lines that used to be skipped silently are reported
+        let table = "BOOM LENGTH: 30.0m\nRadius(m)  Capacity(kg)\n3.0 100000\n5.0 lots\n";
+        let report = ChartValidator::default().validate_source(table, ChartFormat::Table, None);
+        assert_eq!(kinds(&report), vec![DiagnosticKind::Skipped]);
+        assert_eq!(
+            report.diagnostics[0].location,
+            Some(SourceLocation::line(4))
+        );
+    }
+
+    #[test]
+    fn test_library_specs_are_clean() {
+        let validator = ChartValidator::default();
+        for spec in crate::crane_data::test_library().specs() {
+            let errors: Vec<String> = validator
+                .validate_spec(spec)
+                .iter()
+                .filter(|d| d.severity == Severity::Error)
+                .map(|d| d.to_string())
+                .collect();
+            assert!(errors.is_empty(), "{}: {:?}", spec.id, errors);
+        }
+    }
+}
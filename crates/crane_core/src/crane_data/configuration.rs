@@ -1,8 +1,14 @@
 use super::counterweight::CounterweightConfig;
+use super::crawler::CrawlerSpec;
+use super::deflection::BoomDeflection;
 use super::errors::{CraneConfigError, Result};
-use super::outriggers::OutriggerSystem;
+use super::jib::{JibSpec, JibState};
+use super::levelling::{LevellingResult, LevellingSolver, TiltEffect};
+use super::outriggers::{OutriggerSystem, TippingEdge};
 use super::spec::CraneSpec;
-use nalgebra::Point3;
+use super::telescoping::TelescopeState;
+use super::tower::TowerJibType;
+use nalgebra::{Point3, Vector2, Vector3};
 use serde::{Deserialize, Serialize};
 
 /// Complete crane configuration at a specific moment
@@ -20,6 +26,18 @@ pub struct CraneConfiguration {
     pub boom_angle_deg: f32,
     pub swing_angle_deg: f32, // Relative to crane heading
 
+    // Telescoping mode and section extension (None = continuous boom length)
+    #[serde(default)]
+    pub telescope: Option<TelescopeState>,
+
+    // Jib rigged on the boom tip (None = main boom only)
+    #[serde(default)]
+    pub jib: Option<JibState>,
+
+    // Trolley radius on a horizontal tower crane jib
+    #[serde(default)]
+    pub trolley_radius_m: Option<f32>,
+
     // Hoist configuration
     pub hoist_length_m: f32,
 
@@ -37,19 +55,112 @@ impl CraneConfiguration {
 
         let _default_hoist = spec.hoist_length_range.0 + spec.hoist_length_range.1;
 
+        // Tower cranes start with the full jib and the trolley parked in
+        let (boom_length_m, boom_angle_deg, trolley_radius_m) = match &spec.tower {
+            Some(tower) if tower.jib_type == TowerJibType::Horizontal => (
+                spec.boom_length_range.1,
+                0.0,
+                Some(tower.min_trolley_radius_m),
+            ),
+            Some(_) => (
+                spec.boom_length_range.1,
+                60.0_f32.clamp(spec.min_boom_angle_deg, spec.max_boom_angle_deg),
+                None,
+            ),
+            None => (30.0, 60.0, None),
+        };
+
         Self {
             spec,
             position: Point3::origin(),
             heading_deg: 0.0,
-            boom_length_m: 30.0,
-            boom_angle_deg: 60.0,
+            boom_length_m,
+            boom_angle_deg,
             swing_angle_deg: 0.0,
+            telescope: None,
+            jib: None,
+            trolley_radius_m,
             hoist_length_m: 10.0, // Default to 10m of cable
             outriggers,
             counterweight,
         }
     }
 
+    /// Move the trolley on a horizontal tower crane jib
+    pub fn set_trolley_radius(&mut self, radius_m: f32) -> Result<()> {
+        let min = match &self.spec.tower {
+            Some(tower) if tower.jib_type == TowerJibType::Horizontal => tower.min_trolley_radius_m,
+            _ => {
+                return Err(CraneConfigError::UnsafeConfiguration {
+                    reason: format!("{} has no trolley jib", self.spec.model),
+                });
+            }
+        };
+
+        if radius_m < min || radius_m > self.boom_length_m {
+            return Err(CraneConfigError::RadiusOutOfRange {
+                current: radius_m,
+                min,
+                max: self.boom_length_m,
+            });
+        }
+
+        self.trolley_radius_m = Some(radius_m);
+        Ok(())
+    }
+
+    /// Get trolley position on a horizontal tower crane jib
+    pub fn get_trolley_position(&self) -> Option<Point3<f32>> {
+        let radius = self.trolley_radius_m?;
+
+        Some(crate::kinematics::calculate_boom_tip_position(
+            self.position,
+            radius,
+            0.0,
+            self.swing_angle_deg + self.heading_deg,
+            self.spec.boom_pivot_height_m,
+        ))
+    }
+
+    /// Set section extensions, deriving the boom length from them
+    pub fn set_telescope(&mut self, state: TelescopeState) -> Result<()> {
+        let system =
+            self.spec
+                .telescoping
+                .as_ref()
+                .ok_or_else(|| CraneConfigError::TelescopingInvalid {
+                    reason: format!("{} has no telescoping modes", self.spec.model),
+                })?;
+
+        system.validate(&state)?;
+        self.boom_length_m = system.boom_length_m(&state.section_extension_pct);
+        self.telescope = Some(state);
+
+        Ok(())
+    }
+
+    /// Switch telescoping mode, choosing the step closest to the current boom length
+    pub fn set_telescoping_mode(&mut self, mode: &str) -> Result<()> {
+        let state = self
+            .spec
+            .telescoping
+            .as_ref()
+            .and_then(|system| system.closest_state(mode, self.boom_length_m))
+            .ok_or_else(|| CraneConfigError::TelescopingInvalid {
+                reason: format!("Unknown telescoping mode '{}'", mode),
+            })?;
+
+        self.set_telescope(state)
+    }
+
+    /// Number of telescopic sections extended beyond the base
+    pub fn extended_sections(&self) -> usize {
+        match &self.telescope {
+            Some(state) => state.extended_sections(),
+            None => self.spec.extended_sections(self.boom_length_m),
+        }
+    }
+
     /// Get boom tip position (where sheave/pulley is)
     pub fn get_boom_tip_position(&self) -> Point3<f32> {
         crate::kinematics::calculate_boom_tip_position(
@@ -61,22 +172,170 @@ impl CraneConfiguration {
         )
     }
 
-    /// Get hook position in world coordinates
-    pub fn get_hook_position(&self) -> Point3<f32> {
-        crate::kinematics::calculate_hook_position(
-            self.position,
-            self.boom_length_m,
+    /// Rig a jib on the boom tip, or remove it with `None`
+    pub fn set_jib(&mut self, jib: Option<JibState>) -> Result<()> {
+        if let Some(state) = &jib {
+            let spec =
+                self.spec
+                    .get_jib(&state.jib_id)
+                    .ok_or_else(|| CraneConfigError::JibInvalid {
+                        reason: format!("{} has no jib '{}'", self.spec.model, state.jib_id),
+                    })?;
+            spec.validate(state)?;
+        }
+
+        self.jib = jib;
+        Ok(())
+    }
+
+    /// Spec of the rigged jib
+    pub fn get_jib_spec(&self) -> Option<&JibSpec> {
+        self.jib
+            .as_ref()
+            .and_then(|state| self.spec.get_jib(&state.jib_id))
+    }
+
+    /// Get jib tip position (None without a jib)
+    pub fn get_jib_tip_position(&self) -> Option<Point3<f32>> {
+        let state = self.jib.as_ref()?;
+        let spec = self.get_jib_spec()?;
+
+        Some(crate::kinematics::calculate_jib_tip_position(
+            self.get_boom_tip_position(),
             self.boom_angle_deg,
             self.swing_angle_deg + self.heading_deg,
-            self.spec.boom_pivot_height_m,
-            self.hoist_length_m,
-        )
+            spec.pivot_offset_m,
+            state.length_m,
+            state.offset_deg,
+        ))
+    }
+
+    /// Get the tip the hoist line runs over (jib tip when a jib is rigged)
+    pub fn get_sheave_position(&self) -> Point3<f32> {
+        self.get_trolley_position()
+            .or_else(|| self.get_jib_tip_position())
+            .unwrap_or_else(|| self.get_boom_tip_position())
+    }
+
+    /// Get hook position in world coordinates
+    pub fn get_hook_position(&self) -> Point3<f32> {
+        let sheave = self.get_sheave_position();
+        Point3::new(sheave.x, sheave.y, sheave.z - self.hoist_length_m)
     }
 
     /// Get current operating radius
     pub fn get_radius(&self) -> f32 {
+        if let Some(trolley_radius) = self.trolley_radius_m {
+            return trolley_radius;
+        }
+
+        let boom_angle_rad = self.boom_angle_deg.to_radians();
+        let boom_radius = self.boom_length_m * boom_angle_rad.cos();
+
+        match (&self.jib, self.get_jib_spec()) {
+            (Some(state), Some(spec)) => {
+                let jib_angle_rad = (self.boom_angle_deg - state.offset_deg).to_radians();
+                boom_radius
+                    + spec.pivot_offset_m * boom_angle_rad.cos()
+                    + state.length_m * jib_angle_rad.cos()
+            }
+            _ => boom_radius,
+        }
+    }
+
+    /// Get boom deflection under a hook load (None if the spec has no deflection model)
+    pub fn get_boom_deflection(&self, hook_load_kg: f32) -> Option<BoomDeflection> {
+        let model = self.spec.boom_deflection?;
+
+        let tip_deflection_m = model.tip_deflection_m(
+            self.boom_length_m,
+            self.boom_angle_deg,
+            self.extended_sections(),
+            hook_load_kg,
+        );
+
+        // Deflection is normal to the boom, downward in the luffing plane
         let boom_angle_rad = self.boom_angle_deg.to_radians();
-        self.boom_length_m * boom_angle_rad.cos()
+        let radius_increase_m = tip_deflection_m * boom_angle_rad.sin();
+        let tip_drop_m = tip_deflection_m * boom_angle_rad.cos();
+
+        let swing_rad = (self.swing_angle_deg + self.heading_deg).to_radians();
+        let outward = Vector3::new(swing_rad.sin(), swing_rad.cos(), 0.0);
+
+        let unloaded_tip = self.get_boom_tip_position();
+        let loaded_tip = unloaded_tip + outward * radius_increase_m - Vector3::z() * tip_drop_m;
+
+        Some(BoomDeflection {
+            tip_deflection_m,
+            unloaded_tip,
+            loaded_tip,
+            radius_increase_m,
+            tip_drop_m,
+        })
+    }
+
+    /// Get boom tip position with the boom deflected under a hook load
+    pub fn get_loaded_boom_tip_position(&self, hook_load_kg: f32) -> Point3<f32> {
+        self.get_boom_deflection(hook_load_kg)
+            .map(|d| d.loaded_tip)
+            .unwrap_or_else(|| self.get_boom_tip_position())
+    }
+
+    /// Get hook position with the boom deflected under a hook load
+    pub fn get_loaded_hook_position(&self, hook_load_kg: f32) -> Point3<f32> {
+        // A rigged jib is carried along by the boom tip displacement
+        let hook = self.get_hook_position();
+        self.get_boom_deflection(hook_load_kg)
+            .map(|d| hook + (d.loaded_tip - d.unloaded_tip))
+            .unwrap_or(hook)
+    }
+
+    /// Get operating radius with the boom deflected under a hook load
+    pub fn get_loaded_radius(&self, hook_load_kg: f32) -> f32 {
+        self.get_radius()
+            + self
+                .get_boom_deflection(hook_load_kg)
+                .map_or(0.0, |d| d.radius_increase_m)
+            + self.get_tilt_effect().radius_increase_m
+    }
+
+    /// Hook displacement from the carrier standing out of level on its jacks
+    ///
+    /// The slew axis leans downhill, carrying the sheave sideways by its
+    /// height times the slope. Zero on tyres, tracks or a tower foundation.
+    pub fn get_tilt_effect(&self) -> TiltEffect {
+        if self.spec.crawler.is_some() || self.spec.tower.is_some() {
+            return TiltEffect::default();
+        }
+        let Some(slope) = self.outriggers.chassis_slope() else {
+            return TiltEffect::default();
+        };
+
+        let swing = self.swing_angle_deg.to_radians();
+        let along = Vector2::new(swing.sin(), swing.cos());
+        let across = Vector2::new(swing.cos(), -swing.sin());
+        let height = self.get_sheave_position().z - self.position.z;
+
+        TiltEffect {
+            slope,
+            tilt_pct: slope.norm() * 100.0,
+            radius_increase_m: -height * slope.dot(&along),
+            side_offset_m: -height * slope.dot(&across),
+        }
+    }
+
+    /// Capacity factor for the carrier being out of level beyond tolerance
+    pub fn get_out_of_level_factor(&self) -> f32 {
+        self.spec
+            .levelling_spec()
+            .capacity_factor(self.get_tilt_effect().tilt_pct)
+    }
+
+    /// Level the carrier on its jacks over the ground under each float
+    pub fn level_outriggers(&mut self) -> Result<LevellingResult> {
+        let result = LevellingSolver::solve(&self.outriggers, &self.spec.levelling_spec())?;
+        LevellingSolver::apply(&mut self.outriggers, &result);
+        Ok(result)
     }
 
     /// Get current hook height
@@ -86,38 +345,158 @@ impl CraneConfiguration {
 
     /// Get capacity at current configuration
     pub fn get_current_capacity(&self) -> Option<f32> {
-        let radius = self.get_radius();
+        self.get_capacity_at_radius(self.get_radius())
+    }
+
+    /// Get capacity at the radius reached with the boom deflected under a hook load
+    pub fn get_loaded_capacity(&self, hook_load_kg: f32) -> Option<f32> {
+        self.get_capacity_at_radius(self.get_loaded_radius(hook_load_kg))
+    }
+
+    /// Get capacity at a given radius for the current boom and support setup
+    pub fn get_capacity_at_radius(&self, radius: f32) -> Option<f32> {
+        if let Some(crawler) = &self.spec.crawler {
+            return self.get_crawler_capacity(crawler, radius);
+        }
+
+        // Tower cranes: radius chart for the jib length, independent of slew
+        if let Some(tower) = &self.spec.tower {
+            return self
+                .spec
+                .capacity_chart
+                .get_capacity(self.boom_length_m, radius, 0.0, 1.0, false)
+                .map(|c| {
+                    let tip_radius =
+                        tower.tip_radius_m(self.boom_length_m, self.spec.min_boom_angle_deg);
+                    tower.cap_at_tip(c.min(self.spec.max_capacity_kg), tip_radius, radius)
+                });
+        }
+
         let on_tires = !self.outriggers.all_deployed();
 
-        // Calculate average outrigger extension percentage
-        let avg_extension = if on_tires {
-            0.0
+        // Charts are for full span; shorter legs are derated by stability
+        // over the edge the boom works across (variable base)
+        let (extension, support_factor) = if on_tires {
+            (0.0, 1.0)
         } else {
-            let total: f32 = self
-                .outriggers
-                .outriggers
-                .iter()
-                .map(|o| o.extension_m / o.max_extension_m)
-                .sum();
-            total / self.outriggers.outriggers.len() as f32
+            (
+                1.0,
+                self.get_outrigger_capacity_factor(radius) * self.get_out_of_level_factor(),
+            )
         };
 
-        self.spec.capacity_chart.get_capacity(
-            self.boom_length_m,
-            radius,
-            self.swing_angle_deg,
-            avg_extension,
-            on_tires,
-        )
+        // Jib charts are indexed by main boom length and jib radius
+        if let Some(state) = &self.jib {
+            let jib = self.get_jib_spec()?;
+            let capacity = jib
+                .find_chart(state, self.boom_angle_deg)?
+                .chart
+                .get_capacity(
+                    self.boom_length_m,
+                    radius,
+                    self.swing_angle_deg,
+                    extension,
+                    on_tires,
+                )?;
+            return Some((capacity * support_factor).min(jib.max_capacity_kg));
+        }
+
+        self.spec
+            .capacity_chart
+            .get_capacity_for_mode(
+                self.telescope.as_ref().map(|t| t.mode.as_str()),
+                self.boom_length_m,
+                radius,
+                self.swing_angle_deg,
+                extension,
+                on_tires,
+            )
+            .map(|c| c * support_factor)
     }
 
-    /// Check if a load is within capacity
+    /// Hook load that tips the crane over the governing outrigger edge at
+    /// `radius` and the current slew, with that edge
+    ///
+    /// Rigid base, boom self-weight ignored. None when the hook is inside
+    /// the support polygon.
+    pub fn get_tipping_load_kg(&self, radius: f32) -> Option<(f32, TippingEdge)> {
+        self.tipping_load_over(&self.outriggers, radius)
+    }
+
+    /// Capacity factor for the actual leg spans against full span at the
+    /// current slew (1.0 when the short legs are not on the boom side)
+    pub fn get_outrigger_capacity_factor(&self, radius: f32) -> f32 {
+        if !self.outriggers.all_deployed() {
+            return 1.0;
+        }
+
+        let actual = self.tipping_load_over(&self.outriggers, radius);
+        let full = self.tipping_load_over(&self.outriggers.fully_extended(), radius);
+        match (actual, full) {
+            (Some((actual, _)), Some((full, _))) if full > 0.0 => (actual / full).clamp(0.0, 1.0),
+            _ => 1.0,
+        }
+    }
+
+    fn tipping_load_over(
+        &self,
+        outriggers: &OutriggerSystem,
+        radius: f32,
+    ) -> Option<(f32, TippingEdge)> {
+        let swing = self.swing_angle_deg.to_radians();
+        let direction = Vector2::new(swing.sin(), swing.cos());
+        let base_kg = self.spec.base_weight_kg;
+        let counterweight_kg = self.counterweight.get_total_weight_kg();
+        let arm = self.counterweight.moment_arm_m;
+
+        outriggers
+            .tipping_edges()
+            .into_iter()
+            .filter_map(|edge| {
+                let toward = direction.dot(&edge.normal);
+                let overturning_arm = radius * toward - edge.distance_m;
+                if overturning_arm <= 0.0 {
+                    return None;
+                }
+
+                // Counterweight sits behind the slew centre, opposite the boom
+                let restoring =
+                    base_kg * edge.distance_m + counterweight_kg * (edge.distance_m + arm * toward);
+                Some((restoring.max(0.0) / overturning_arm, edge))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+    }
+
+    /// Crawler capacity from the over-end or over-side chart for the current slew
+    fn get_crawler_capacity(&self, crawler: &CrawlerSpec, radius: f32) -> Option<f32> {
+        match &crawler.over_side_chart {
+            Some(over_side) if !crawler.is_over_end(self.swing_angle_deg) => {
+                over_side.get_capacity(self.boom_length_m, radius, 0.0, 1.0, false)
+            }
+            Some(_) => {
+                self.spec
+                    .capacity_chart
+                    .get_capacity(self.boom_length_m, radius, 0.0, 1.0, false)
+            }
+            // Single chart: fall back to its swing factors
+            None => self.spec.capacity_chart.get_capacity(
+                self.boom_length_m,
+                radius,
+                self.swing_angle_deg,
+                1.0,
+                false,
+            ),
+        }
+    }
+
+    /// Check if a load is within capacity (evaluated at the loaded radius)
     pub fn can_lift(&self, load_kg: f32) -> Result<bool> {
-        let capacity =
-            self.get_current_capacity()
-                .ok_or_else(|| CraneConfigError::UnsafeConfiguration {
-                    reason: "Cannot determine capacity for current configuration".to_string(),
-                })?;
+        let radius = self.get_loaded_radius(load_kg);
+        let capacity = self.get_capacity_at_radius(radius).ok_or_else(|| {
+            CraneConfigError::UnsafeConfiguration {
+                reason: "Cannot determine capacity for current configuration".to_string(),
+            }
+        })?;
 
         // Apply 75% of chart capacity as safe working load
         let safe_capacity = capacity * 0.75;
@@ -126,7 +505,7 @@ impl CraneConfiguration {
             return Err(CraneConfigError::LoadExceedsCapacity {
                 load_kg,
                 capacity_kg: capacity,
-                radius_m: self.get_radius(),
+                radius_m: radius,
             });
         }
 
@@ -146,6 +525,28 @@ impl CraneConfiguration {
             });
         }
 
+        // Check telescoping state and derived boom length
+        if let Some(state) = &self.telescope {
+            let system = self.spec.telescoping.as_ref().ok_or_else(|| {
+                CraneConfigError::TelescopingInvalid {
+                    reason: format!("{} has no telescoping modes", self.spec.model),
+                }
+            })?;
+            system.validate(state)?;
+
+            let derived = system.boom_length_m(&state.section_extension_pct);
+            if (derived - self.boom_length_m).abs() > 0.05 {
+                return Err(CraneConfigError::TelescopingInvalid {
+                    reason: format!(
+                        "Boom length {:.1}m does not match {:.1}m from sections {}",
+                        self.boom_length_m,
+                        derived,
+                        state.describe()
+                    ),
+                });
+            }
+        }
+
         // Check boom angle
         if self.boom_angle_deg < self.spec.min_boom_angle_deg
             || self.boom_angle_deg > self.spec.max_boom_angle_deg
@@ -155,9 +556,21 @@ impl CraneConfiguration {
             });
         }
 
-        // Check radius
+        // Check jib
+        if let Some(state) = &self.jib {
+            let spec = self
+                .get_jib_spec()
+                .ok_or_else(|| CraneConfigError::JibInvalid {
+                    reason: format!("{} has no jib '{}'", self.spec.model, state.jib_id),
+                })?;
+            spec.validate(state)?;
+        }
+
+        // Check radius (jib radius is limited by the jib charts instead)
         let radius = self.get_radius();
-        if radius < self.spec.min_radius_m || radius > self.spec.max_radius_m {
+        if self.jib.is_none()
+            && (radius < self.spec.min_radius_m || radius > self.spec.max_radius_m)
+        {
             return Err(CraneConfigError::RadiusOutOfRange {
                 current: radius,
                 min: self.spec.min_radius_m,
@@ -174,8 +587,22 @@ impl CraneConfiguration {
             });
         }
 
-        // Validate outriggers
-        self.outriggers.validate()?;
+        // Validate outriggers (crawlers stand on tracks, tower cranes on a foundation)
+        if self.spec.crawler.is_none() && self.spec.tower.is_none() {
+            self.outriggers.validate()?;
+
+            // Charts assume a level carrier within the manufacturer tolerance
+            let tolerance = self.spec.levelling_spec().level_tolerance_pct;
+            let tilt = self.get_tilt_effect().tilt_pct;
+            if tilt > tolerance {
+                return Err(CraneConfigError::UnsafeConfiguration {
+                    reason: format!(
+                        "Crane is {:.1}% out of level (tolerance {:.1}%)",
+                        tilt, tolerance
+                    ),
+                });
+            }
+        }
 
         // Validate counterweight
         self.counterweight.validate()?;
@@ -183,6 +610,16 @@ impl CraneConfiguration {
         Ok(())
     }
 
+    /// Move the crane axes to a motion state
+    pub fn apply_state(&mut self, state: &CraneState) {
+        self.position = state.position;
+        self.boom_length_m = state.boom_length_m;
+        self.boom_angle_deg = state.boom_angle_deg;
+        self.swing_angle_deg = state.swing_angle_deg;
+        self.hoist_length_m = state.hoist_length_m;
+        self.trolley_radius_m = state.trolley_radius_m;
+    }
+
     /// Get total crane weight (base + counterweight)
     pub fn get_total_weight_kg(&self) -> f32 {
         self.spec.base_weight_kg + self.counterweight.get_total_weight_kg()
@@ -195,6 +632,8 @@ pub struct CraneState {
     pub boom_length_m: f32,
     pub boom_angle_deg: f32,
     pub swing_angle_deg: f32,
+    pub hoist_length_m: f32,
+    pub trolley_radius_m: Option<f32>,
     pub position: Point3<f32>,
 }
 
@@ -204,7 +643,32 @@ impl From<&CraneConfiguration> for CraneState {
             boom_length_m: config.boom_length_m,
             boom_angle_deg: config.boom_angle_deg,
             swing_angle_deg: config.swing_angle_deg,
+            hoist_length_m: config.hoist_length_m,
+            trolley_radius_m: config.trolley_radius_m,
             position: config.position,
         }
     }
 }
+
+impl CraneState {
+    /// Linear blend towards `other` (`t` in 0..1)
+    ///
+    /// Swing takes the short way round, so 350° to 10° slews through 0°.
+    pub fn lerp(&self, other: &CraneState, t: f32) -> CraneState {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let swing_delta =
+            crate::kinematics::shortest_swing_delta(self.swing_angle_deg, other.swing_angle_deg);
+
+        CraneState {
+            boom_length_m: mix(self.boom_length_m, other.boom_length_m),
+            boom_angle_deg: mix(self.boom_angle_deg, other.boom_angle_deg),
+            swing_angle_deg: self.swing_angle_deg + swing_delta * t,
+            hoist_length_m: mix(self.hoist_length_m, other.hoist_length_m),
+            trolley_radius_m: self
+                .trolley_radius_m
+                .zip(other.trolley_radius_m)
+                .map(|(a, b)| mix(a, b)),
+            position: self.position + (other.position - self.position) * t,
+        }
+    }
+}
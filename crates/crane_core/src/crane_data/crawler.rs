@@ -0,0 +1,434 @@
+// crates/crane_core/src/crane_data/crawler.rs
+
+use super::capacity::CapacityChart;
+use super::configuration::CraneConfiguration;
+use super::errors::{CraneConfigError, Result};
+use crate::ground_bearing::SoilType;
+use nalgebra::Point3;
+use serde::{Deserialize, Serialize};
+
+const G: f32 = 9.81;
+
+/// Crawler undercarriage (replaces outriggers for crawler cranes)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlerSpec {
+    /// Centre-to-centre distance between the tracks
+    pub track_gauge_m: f32,
+    /// Track length in contact with the ground (tumbler to idler)
+    pub track_length_m: f32,
+    pub track_shoe_width_m: f32,
+    /// Height of the machine's centre of gravity (carbody, superstructure and
+    /// counterweight) above the track ground line
+    pub cog_height_m: f32,
+    /// Over-side chart; the spec's main chart is used over the ends
+    pub over_side_chart: Option<CapacityChart>,
+    /// Fraction of stationary capacity allowed when travelling with load
+    pub pick_and_carry_factor: f32,
+    /// Maximum travel grade with load (percent)
+    pub max_travel_grade_pct: f32,
+}
+
+impl CrawlerSpec {
+    /// Half-angle of the over-end zone, measured from the track axis
+    ///
+    /// The boom is over the end while it passes over the track ends,
+    /// i.e. within the angle subtended by the outer track corners.
+    pub fn over_end_half_angle_deg(&self) -> f32 {
+        let half_width = (self.track_gauge_m + self.track_shoe_width_m) / 2.0;
+        half_width.atan2(self.track_length_m / 2.0).to_degrees()
+    }
+
+    /// Whether a slew angle (relative to the carbody) is over the track ends
+    pub fn is_over_end(&self, swing_angle_deg: f32) -> bool {
+        let from_axis = swing_angle_deg.rem_euclid(180.0);
+        let from_axis = from_axis.min(180.0 - from_axis);
+        from_axis <= self.over_end_half_angle_deg()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrackSide {
+    Left,
+    Right,
+}
+
+/// Pressure under one track (linear distribution along the track)
+#[derive(Debug, Clone)]
+pub struct TrackPressure {
+    pub side: TrackSide,
+    pub vertical_load_kg: f32,
+    /// Offset of the resultant from the track centre (positive forward)
+    pub eccentricity_m: f32,
+    /// Length of track in contact (less than track length when the far end lifts)
+    pub contact_length_m: f32,
+    pub max_pressure_kpa: f32,
+    pub min_pressure_kpa: f32,
+}
+
+/// Track ground pressures for one superstructure slew angle
+#[derive(Debug, Clone)]
+pub struct CrawlerGroundPressure {
+    pub swing_angle_deg: f32,
+    pub over_end: bool,
+    pub tracks: [TrackPressure; 2],
+    pub max_pressure_kpa: f32,
+    /// False if a track unloads or the resultant leaves the track
+    pub is_stable: bool,
+}
+
+impl CrawlerGroundPressure {
+    /// Utilisation of allowable soil bearing pressure
+    pub fn utilization_percent(&self, soil: SoilType, safety_factor: f32) -> f32 {
+        let allowable = soil.allowable_bearing_capacity_kpa() / safety_factor;
+        self.max_pressure_kpa / allowable * 100.0
+    }
+}
+
+/// Result at one waypoint of a pick-and-carry travel path
+#[derive(Debug, Clone)]
+pub struct TravelCheckPoint {
+    pub position: Point3<f32>,
+    pub heading_deg: f32,
+    pub grade_pct: f32,
+    pub capacity_kg: f32,
+    pub utilization_percent: f32,
+    pub ground_pressure: CrawlerGroundPressure,
+    pub is_safe: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TravelCheck {
+    pub points: Vec<TravelCheckPoint>,
+    pub max_utilization_percent: f32,
+    pub is_safe: bool,
+    pub warnings: Vec<String>,
+}
+
+pub struct CrawlerCalculator;
+
+impl CrawlerCalculator {
+    fn crawler_spec(config: &CraneConfiguration) -> Result<&CrawlerSpec> {
+        config
+            .spec
+            .crawler
+            .as_ref()
+            .ok_or_else(|| CraneConfigError::UnsafeConfiguration {
+                reason: format!("{} has no crawler undercarriage", config.spec.model),
+            })
+    }
+
+    /// Track pressures with a hook load at the current configuration
+    ///
+    /// Crane base weight acts at the slewing centre, counterweight behind it
+    /// and the hook load at the operating radius.
+    pub fn ground_pressure(
+        config: &CraneConfiguration,
+        hook_load_kg: f32,
+    ) -> Result<CrawlerGroundPressure> {
+        Self::ground_pressure_on_grade(config, hook_load_kg, 0.0)
+    }
+
+    /// Track pressures on a pad graded along the tracks
+    ///
+    /// `grade_pct` is positive when the front of the carbody is uphill. The
+    /// machine and hook load act at their heights above the track ground
+    /// line, so the combined CoG moves downhill by height × sin(grade).
+    pub fn ground_pressure_on_grade(
+        config: &CraneConfiguration,
+        hook_load_kg: f32,
+        grade_pct: f32,
+    ) -> Result<CrawlerGroundPressure> {
+        let crawler = Self::crawler_spec(config)?;
+
+        let machine_kg = config.get_total_weight_kg();
+        let vertical_kg = machine_kg + hook_load_kg;
+        let moment_kgm = hook_load_kg * config.get_loaded_radius(hook_load_kg)
+            - config.counterweight.get_total_weight_kg() * config.counterweight.moment_arm_m;
+
+        // The hook load hangs from the tip sheave
+        let sheave_height_m = config.get_sheave_position().z - config.position.z;
+        let grade_sin = (grade_pct / 100.0).atan().sin();
+        let grade_moment_kgm =
+            (machine_kg * crawler.cog_height_m + hook_load_kg * sheave_height_m) * grade_sin;
+
+        // Resolve into carbody axes (x across tracks, y along tracks)
+        let swing_rad = config.swing_angle_deg.to_radians();
+        let moment_across = moment_kgm * swing_rad.sin();
+        let moment_along = moment_kgm * swing_rad.cos() - grade_moment_kgm;
+
+        let right_kg = vertical_kg / 2.0 + moment_across / crawler.track_gauge_m;
+        let left_kg = vertical_kg / 2.0 - moment_across / crawler.track_gauge_m;
+
+        let left = Self::track_pressure(crawler, TrackSide::Left, left_kg, moment_along / 2.0);
+        let right = Self::track_pressure(crawler, TrackSide::Right, right_kg, moment_along / 2.0);
+
+        let is_stable = [&left, &right].iter().all(|t| {
+            t.vertical_load_kg > 0.0 && t.eccentricity_m.abs() < crawler.track_length_m / 2.0
+        });
+
+        Ok(CrawlerGroundPressure {
+            swing_angle_deg: config.swing_angle_deg,
+            over_end: crawler.is_over_end(config.swing_angle_deg),
+            max_pressure_kpa: left.max_pressure_kpa.max(right.max_pressure_kpa),
+            tracks: [left, right],
+            is_stable,
+        })
+    }
+
+    /// Track pressures as the superstructure slews a full circle
+    pub fn ground_pressure_over_slew(
+        config: &CraneConfiguration,
+        hook_load_kg: f32,
+        step_deg: f32,
+    ) -> Result<Vec<CrawlerGroundPressure>> {
+        let steps = (360.0 / step_deg.max(1.0)).ceil() as usize;
+        let mut slewed = config.clone();
+
+        (0..steps)
+            .map(|i| {
+                slewed.swing_angle_deg = i as f32 * step_deg.max(1.0);
+                Self::ground_pressure(&slewed, hook_load_kg)
+            })
+            .collect()
+    }
+
+    fn track_pressure(
+        crawler: &CrawlerSpec,
+        side: TrackSide,
+        vertical_kg: f32,
+        moment_along_kgm: f32,
+    ) -> TrackPressure {
+        let length = crawler.track_length_m;
+        let width = crawler.track_shoe_width_m;
+
+        if vertical_kg <= 0.0 {
+            return TrackPressure {
+                side,
+                vertical_load_kg: vertical_kg,
+                eccentricity_m: 0.0,
+                contact_length_m: 0.0,
+                max_pressure_kpa: 0.0,
+                min_pressure_kpa: 0.0,
+            };
+        }
+
+        let force_kn = vertical_kg * G / 1000.0;
+        let eccentricity = moment_along_kgm / vertical_kg;
+        let e = eccentricity.abs();
+
+        let (contact_length, max_pressure, min_pressure) = if e <= length / 6.0 {
+            // Trapezoidal: full track in contact
+            let average = force_kn / (width * length);
+            (
+                length,
+                average * (1.0 + 6.0 * e / length),
+                average * (1.0 - 6.0 * e / length),
+            )
+        } else {
+            // Triangular: far end of the track unloads
+            let contact = (3.0 * (length / 2.0 - e)).max(0.0);
+            let max = if contact > 0.0 {
+                2.0 * force_kn / (width * contact)
+            } else {
+                f32::INFINITY
+            };
+            (contact, max, 0.0)
+        };
+
+        TrackPressure {
+            side,
+            vertical_load_kg: vertical_kg,
+            eccentricity_m: eccentricity,
+            contact_length_m: contact_length,
+            max_pressure_kpa: max_pressure,
+            min_pressure_kpa: min_pressure,
+        }
+    }
+
+    /// Check pick-and-carry travel along a path of ground points
+    ///
+    /// The crawler travels forward along each segment with the superstructure
+    /// locked at its current slew relative to the carbody.
+    pub fn check_travel(
+        config: &CraneConfiguration,
+        hook_load_kg: f32,
+        path: &[Point3<f32>],
+        soil: Option<(SoilType, f32)>,
+    ) -> Result<TravelCheck> {
+        let crawler = Self::crawler_spec(config)?;
+        if path.len() < 2 {
+            return Err(CraneConfigError::UnsafeConfiguration {
+                reason: "Travel path needs at least two points".to_string(),
+            });
+        }
+
+        let mut points = Vec::with_capacity(path.len());
+        let mut warnings = Vec::new();
+        let mut state = config.clone();
+
+        for (i, &position) in path.iter().enumerate() {
+            // Final waypoint keeps the heading and grade of the last segment
+            let (from, to) = if i + 1 < path.len() {
+                (position, path[i + 1])
+            } else {
+                (path[i - 1], position)
+            };
+
+            let delta = to - from;
+            let horizontal = (delta.x.powi(2) + delta.y.powi(2)).sqrt();
+            let heading_deg = delta.x.atan2(delta.y).to_degrees();
+            // Positive when the carbody front is uphill
+            let slope_pct = if horizontal > 0.0 {
+                delta.z / horizontal * 100.0
+            } else {
+                0.0
+            };
+            let grade_pct = slope_pct.abs();
+
+            state.position = position;
+            state.heading_deg = heading_deg;
+
+            let capacity_kg = state.get_loaded_capacity(hook_load_kg).unwrap_or(0.0)
+                * crawler.pick_and_carry_factor;
+            let utilization_percent = if capacity_kg > 0.0 {
+                hook_load_kg / capacity_kg * 100.0
+            } else {
+                f32::INFINITY
+            };
+            let ground_pressure = Self::ground_pressure_on_grade(&state, hook_load_kg, slope_pct)?;
+
+            let mut is_safe = true;
+            if grade_pct > crawler.max_travel_grade_pct {
+                is_safe = false;
+                warnings.push(format!(
+                    "Waypoint {}: grade {:.1}% exceeds {:.1}% travel limit",
+                    i, grade_pct, crawler.max_travel_grade_pct
+                ));
+            }
+            if utilization_percent > 100.0 {
+                is_safe = false;
+                warnings.push(format!(
+                    "Waypoint {}: load {:.0}kg exceeds pick-and-carry capacity {:.0}kg",
+                    i, hook_load_kg, capacity_kg
+                ));
+            }
+            if !ground_pressure.is_stable {
+                is_safe = false;
+                warnings.push(format!("Waypoint {}: track unloads, crane unstable", i));
+            }
+            if let Some((soil, safety_factor)) = soil {
+                let ground_utilization = ground_pressure.utilization_percent(soil, safety_factor);
+                if ground_utilization > 100.0 {
+                    is_safe = false;
+                    warnings.push(format!(
+                        "Waypoint {}: track pressure {:.0}kPa at {:.0}% of allowable",
+                        i, ground_pressure.max_pressure_kpa, ground_utilization
+                    ));
+                }
+            }
+
+            points.push(TravelCheckPoint {
+                position,
+                heading_deg,
+                grade_pct,
+                capacity_kg,
+                utilization_percent,
+                ground_pressure,
+                is_safe,
+            });
+        }
+
+        Ok(TravelCheck {
+            max_utilization_percent: points
+                .iter()
+                .map(|p| p.utilization_percent)
+                .fold(0.0, f32::max),
+            is_safe: points.iter().all(|p| p.is_safe),
+            points,
+            warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::test_spec;
+
+    fn crawler_config() -> CraneConfiguration {
+        let mut config = CraneConfiguration::new(test_spec("liebherr_lr_1300_sx"));
+        config.boom_length_m = 56.0;
+        config.boom_angle_deg = 70.0;
+        config.counterweight.set_slab_count(6).unwrap();
+        config
+    }
+
+    #[test]
+    fn test_over_end_zone() {
+        let crawler = test_spec("liebherr_lr_1300_sx").crawler.unwrap();
+        assert!(crawler.is_over_end(0.0));
+        assert!(crawler.is_over_end(180.0));
+        assert!(crawler.is_over_end(-10.0));
+        assert!(!crawler.is_over_end(90.0));
+        assert!(!crawler.is_over_end(270.0));
+    }
+
+    #[test]
+    fn test_track_pressure_over_side() {
+        let mut config = crawler_config();
+
+        config.swing_angle_deg = 0.0;
+        let over_end = CrawlerCalculator::ground_pressure(&config, 50_000.0).unwrap();
+        let [left, right] = &over_end.tracks;
+        assert!((left.vertical_load_kg - right.vertical_load_kg).abs() < 1.0);
+
+        config.swing_angle_deg = 90.0;
+        let over_side = CrawlerCalculator::ground_pressure(&config, 50_000.0).unwrap();
+        let [left, right] = &over_side.tracks;
+        assert!(right.vertical_load_kg > left.vertical_load_kg);
+        assert!(!over_side.over_end);
+
+        let sweep = CrawlerCalculator::ground_pressure_over_slew(&config, 50_000.0, 30.0).unwrap();
+        assert_eq!(sweep.len(), 12);
+    }
+
+    #[test]
+    fn test_track_pressure_on_grade() {
+        let mut config = crawler_config();
+        config.swing_angle_deg = 90.0;
+
+        let level = CrawlerCalculator::ground_pressure(&config, 20_000.0).unwrap();
+        let uphill = CrawlerCalculator::ground_pressure_on_grade(&config, 20_000.0, 4.0).unwrap();
+        let downhill =
+            CrawlerCalculator::ground_pressure_on_grade(&config, 20_000.0, -4.0).unwrap();
+
+        // Boom over the side, so only the grade moves the resultant along the tracks
+        for (track, level_track) in uphill.tracks.iter().zip(&level.tracks) {
+            assert!(level_track.eccentricity_m.abs() < 1e-3);
+            assert!(track.eccentricity_m < -0.05);
+            assert!((track.vertical_load_kg - level_track.vertical_load_kg).abs() < 1.0);
+        }
+        assert!(uphill.max_pressure_kpa > level.max_pressure_kpa);
+        assert!((uphill.tracks[0].eccentricity_m + downhill.tracks[0].eccentricity_m).abs() < 1e-3);
+
+        // The travel check sees the grade of each segment
+        let graded = [Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 20.0, 0.8)];
+        let check = CrawlerCalculator::check_travel(&config, 20_000.0, &graded, None).unwrap();
+        let pressure = &check.points[0].ground_pressure;
+        assert!((pressure.tracks[0].eccentricity_m - uphill.tracks[0].eccentricity_m).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_travel_grade_limit() {
+        let config = crawler_config();
+        let flat = [Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 20.0, 0.0)];
+        let steep = [Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 20.0, 2.0)];
+
+        let check = CrawlerCalculator::check_travel(&config, 10_000.0, &flat, None).unwrap();
+        assert!(check.is_safe, "{:?}", check.warnings);
+
+        let check = CrawlerCalculator::check_travel(&config, 10_000.0, &steep, None).unwrap();
+        assert!(!check.is_safe);
+        assert!(check.warnings.iter().any(|w| w.contains("grade")));
+    }
+}
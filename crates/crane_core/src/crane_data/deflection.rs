@@ -0,0 +1,100 @@
+// crates/crane_core/src/crane_data/deflection.rs
+
+use nalgebra::Point3;
+use serde::{Deserialize, Serialize};
+
+const G: f32 = 9.81;
+
+/// Elastic boom deflection model (cantilever beam in the luffing plane)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BoomDeflectionModel {
+    /// Flexural rigidity EI of the base section alone (N·m²)
+    pub base_flexural_rigidity_nm2: f32,
+    /// EI multiplier for each telescopic section extended beyond the base
+    pub rigidity_factor_per_section: f32,
+}
+
+impl BoomDeflectionModel {
+    /// Effective EI with `extended_sections` telescopic sections out
+    pub fn effective_rigidity_nm2(&self, extended_sections: usize) -> f32 {
+        self.base_flexural_rigidity_nm2
+            * self
+                .rigidity_factor_per_section
+                .powi(extended_sections as i32)
+    }
+
+    /// Additional tip deflection perpendicular to the boom under a tip load
+    ///
+    /// Cantilever with the load component normal to the boom: `P·L³ / 3EI`.
+    /// Self-weight deflection is already present in the unloaded geometry.
+    pub fn tip_deflection_m(
+        &self,
+        boom_length_m: f32,
+        boom_angle_deg: f32,
+        extended_sections: usize,
+        tip_load_kg: f32,
+    ) -> f32 {
+        let ei = self.effective_rigidity_nm2(extended_sections);
+        if ei <= 0.0 || boom_length_m <= 0.0 {
+            return 0.0;
+        }
+
+        let normal_force = tip_load_kg.max(0.0) * G * boom_angle_deg.to_radians().cos();
+        normal_force * boom_length_m.powi(3) / (3.0 * ei)
+    }
+}
+
+/// Loaded boom geometry
+#[derive(Debug, Clone, Copy)]
+pub struct BoomDeflection {
+    /// Deflection perpendicular to the boom (meters)
+    pub tip_deflection_m: f32,
+    pub unloaded_tip: Point3<f32>,
+    pub loaded_tip: Point3<f32>,
+    /// Horizontal growth in radius from deflection
+    pub radius_increase_m: f32,
+    /// Drop in tip elevation from deflection
+    pub tip_drop_m: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{CraneConfiguration, test_spec};
+
+    #[test]
+    fn test_deflection_scales_with_length_cubed() {
+        let model = BoomDeflectionModel {
+            base_flexural_rigidity_nm2: 1.0e10,
+            rigidity_factor_per_section: 1.0,
+        };
+
+        let short = model.tip_deflection_m(20.0, 0.0, 0, 10_000.0);
+        let long = model.tip_deflection_m(40.0, 0.0, 0, 10_000.0);
+        assert!((long / short - 8.0).abs() < 1e-3);
+
+        // No normal component with a vertical boom
+        assert!(model.tip_deflection_m(40.0, 90.0, 0, 10_000.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_loaded_radius_increases() {
+        let mut config = CraneConfiguration::new(test_spec("liebherr_ltm_1100_5_2"));
+        config.boom_length_m = 52.0;
+        config.boom_angle_deg = 60.0;
+
+        let deflection = config.get_boom_deflection(10_000.0).unwrap();
+        assert!(deflection.radius_increase_m > 0.0);
+        assert!(deflection.loaded_tip.z < deflection.unloaded_tip.z);
+        assert!(
+            (config.get_loaded_radius(10_000.0)
+                - config.get_radius()
+                - deflection.radius_increase_m)
+                .abs()
+                < 1e-4
+        );
+
+        config.spec.boom_deflection = None;
+        assert_eq!(config.get_loaded_radius(10_000.0), config.get_radius());
+    }
+}
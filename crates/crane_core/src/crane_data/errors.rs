@@ -1,3 +1,4 @@
+use super::units::UnitSystem;
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -40,83 +41,96 @@ pub enum CraneConfigError {
     CapacityChartNotFound {
         boom_length: f32,
     },
+    TelescopingInvalid {
+        reason: String,
+    },
+    JibInvalid {
+        reason: String,
+    },
     UnsafeConfiguration {
         reason: String,
     },
 }
 
-impl fmt::Display for CraneConfigError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl CraneConfigError {
+    /// Message with lengths and weights in a unit system
+    ///
+    /// Free-text reasons are kept as written.
+    pub fn describe(&self, units: UnitSystem) -> String {
         match self {
-            CraneConfigError::BoomLengthOutOfRange { current, min, max } => {
-                write!(
-                    f,
-                    "Boom length {:.1}m is out of range ({:.1}m - {:.1}m)",
-                    current, min, max
-                )
-            }
+            CraneConfigError::BoomLengthOutOfRange { current, min, max } => format!(
+                "Boom length {} is out of range ({} - {})",
+                units.length(*current),
+                units.length(*min),
+                units.length(*max)
+            ),
             CraneConfigError::BoomAngleInvalid { angle } => {
-                write!(f, "Boom angle {:.1}° is invalid (must be 0-85°)", angle)
-            }
-            CraneConfigError::RadiusOutOfRange { current, min, max } => {
-                write!(
-                    f,
-                    "Radius {:.1}m is out of range ({:.1}m - {:.1}m)",
-                    current, min, max
-                )
-            }
-            CraneConfigError::HeightExceeded { current, max } => {
-                write!(f, "Hook height {:.1}m exceeds maximum {:.1}m", current, max)
+                format!("Boom angle {:.1}° is invalid (must be 0-85°)", angle)
             }
+            CraneConfigError::RadiusOutOfRange { current, min, max } => format!(
+                "Radius {} is out of range ({} - {})",
+                units.length(*current),
+                units.length(*min),
+                units.length(*max)
+            ),
+            CraneConfigError::HeightExceeded { current, max } => format!(
+                "Hook height {} exceeds maximum {}",
+                units.length(*current),
+                units.length(*max)
+            ),
             CraneConfigError::LoadExceedsCapacity {
                 load_kg,
                 capacity_kg,
                 radius_m,
-            } => {
-                write!(
-                    f,
-                    "Load {:.0}kg exceeds capacity {:.0}kg at {:.1}m radius",
-                    load_kg, capacity_kg, radius_m
-                )
-            }
+            } => format!(
+                "Load {} exceeds capacity {} at {} radius",
+                units.mass(*load_kg),
+                units.mass(*capacity_kg),
+                units.length(*radius_m)
+            ),
             CraneConfigError::OutriggerPositionInvalid { position } => {
-                write!(f, "Invalid outrigger position: {}", position)
+                format!("Invalid outrigger position: {}", position)
             }
             CraneConfigError::OutriggerExtensionInvalid {
                 extension,
                 min,
                 max,
-            } => {
-                write!(
-                    f,
-                    "Outrigger extension {:.1}m out of range ({:.1}m - {:.1}m)",
-                    extension, min, max
-                )
-            }
+            } => format!(
+                "Outrigger extension {} out of range ({} - {})",
+                units.length(*extension),
+                units.length(*min),
+                units.length(*max)
+            ),
             CraneConfigError::CounterweightInvalid {
                 weight_kg,
                 min,
                 max,
-            } => {
-                write!(
-                    f,
-                    "Counterweight {:.0}kg invalid (must be {:.0}kg - {:.0}kg)",
-                    weight_kg, min, max
-                )
-            }
-            CraneConfigError::CapacityChartNotFound { boom_length } => {
-                write!(
-                    f,
-                    "No capacity chart found for boom length {:.1}m",
-                    boom_length
-                )
+            } => format!(
+                "Counterweight {} invalid (must be {} - {})",
+                units.mass(*weight_kg),
+                units.mass(*min),
+                units.mass(*max)
+            ),
+            CraneConfigError::CapacityChartNotFound { boom_length } => format!(
+                "No capacity chart found for boom length {}",
+                units.length(*boom_length)
+            ),
+            CraneConfigError::TelescopingInvalid { reason } => {
+                format!("Invalid telescoping: {}", reason)
             }
+            CraneConfigError::JibInvalid { reason } => format!("Invalid jib: {}", reason),
             CraneConfigError::UnsafeConfiguration { reason } => {
-                write!(f, "Unsafe configuration: {}", reason)
+                format!("Unsafe configuration: {}", reason)
             }
         }
     }
 }
 
+impl fmt::Display for CraneConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe(UnitSystem::Metric))
+    }
+}
+
 impl std::error::Error for CraneConfigError {}
 pub type Result<T> = std::result::Result<T, CraneConfigError>;
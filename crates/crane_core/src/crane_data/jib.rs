@@ -0,0 +1,188 @@
+// crates/crane_core/src/crane_data/jib.rs
+
+use super::capacity::CapacityChart;
+use super::errors::{CraneConfigError, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JibType {
+    /// Swing-away lattice fly jib pinned at fixed offsets (e.g. 0°/20°/40°)
+    FlyJib,
+    /// Luffing jib with a continuously variable angle to the boom
+    LuffingJib,
+}
+
+/// Load chart for one jib length at one offset (or main boom angle for luffing jibs)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JibLoadChart {
+    pub jib_length_m: f32,
+    /// Fly jib offset this chart applies to
+    pub offset_deg: Option<f32>,
+    /// Main boom angle this chart applies to (luffing jibs)
+    pub boom_angle_deg: Option<f32>,
+    /// Capacity by main boom length and jib radius
+    pub chart: CapacityChart,
+}
+
+/// Jib definition on a crane spec
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JibSpec {
+    pub id: String,
+    pub name: String,
+    pub jib_type: JibType,
+    pub lengths_m: Vec<f32>,
+    /// Fixed offsets below the boom centreline (fly jibs)
+    pub offsets_deg: Vec<f32>,
+    /// Allowed offset range below the boom centreline (luffing jibs)
+    pub offset_range_deg: Option<(f32, f32)>,
+    /// Distance from the boom tip to the jib pivot along the boom axis
+    pub pivot_offset_m: f32,
+    pub weight_kg: f32,
+    pub max_capacity_kg: f32,
+    pub load_charts: Vec<JibLoadChart>,
+}
+
+impl JibSpec {
+    /// Check a jib state is a legal length/offset for this jib
+    pub fn validate(&self, state: &JibState) -> Result<()> {
+        if !self
+            .lengths_m
+            .iter()
+            .any(|l| (l - state.length_m).abs() < 0.05)
+        {
+            return Err(CraneConfigError::JibInvalid {
+                reason: format!("{} has no {:.1}m length", self.name, state.length_m),
+            });
+        }
+
+        let offset_ok = match self.jib_type {
+            JibType::FlyJib => self
+                .offsets_deg
+                .iter()
+                .any(|o| (o - state.offset_deg).abs() < 0.5),
+            JibType::LuffingJib => self
+                .offset_range_deg
+                .is_some_and(|(min, max)| (min..=max).contains(&state.offset_deg)),
+        };
+
+        if !offset_ok {
+            return Err(CraneConfigError::JibInvalid {
+                reason: format!(
+                    "{:.0}° offset not allowed for {}",
+                    state.offset_deg, self.name
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Find the chart for a jib state (closest length, matching offset or boom angle)
+    pub fn find_chart(&self, state: &JibState, boom_angle_deg: f32) -> Option<&JibLoadChart> {
+        let length = self
+            .load_charts
+            .iter()
+            .map(|c| c.jib_length_m)
+            .min_by(|a, b| {
+                (a - state.length_m)
+                    .abs()
+                    .total_cmp(&(b - state.length_m).abs())
+            })?;
+
+        let candidates = self
+            .load_charts
+            .iter()
+            .filter(|c| (c.jib_length_m - length).abs() < 0.05);
+
+        match self.jib_type {
+            JibType::FlyJib => candidates
+                .filter(|c| {
+                    c.offset_deg
+                        .is_none_or(|o| (o - state.offset_deg).abs() < 0.5)
+                })
+                .min_by_key(|c| c.offset_deg.is_none()),
+            JibType::LuffingJib => candidates.min_by(|a, b| {
+                let da = a
+                    .boom_angle_deg
+                    .map_or(f32::MAX, |x| (x - boom_angle_deg).abs());
+                let db = b
+                    .boom_angle_deg
+                    .map_or(f32::MAX, |x| (x - boom_angle_deg).abs());
+                da.total_cmp(&db)
+            }),
+        }
+    }
+}
+
+/// Jib currently rigged on the boom
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JibState {
+    pub jib_id: String,
+    pub length_m: f32,
+    /// Angle between boom centreline and jib, positive below the boom
+    pub offset_deg: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{CraneConfiguration, test_spec};
+
+    fn fly_jib_config(offset_deg: f32) -> CraneConfiguration {
+        let mut config = CraneConfiguration::new(test_spec("liebherr_ltm_1100_5_2"));
+        config.boom_length_m = 50.0;
+        config.boom_angle_deg = 60.0;
+        config.outriggers.preset_max_extension();
+        config
+            .set_jib(Some(JibState {
+                jib_id: "ltm_1100_fly_jib".to_string(),
+                length_m: 10.5,
+                offset_deg,
+            }))
+            .unwrap();
+        config
+    }
+
+    #[test]
+    fn test_jib_tip_kinematics() {
+        let config = fly_jib_config(0.0);
+        let boom_tip = config.get_boom_tip_position();
+        let jib_tip = config.get_jib_tip_position().unwrap();
+
+        // Zero offset continues along the boom axis
+        assert!(((jib_tip - boom_tip).norm() - 11.3).abs() < 1e-3);
+        assert!((config.get_radius() - 61.3 * 0.5).abs() < 1e-3);
+
+        let hook = config.get_hook_position();
+        assert!((hook.z - (jib_tip.z - config.hoist_length_m)).abs() < 1e-4);
+
+        // Offsetting the jib increases radius and lowers the tip
+        let offset = fly_jib_config(40.0);
+        assert!(offset.get_radius() > config.get_radius());
+        assert!(offset.get_jib_tip_position().unwrap().z < jib_tip.z);
+    }
+
+    #[test]
+    fn test_jib_chart_lookup() {
+        let zero = fly_jib_config(0.0);
+        let forty = fly_jib_config(40.0);
+
+        let zero_capacity = zero.get_capacity_at_radius(30.0).unwrap();
+        let forty_capacity = forty.get_capacity_at_radius(30.0).unwrap();
+        assert!((zero_capacity - 4500.0).abs() < 1.0);
+        assert!((forty_capacity - 3200.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_invalid_jib_offset() {
+        let mut config = CraneConfiguration::new(test_spec("liebherr_ltm_1100_5_2"));
+        let result = config.set_jib(Some(JibState {
+            jib_id: "ltm_1100_fly_jib".to_string(),
+            length_m: 10.5,
+            offset_deg: 30.0,
+        }));
+
+        assert!(matches!(result, Err(CraneConfigError::JibInvalid { .. })));
+        assert!(config.jib.is_none());
+    }
+}
@@ -0,0 +1,243 @@
+// crates/crane_core/src/crane_data/levelling.rs
+
+use super::errors::{CraneConfigError, Result};
+use super::outriggers::{OutriggerDeployment, OutriggerPosition, OutriggerSystem};
+use nalgebra::Vector2;
+use serde::{Deserialize, Serialize};
+
+/// Jack stroke, cribbing and out-of-level limits for a carrier
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LevellingSpec {
+    /// Shortest jack stroke that still carries the carrier clear of its tyres (m)
+    pub min_jack_stroke_m: f32,
+    pub max_jack_stroke_m: f32,
+    /// Out-of-level the load charts allow (percent slope)
+    pub level_tolerance_pct: f32,
+    /// Capacity lost per percent of slope beyond the tolerance (fraction)
+    pub derate_per_pct: f32,
+    /// Thickness of one cribbing layer (m)
+    pub cribbing_layer_m: f32,
+    /// Tallest cribbing stack allowed under a float (m)
+    pub max_cribbing_m: f32,
+}
+
+impl Default for LevellingSpec {
+    fn default() -> Self {
+        Self {
+            min_jack_stroke_m: 0.1,
+            max_jack_stroke_m: 0.5,
+            level_tolerance_pct: 1.0,
+            derate_per_pct: 0.25,
+            cribbing_layer_m: 0.1,
+            max_cribbing_m: 0.6,
+        }
+    }
+}
+
+impl LevellingSpec {
+    /// Capacity factor for a carrier `tilt_pct` out of level
+    pub fn capacity_factor(&self, tilt_pct: f32) -> f32 {
+        let excess = tilt_pct - self.level_tolerance_pct;
+        if excess <= 0.0 {
+            1.0
+        } else {
+            (1.0 - self.derate_per_pct * excess).max(0.0)
+        }
+    }
+}
+
+/// Jack stroke and cribbing for one leg
+#[derive(Debug, Clone, Copy)]
+pub struct LegLevelling {
+    pub position: OutriggerPosition,
+    /// Ground under the float, relative to the crane position (m)
+    pub ground_elevation_m: f32,
+    pub jack_stroke_m: f32,
+    pub cribbing_m: f32,
+}
+
+/// Solved jack settings that bring the carrier level
+#[derive(Debug, Clone)]
+pub struct LevellingResult {
+    pub legs: Vec<LegLevelling>,
+    /// Common elevation of the jack tops once levelled (m)
+    pub jack_top_elevation_m: f32,
+    /// Largest ground difference between floats (m)
+    pub ground_difference_m: f32,
+}
+
+impl LevellingResult {
+    pub fn leg(&self, position: OutriggerPosition) -> Option<&LegLevelling> {
+        self.legs.iter().find(|l| l.position == position)
+    }
+
+    /// Total cribbing height across all floats
+    pub fn total_cribbing_m(&self) -> f32 {
+        self.legs.iter().map(|l| l.cribbing_m).sum()
+    }
+}
+
+/// Effect of the carrier being out of level on the hook
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TiltEffect {
+    /// Carrier slope (dz/dx, dz/dy) in crane local axes
+    pub slope: Vector2<f32>,
+    /// Steepest slope of the carrier (percent)
+    pub tilt_pct: f32,
+    /// Radius gained with the slew axis leaning towards the boom (m)
+    pub radius_increase_m: f32,
+    /// Hook offset across the boom plane, positive to the boom's right (m)
+    pub side_offset_m: f32,
+}
+
+/// Levels the carrier on its jacks over uneven ground
+pub struct LevellingSolver;
+
+impl LevellingSolver {
+    /// Jack strokes that level the carrier as low as possible
+    ///
+    /// The float on the highest ground runs at minimum stroke; legs that
+    /// would need more than the maximum stroke are built up with cribbing
+    /// in whole layers.
+    pub fn solve(outriggers: &OutriggerSystem, spec: &LevellingSpec) -> Result<LevellingResult> {
+        let highest = outriggers
+            .outriggers
+            .iter()
+            .map(|o| o.ground_elevation_m)
+            .reduce(f32::max)
+            .ok_or_else(|| CraneConfigError::UnsafeConfiguration {
+                reason: "No outriggers to level".to_string(),
+            })?;
+        let lowest = outriggers
+            .outriggers
+            .iter()
+            .map(|o| o.ground_elevation_m)
+            .fold(highest, f32::min);
+
+        let jack_top = highest + spec.min_jack_stroke_m;
+        let legs = outriggers
+            .outriggers
+            .iter()
+            .map(|outrigger| {
+                let needed = jack_top - outrigger.ground_elevation_m;
+                let cribbing = if needed > spec.max_jack_stroke_m + 1e-4 {
+                    let layers = ((needed - spec.max_jack_stroke_m) / spec.cribbing_layer_m).ceil();
+                    layers * spec.cribbing_layer_m
+                } else {
+                    0.0
+                };
+
+                if cribbing > spec.max_cribbing_m + 1e-4 {
+                    return Err(CraneConfigError::UnsafeConfiguration {
+                        reason: format!(
+                            "{} outrigger needs {:.2}m of cribbing to level (max {:.2}m)",
+                            outrigger.position.as_str(),
+                            cribbing,
+                            spec.max_cribbing_m
+                        ),
+                    });
+                }
+
+                Ok(LegLevelling {
+                    position: outrigger.position,
+                    ground_elevation_m: outrigger.ground_elevation_m,
+                    jack_stroke_m: needed - cribbing,
+                    cribbing_m: cribbing,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(LevellingResult {
+            legs,
+            jack_top_elevation_m: jack_top,
+            ground_difference_m: highest - lowest,
+        })
+    }
+
+    /// Set every leg to its solved stroke and cribbing
+    pub fn apply(outriggers: &mut OutriggerSystem, result: &LevellingResult) {
+        for leg in &result.legs {
+            if let Some(outrigger) = outriggers.get_outrigger_mut(leg.position) {
+                outrigger.cribbing_m = leg.cribbing_m;
+                outrigger.deployment = OutriggerDeployment::Set {
+                    jack_extension_m: leg.jack_stroke_m,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{CraneConfiguration, test_crane, test_spec};
+
+    fn sloped_config(elevations: [f32; 4]) -> CraneConfiguration {
+        let mut config = test_crane(40.0, 60.0);
+        for (position, elevation) in OutriggerPosition::all().into_iter().zip(elevations) {
+            config.outriggers.set_ground_elevation(position, elevation);
+        }
+        config
+    }
+
+    #[test]
+    fn test_levelling_on_slope() {
+        // Ground falls 0.3m towards the rear
+        let mut config = sloped_config([0.15, 0.15, -0.15, -0.15]);
+
+        // Equal jack strokes leave the carrier on the ground slope
+        assert!(config.get_tilt_effect().tilt_pct > 1.0);
+        assert!(config.validate().is_err());
+
+        let result = config.level_outriggers().unwrap();
+        let front = result.leg(OutriggerPosition::FrontLeft).unwrap();
+        let rear = result.leg(OutriggerPosition::RearLeft).unwrap();
+        assert!((rear.jack_stroke_m - front.jack_stroke_m - 0.3).abs() < 1e-4);
+        assert_eq!(result.total_cribbing_m(), 0.0);
+
+        let tilt = config.get_tilt_effect();
+        assert!(tilt.tilt_pct < 1e-3);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_cribbing_beyond_stroke() {
+        let spec = test_spec("liebherr_ltm_1100_5_2").levelling.unwrap();
+        let mut config = sloped_config([0.0, 0.0, 0.0, -0.75]);
+
+        let result = config.level_outriggers().unwrap();
+        let low = result.leg(OutriggerPosition::RearRight).unwrap();
+        assert!(low.cribbing_m > 0.0);
+        assert!(low.jack_stroke_m <= spec.max_jack_stroke_m + 1e-4);
+        assert!(low.jack_stroke_m >= spec.min_jack_stroke_m);
+
+        // A hole deeper than jack stroke plus cribbing cannot be levelled
+        let config = sloped_config([0.0, 0.0, 0.0, -2.0]);
+        assert!(LevellingSolver::solve(&config.outriggers, &spec).is_err());
+    }
+
+    #[test]
+    fn test_tilt_radius_and_derating() {
+        // Ground falls 2% to the right, jacks left equal
+        let width = 7.0;
+        let drop = 0.02 * width;
+        let mut config = sloped_config([0.0, -drop, 0.0, -drop]);
+        config.swing_angle_deg = 90.0;
+
+        let level_radius = config.get_radius();
+        let tilt = config.get_tilt_effect();
+        assert!(tilt.tilt_pct > 1.0);
+
+        // Leaning towards the boom carries the hook further out
+        assert!(tilt.radius_increase_m > 0.0);
+        assert!(config.get_loaded_radius(0.0) > level_radius);
+
+        let spec = test_spec("liebherr_ltm_1100_5_2").levelling.unwrap();
+        assert!(config.get_out_of_level_factor() < 1.0);
+        assert_eq!(spec.capacity_factor(0.5), 1.0);
+
+        // Slewed uphill the radius shrinks instead
+        config.swing_angle_deg = 270.0;
+        assert!(config.get_tilt_effect().radius_increase_m < 0.0);
+    }
+}
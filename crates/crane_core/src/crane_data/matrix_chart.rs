@@ -0,0 +1,556 @@
+// crates/crane_core/src/crane_data/matrix_chart.rs
+
+use super::capacity::{CapacityLimit, CapacityPoint, LoadChart, LoadChartParser, first_error};
+use super::chart_validation::{ChartDiagnostic, DiagnosticKind, SourceLocation};
+use super::units::{ChartUnits, Unit};
+use std::collections::BTreeMap;
+
+/// Load charts read from a manufacturer matrix, with the chart's own text
+#[derive(Debug, Clone, Default)]
+pub struct MatrixChartImport {
+    /// One chart per boom length column
+    pub charts: Vec<LoadChart>,
+    /// Lines above the boom length header
+    pub title: Vec<String>,
+    /// Footnote text by marker
+    pub footnotes: BTreeMap<String, String>,
+    /// Units the chart was printed in; values are converted to m and kg
+    pub units: ChartUnits,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MatrixLayout {
+    Csv,
+    Text,
+}
+
+/// A cell and the character column its text ends at
+struct Cell {
+    text: String,
+    end: usize,
+}
+
+struct MatrixCell {
+    capacity: f32,
+    limit: Option<CapacityLimit>,
+    footnotes: Vec<String>,
+}
+
+struct MatrixHeader {
+    boom_lengths: Vec<f32>,
+    /// Boom length cells as printed, for reading in the chart's units
+    texts: Vec<String>,
+    ends: Vec<usize>,
+}
+
+impl LoadChartParser {
+    /// Parse a matrix chart laid out as CSV
+    ///
+    /// Radii run down the first column and boom lengths across the header
+    /// row. Empty or `-` cells are unavailable combinations.
+    /// ```csv
+    /// LTM 1100-5.2 main boom,,,
+    /// Radius (m) / Boom (m) (t),30.0,40.0,50.0
+    /// Telescoping,T1,T2,T3
+    /// 3.0,100S,90S,80*
+    /// 10.0,40,35,30
+    /// 25.0,10T,,
+    /// * With 22 t counterweight only
+    /// ```
+    /// A trailing `S` marks a structural (strength) limit and `T` a
+    /// stability limit; symbols such as `*`, `†` or `¹` are footnote
+    /// markers, defined on lines that start with the marker.
+    pub fn parse_matrix_csv(csv_data: &str) -> Result<MatrixChartImport, String> {
+        let (import, diagnostics) = Self::read_matrix_csv(csv_data);
+        first_error(&diagnostics)?;
+        Ok(import)
+    }
+
+    /// Read a CSV matrix, reporting every unreadable cell
+    pub fn read_matrix_csv(csv_data: &str) -> (MatrixChartImport, Vec<ChartDiagnostic>) {
+        read_matrix(csv_data, MatrixLayout::Csv)
+    }
+
+    /// Parse a matrix chart laid out in whitespace-separated columns
+    ///
+    /// Same markings as [`LoadChartParser::parse_matrix_csv`]. Rows with
+    /// missing cells are matched to boom columns by where their values end,
+    /// so right-aligned printed charts can leave unavailable cells blank.
+    /// ```text
+    /// Radius(m)   30.0   40.0   50.0
+    /// 3.0         100S    90S    80*
+    /// 25.0         10T
+    /// 45.0                        3
+    /// ```
+    pub fn parse_matrix_text(text_data: &str) -> Result<MatrixChartImport, String> {
+        let (import, diagnostics) = Self::read_matrix_text(text_data);
+        first_error(&diagnostics)?;
+        Ok(import)
+    }
+
+    /// Read a text matrix, reporting every unreadable cell
+    pub fn read_matrix_text(text_data: &str) -> (MatrixChartImport, Vec<ChartDiagnostic>) {
+        read_matrix(text_data, MatrixLayout::Text)
+    }
+}
+
+fn read_matrix(data: &str, layout: MatrixLayout) -> (MatrixChartImport, Vec<ChartDiagnostic>) {
+    let mut import = MatrixChartImport::default();
+    let mut diagnostics = Vec::new();
+    let mut header: Option<MatrixHeader> = None;
+    let mut codes: Vec<Option<String>> = Vec::new();
+    let mut columns: Vec<Vec<CapacityPoint>> = Vec::new();
+
+    for (index, line) in data.lines().enumerate() {
+        let line_num = index + 1;
+        let trimmed = line.trim().trim_matches('"').trim_end_matches(',').trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some((marker, text)) = footnote_definition(trimmed) {
+            import.footnotes.insert(marker, text);
+            continue;
+        }
+
+        let cells = split_row(line, layout);
+
+        let Some(header) = &header else {
+            match parse_header(&cells, layout) {
+                Some((label, mut parsed)) => {
+                    // Lengths are named in the header; capacity units may
+                    // only appear in the title
+                    import.units = ChartUnits::detect(&label);
+                    if import.units.mass == Unit::Kilogram {
+                        import.units.mass = ChartUnits::detect(&import.title.join(" ")).mass;
+                    }
+                    parsed.boom_lengths = parsed
+                        .texts
+                        .iter()
+                        .filter_map(|t| parse_length(t, import.units.length))
+                        .collect();
+                    codes = vec![None; parsed.boom_lengths.len()];
+                    columns = vec![Vec::new(); parsed.boom_lengths.len()];
+                    header = Some(parsed);
+                }
+                None => import.title.push(trimmed.to_string()),
+            }
+            continue;
+        };
+
+        let label = cells[0].text.to_lowercase();
+        if ["tele", "mode", "code"]
+            .iter()
+            .any(|p| label.starts_with(p))
+        {
+            match align_cells(&cells[1..], header, layout) {
+                Ok(aligned) => codes = aligned,
+                Err(message) => diagnostics.push(
+                    ChartDiagnostic::error(DiagnosticKind::Parse, message)
+                        .at(Some(SourceLocation::line(line_num))),
+                ),
+            }
+            continue;
+        }
+
+        // Other text rows (sub-headings, remarks) carry no capacities
+        let Some(radius) = parse_length(&cells[0].text, import.units.length) else {
+            continue;
+        };
+
+        let aligned = match align_cells(&cells[1..], header, layout) {
+            Ok(aligned) => aligned,
+            Err(message) => {
+                diagnostics.push(
+                    ChartDiagnostic::error(DiagnosticKind::Parse, message)
+                        .at(Some(SourceLocation::line(line_num))),
+                );
+                continue;
+            }
+        };
+
+        for (column, cell) in aligned.into_iter().enumerate() {
+            let Some(text) = cell else {
+                continue;
+            };
+            // Cells are numbered from the radius column
+            let location = SourceLocation::cell(line_num, column + 2);
+            let parsed = match parse_cell(&text, import.units.mass) {
+                Some(Some(parsed)) => parsed,
+                Some(None) => continue,
+                None => {
+                    diagnostics.push(
+                        ChartDiagnostic::error(
+                            DiagnosticKind::Parse,
+                            format!(
+                                "Invalid capacity '{}' for {:.1}m boom",
+                                text, header.boom_lengths[column]
+                            ),
+                        )
+                        .at(Some(location)),
+                    );
+                    continue;
+                }
+            };
+
+            columns[column].push(CapacityPoint {
+                radius_m: radius,
+                capacity_kg: parsed.capacity,
+                limit: parsed.limit,
+                footnotes: parsed.footnotes,
+                source: Some(location),
+            });
+        }
+    }
+
+    let Some(header) = header else {
+        diagnostics.push(ChartDiagnostic::error(
+            DiagnosticKind::Parse,
+            "No boom length header row found",
+        ));
+        return (import, diagnostics);
+    };
+
+    for ((boom_length, code), points) in header.boom_lengths.iter().zip(codes).zip(columns) {
+        if points.is_empty() {
+            continue;
+        }
+
+        let mut chart = match code {
+            Some(code) => LoadChart::for_telescoping_mode(*boom_length, &code),
+            None => LoadChart::new(*boom_length),
+        };
+
+        // Notes carry the title and the footnotes this column refers to
+        let mut notes = import.title.clone();
+        let mut markers: Vec<&String> = points.iter().flat_map(|p| &p.footnotes).collect();
+        markers.sort();
+        markers.dedup();
+        for marker in markers {
+            if let Some(text) = import.footnotes.get(marker) {
+                notes.push(format!("{} {}", marker, text));
+            }
+        }
+        if !notes.is_empty() {
+            chart.notes = Some(notes.join("; "));
+        }
+
+        for point in points {
+            chart.push_point(point);
+        }
+        import.charts.push(chart);
+    }
+
+    (import, diagnostics)
+}
+
+fn is_marker(c: char) -> bool {
+    matches!(
+        c,
+        '*' | '†' | '‡' | '§' | '#' | '¶' | '+' | '¹' | '²' | '³' | '⁴'..='⁹' | '⁰'
+    )
+}
+
+fn is_blank(text: &str) -> bool {
+    matches!(text, "" | "-" | "–" | "—" | ".")
+}
+
+/// `* text` style footnote line
+fn footnote_definition(line: &str) -> Option<(String, String)> {
+    let first = line.chars().next()?;
+    if !is_marker(first) {
+        return None;
+    }
+
+    let marker: String = line.chars().take_while(|c| *c == first).collect();
+    let text = line[marker.len()..]
+        .trim_start_matches([' ', '=', ':', ')'])
+        .trim()
+        .trim_end_matches(',')
+        .trim();
+    Some((marker, text.to_string()))
+}
+
+/// Split a row into cells, tracking where each ends for text alignment
+fn split_row(line: &str, layout: MatrixLayout) -> Vec<Cell> {
+    let mut cells = Vec::new();
+
+    match layout {
+        MatrixLayout::Csv => {
+            for (index, part) in line.split(',').enumerate() {
+                cells.push(Cell {
+                    text: part.trim().trim_matches('"').trim().to_string(),
+                    end: index,
+                });
+            }
+        }
+        MatrixLayout::Text => {
+            let mut current = String::new();
+            for (column, c) in line.chars().enumerate() {
+                if c.is_whitespace() {
+                    if !current.is_empty() {
+                        cells.push(Cell {
+                            text: std::mem::take(&mut current),
+                            end: column,
+                        });
+                    }
+                } else {
+                    current.push(c);
+                }
+            }
+            if !current.is_empty() {
+                cells.push(Cell {
+                    end: line.chars().count(),
+                    text: current,
+                });
+            }
+        }
+    }
+
+    if cells.is_empty() {
+        cells.push(Cell {
+            text: String::new(),
+            end: 0,
+        });
+    }
+    cells
+}
+
+/// Boom length header: a text label followed only by lengths
+fn parse_header(cells: &[Cell], layout: MatrixLayout) -> Option<(String, MatrixHeader)> {
+    let label_cells = match layout {
+        MatrixLayout::Csv => 1,
+        MatrixLayout::Text => cells
+            .iter()
+            .position(|c| parse_length(&c.text, Unit::Metre).is_some())
+            .filter(|p| *p > 0)?,
+    };
+    if parse_length(&cells[0].text, Unit::Metre).is_some() {
+        return None;
+    }
+
+    let mut boom_lengths = Vec::new();
+    let mut texts = Vec::new();
+    let mut ends = Vec::new();
+    for cell in &cells[label_cells..] {
+        if cell.text.is_empty() {
+            continue;
+        }
+        boom_lengths.push(parse_length(&cell.text, Unit::Metre)?);
+        texts.push(cell.text.clone());
+        ends.push(cell.end);
+    }
+    if boom_lengths.is_empty() {
+        return None;
+    }
+
+    let label: Vec<&str> = cells[..label_cells]
+        .iter()
+        .map(|c| c.text.as_str())
+        .collect();
+    Some((
+        label.join(" "),
+        MatrixHeader {
+            boom_lengths,
+            texts,
+            ends,
+        },
+    ))
+}
+
+/// Place a row's cells under the boom columns
+fn align_cells(
+    cells: &[Cell],
+    header: &MatrixHeader,
+    layout: MatrixLayout,
+) -> Result<Vec<Option<String>>, String> {
+    let columns = header.boom_lengths.len();
+    let mut aligned = vec![None; columns];
+
+    let by_index = layout == MatrixLayout::Csv || cells.len() == columns;
+    for (index, cell) in cells.iter().enumerate() {
+        if is_blank(&cell.text) && layout == MatrixLayout::Csv {
+            continue;
+        }
+
+        let column = if by_index {
+            index
+        } else {
+            (0..columns)
+                .min_by_key(|c| header.ends[*c].abs_diff(cell.end))
+                .unwrap_or(0)
+        };
+
+        if column >= columns {
+            return Err(format!(
+                "{} cells for {} boom lengths",
+                cells.len(),
+                columns
+            ));
+        }
+        if aligned[column].is_some() {
+            return Err(format!(
+                "Cannot line up '{}' with a boom length column",
+                cell.text
+            ));
+        }
+        aligned[column] = Some(if is_blank(&cell.text) {
+            String::new()
+        } else {
+            cell.text.clone()
+        });
+    }
+
+    // Blank placeholders only reserve their column
+    Ok(aligned
+        .into_iter()
+        .map(|c| c.filter(|t| !t.is_empty()))
+        .collect())
+}
+
+/// Length in `unit`, with or without a trailing `m`, `ft` or `'`
+fn parse_length(text: &str, unit: Unit) -> Option<f32> {
+    let number = text
+        .trim()
+        .trim_end_matches(|c: char| c.is_alphabetic() || c == '\'')
+        .trim();
+    unit.parse_value(number)
+}
+
+/// Capacity, limit flag and footnote markers of one cell
+///
+/// Outer None for unreadable text, inner None for a blank cell.
+fn parse_cell(text: &str, mass: Unit) -> Option<Option<MatrixCell>> {
+    if is_blank(text) {
+        return Some(None);
+    }
+
+    let split = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(text.len());
+    let capacity = mass.parse_value(&text[..split])?;
+
+    let mut limit = None;
+    let mut footnotes: Vec<String> = Vec::new();
+    for c in text[split..].chars() {
+        match c {
+            'S' | 's' => limit = Some(CapacityLimit::Structural),
+            'T' | 't' => limit = Some(CapacityLimit::Stability),
+            c if is_marker(c) => match footnotes.last_mut() {
+                // Repeated symbols (`**`) are one marker
+                Some(last) if last.starts_with(c) && last.chars().all(|m| m == c) => last.push(c),
+                _ => footnotes.push(c.to_string()),
+            },
+            _ => return None,
+        }
+    }
+
+    Some(Some(MatrixCell {
+        capacity,
+        limit,
+        footnotes,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_csv_import() {
+        let csv = "\
+LTM 1100-5.2 main boom 360deg,,,
+Radius (m) / Boom (m) (t),30.0,40.0,50.0
+Telescoping,T1,T2,
+3.0,100S,90S,80*
+10.0,40,35,30
+25.0,10T,-,
+45.0,,,3**
+* With 22 t counterweight only
+** Boom raised before slewing
+";
+        let import = LoadChartParser::parse_matrix_csv(csv).unwrap();
+        assert_eq!(import.units.mass, Unit::Tonne);
+        assert_eq!(import.footnotes.len(), 2);
+        assert_eq!(import.charts.len(), 3);
+
+        let chart_30 = &import.charts[0];
+        assert_eq!(chart_30.telescoping_mode.as_deref(), Some("T1"));
+        assert_eq!(chart_30.points.len(), 3);
+        assert_eq!(chart_30.points[0].capacity_kg, 100_000.0);
+        assert_eq!(chart_30.points[0].limit, Some(CapacityLimit::Structural));
+        assert_eq!(chart_30.points[2].limit, Some(CapacityLimit::Stability));
+
+        // Blank cells leave gaps; the uncoded column is mode independent
+        let chart_50 = &import.charts[2];
+        assert_eq!(chart_50.telescoping_mode, None);
+        assert_eq!(chart_50.points.len(), 3);
+        assert_eq!(chart_50.points[0].footnotes, vec!["*".to_string()]);
+        assert_eq!(chart_50.points[2].footnotes, vec!["**".to_string()]);
+        let notes = chart_50.notes.as_deref().unwrap();
+        assert!(notes.contains("22 t counterweight"));
+        assert!(notes.contains("Boom raised"));
+
+        assert!(LoadChartParser::parse_matrix_csv("Radius,30\n3.0,abc").is_err());
+    }
+
+    #[test]
+    fn test_matrix_text_alignment() {
+        let text = "
+Radius(m)   30.0   40.0   50.0
+3.0         100000 90000  80000
+25.0        10000
+35.0               5000†
+45.0                      3000
+† Reduced slewing speed
+";
+        let import = LoadChartParser::parse_matrix_text(text).unwrap();
+        assert!(import.units.is_metric());
+
+        let points: Vec<usize> = import.charts.iter().map(|c| c.points.len()).collect();
+        assert_eq!(points, vec![2, 2, 2]);
+        assert_eq!(import.charts[1].points[1].radius_m, 35.0);
+        assert_eq!(import.charts[1].points[1].footnotes, vec!["†".to_string()]);
+        assert_eq!(import.charts[2].get_capacity_at_radius(45.0), Some(3000.0));
+    }
+
+    #[test]
+    fn test_matrix_imperial_units() {
+        let text = "
+Capacities in pounds (lbs)
+Radius(ft)   100    150
+10         60000  50000
+40         20000  15000
+";
+        let import = LoadChartParser::parse_matrix_text(text).unwrap();
+        assert_eq!(import.units, ChartUnits::imperial());
+
+        let chart = &import.charts[1];
+        assert_eq!(chart.boom_length_m, Unit::Foot.to_metric(150.0));
+        assert_eq!(chart.points[1].radius_m, 12.192);
+        assert_eq!(chart.points[1].capacity_kg, Unit::Pound.to_metric(15_000.0));
+    }
+
+    #[test]
+    fn test_matrix_asset_import() {
+        let text = include_str!("../../../../assets/load_charts/liebherr_ltm_1100_matrix.txt");
+        let import = LoadChartParser::parse_matrix_text(text).unwrap();
+        assert_eq!(import.units.mass, Unit::Tonne);
+        assert_eq!(import.charts.len(), 5);
+
+        let chart_50 = import
+            .charts
+            .iter()
+            .find(|c| c.boom_length_m == 50.0)
+            .unwrap();
+        assert_eq!(chart_50.get_capacity_at_radius(45.0), Some(3000.0));
+        assert_eq!(
+            chart_50.points.last().unwrap().footnotes,
+            vec!["*".to_string()]
+        );
+
+        let chart_15 = &import.charts[0];
+        assert_eq!(chart_15.points.len(), 4);
+        assert_eq!(chart_15.get_capacity_at_radius(8.0), Some(80_000.0));
+    }
+}
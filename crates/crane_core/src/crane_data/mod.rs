@@ -1,15 +1,43 @@
 // crates/crane_core/src/crane_data/mod.rs
 
 mod capacity;
+mod chart_validation;
 mod configuration;
 mod counterweight;
+mod crawler;
+mod deflection;
 mod errors;
+mod jib;
+mod levelling;
+mod matrix_chart;
 mod outriggers;
+mod provenance;
+mod registry;
 mod spec;
+mod telescoping;
+mod tower;
+mod units;
+
+#[cfg(test)]
+mod test_support;
 
 pub use capacity::*;
+pub use chart_validation::*;
 pub use configuration::*;
 pub use counterweight::*;
+pub use crawler::*;
+pub use deflection::*;
 pub use errors::*;
+pub use jib::*;
+pub use levelling::*;
+pub use matrix_chart::*;
 pub use outriggers::*;
+pub use provenance::*;
+pub use registry::*;
 pub use spec::*;
+pub use telescoping::*;
+pub use tower::*;
+pub use units::*;
+
+#[cfg(test)]
+pub(crate) use test_support::*;
@@ -1,6 +1,7 @@
 // crates/crane_core/src/crane_data/outriggers.rs
 
-use nalgebra::Point3;
+use super::errors::{CraneConfigError, Result};
+use nalgebra::{Matrix3, Point3, Vector2, Vector3};
 use serde::{Deserialize, Serialize};
 
 /// Outrigger position on crane
@@ -46,6 +47,112 @@ pub enum OutriggerDeployment {
     },
 }
 
+/// Where an outrigger box sits on the carrier and how its beam runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutriggerLegGeometry {
+    /// Box station along the carrier from the slew centre (m, + forward)
+    pub box_station_m: f32,
+    /// Lateral offset of the beam root from the carrier centreline (m)
+    pub box_offset_m: f32,
+    /// Beam direction from square-out, towards the carrier's nearer end (deg)
+    #[serde(default)]
+    pub beam_angle_deg: f32,
+    /// Jack centre beyond the beam end (m)
+    #[serde(default)]
+    pub jack_offset_m: f32,
+    /// Float (pad) diameter (m)
+    pub float_diameter_m: f32,
+    /// Beam extensions the leg can be pinned at (m); empty for continuous
+    #[serde(default)]
+    pub pinned_spans_m: Vec<f32>,
+}
+
+impl OutriggerLegGeometry {
+    fn signs(position: OutriggerPosition) -> (f32, f32) {
+        match position {
+            OutriggerPosition::FrontLeft => (-1.0, 1.0),
+            OutriggerPosition::FrontRight => (1.0, 1.0),
+            OutriggerPosition::RearLeft => (-1.0, -1.0),
+            OutriggerPosition::RearRight => (1.0, -1.0),
+        }
+    }
+
+    /// Beam root on the outrigger box (crane local)
+    pub fn beam_root(&self, position: OutriggerPosition) -> Point3<f32> {
+        let (x_sign, _) = Self::signs(position);
+        Point3::new(x_sign * self.box_offset_m, self.box_station_m, 0.0)
+    }
+
+    /// Float centre with the beam out by `extension_m` (crane local)
+    pub fn contact_point(&self, position: OutriggerPosition, extension_m: f32) -> Point3<f32> {
+        let (x_sign, y_sign) = Self::signs(position);
+        let angle = self.beam_angle_deg.to_radians();
+        let reach = extension_m + self.jack_offset_m;
+        let root = self.beam_root(position);
+
+        Point3::new(
+            root.x + x_sign * reach * angle.cos(),
+            root.y + y_sign * reach * angle.sin(),
+            0.0,
+        )
+    }
+
+    /// Shortest and longest beam extension
+    pub fn span_range(&self) -> Option<(f32, f32)> {
+        let min = self.pinned_spans_m.iter().copied().reduce(f32::min)?;
+        let max = self.pinned_spans_m.iter().copied().reduce(f32::max)?;
+        Some((min, max))
+    }
+}
+
+/// Outrigger layout for a crane model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutriggerGeometry {
+    pub legs: Vec<(OutriggerPosition, OutriggerLegGeometry)>,
+}
+
+impl OutriggerGeometry {
+    /// Four square-out legs with boxes at `front_station_m` and `rear_station_m`
+    pub fn symmetric(
+        front_station_m: f32,
+        rear_station_m: f32,
+        box_offset_m: f32,
+        jack_offset_m: f32,
+        float_diameter_m: f32,
+        pinned_spans_m: Vec<f32>,
+    ) -> Self {
+        let legs = OutriggerPosition::all()
+            .into_iter()
+            .map(|position| {
+                let station = match position {
+                    OutriggerPosition::FrontLeft | OutriggerPosition::FrontRight => front_station_m,
+                    OutriggerPosition::RearLeft | OutriggerPosition::RearRight => rear_station_m,
+                };
+                (
+                    position,
+                    OutriggerLegGeometry {
+                        box_station_m: station,
+                        box_offset_m,
+                        beam_angle_deg: 0.0,
+                        jack_offset_m,
+                        float_diameter_m,
+                        pinned_spans_m: pinned_spans_m.clone(),
+                    },
+                )
+            })
+            .collect();
+
+        Self { legs }
+    }
+
+    pub fn leg(&self, position: OutriggerPosition) -> Option<&OutriggerLegGeometry> {
+        self.legs
+            .iter()
+            .find(|(p, _)| *p == position)
+            .map(|(_, leg)| leg)
+    }
+}
+
 /// Configuration for a single outrigger
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutriggerConfig {
@@ -64,6 +171,18 @@ pub struct OutriggerConfig {
 
     /// Pad dimensions if using outrigger pads
     pub pad_diameter_m: Option<f32>,
+
+    /// Model geometry; legacy diagonal layout when None
+    #[serde(default)]
+    pub geometry: Option<OutriggerLegGeometry>,
+
+    /// Ground under the float relative to the crane position (m)
+    #[serde(default)]
+    pub ground_elevation_m: f32,
+
+    /// Cribbing stacked under the float (m)
+    #[serde(default)]
+    pub cribbing_m: f32,
 }
 
 impl OutriggerConfig {
@@ -75,12 +194,42 @@ impl OutriggerConfig {
             max_extension_m,
             min_extension_m,
             pad_diameter_m: Some(0.6), // 600mm standard pad
+            geometry: None,
+            ground_elevation_m: 0.0,
+            cribbing_m: 0.0,
+        }
+    }
+
+    /// Leg with model geometry; spans come from the pinned positions when given
+    pub fn with_geometry(
+        position: OutriggerPosition,
+        geometry: OutriggerLegGeometry,
+        max_extension_m: f32,
+    ) -> Self {
+        let (min, max) = geometry
+            .span_range()
+            .unwrap_or((max_extension_m * 0.5, max_extension_m));
+
+        Self {
+            position,
+            deployment: OutriggerDeployment::Retracted,
+            extension_m: max,
+            max_extension_m: max,
+            min_extension_m: min,
+            pad_diameter_m: Some(geometry.float_diameter_m),
+            geometry: Some(geometry),
+            ground_elevation_m: 0.0,
+            cribbing_m: 0.0,
         }
     }
 
     /// Get the outrigger contact point in crane local coordinates
     /// Assumes crane center is at origin, front is +Y
     pub fn get_contact_point(&self, crane_base_width_m: f32) -> Point3<f32> {
+        if let Some(geometry) = &self.geometry {
+            return geometry.contact_point(self.position, self.extension_m);
+        }
+
         let half_width = crane_base_width_m / 2.0;
 
         // Base position on crane body
@@ -102,6 +251,57 @@ impl OutriggerConfig {
         )
     }
 
+    /// Where the beam leaves the carrier, in crane local coordinates
+    pub fn get_beam_root(&self, crane_base_width_m: f32) -> Point3<f32> {
+        match &self.geometry {
+            Some(geometry) => geometry.beam_root(self.position),
+            None => {
+                let corner = self.get_contact_point(crane_base_width_m);
+                let half_width = crane_base_width_m / 2.0;
+                Point3::new(
+                    half_width * corner.x.signum(),
+                    half_width * corner.y.signum(),
+                    0.0,
+                )
+            }
+        }
+    }
+
+    /// Pinned spans, if the beam cannot be set anywhere in its range
+    pub fn pinned_spans(&self) -> Option<&[f32]> {
+        self.geometry
+            .as_ref()
+            .map(|g| g.pinned_spans_m.as_slice())
+            .filter(|spans| !spans.is_empty())
+    }
+
+    /// Nearest settable extension at or below `extension_m`
+    pub fn snap_extension(&self, extension_m: f32) -> f32 {
+        match self.pinned_spans() {
+            Some(spans) => spans
+                .iter()
+                .copied()
+                .filter(|s| *s <= extension_m + 1e-3)
+                .reduce(f32::max)
+                .unwrap_or(self.min_extension_m),
+            None => extension_m.clamp(self.min_extension_m, self.max_extension_m),
+        }
+    }
+
+    /// Jack stroke when set
+    pub fn jack_stroke_m(&self) -> Option<f32> {
+        match self.deployment {
+            OutriggerDeployment::Set { jack_extension_m } => Some(jack_extension_m),
+            _ => None,
+        }
+    }
+
+    /// Elevation of the jack top relative to the crane position, when set
+    pub fn jack_top_elevation_m(&self) -> Option<f32> {
+        self.jack_stroke_m()
+            .map(|stroke| self.ground_elevation_m + self.cribbing_m + stroke)
+    }
+
     /// Check if outrigger is fully deployed
     pub fn is_deployed(&self) -> bool {
         matches!(self.deployment, OutriggerDeployment::Set { .. })
@@ -118,10 +318,34 @@ impl OutriggerConfig {
                 },
             );
         }
+
+        if let Some(spans) = self.pinned_spans()
+            && !spans.iter().any(|s| (s - self.extension_m).abs() < 1e-3)
+        {
+            let spans: Vec<String> = spans.iter().map(|s| format!("{:.2}", s)).collect();
+            return Err(CraneConfigError::UnsafeConfiguration {
+                reason: format!(
+                    "{} outrigger must be pinned at one of {} m",
+                    self.position.as_str(),
+                    spans.join(", ")
+                ),
+            });
+        }
         Ok(())
     }
 }
 
+/// Edge of the support polygon the crane can tip over
+#[derive(Debug, Clone, Copy)]
+pub struct TippingEdge {
+    pub from: OutriggerPosition,
+    pub to: OutriggerPosition,
+    /// Outward unit normal in crane local XY
+    pub normal: Vector2<f32>,
+    /// Perpendicular distance from the slew centre to the edge (m)
+    pub distance_m: f32,
+}
+
 /// Complete outrigger system configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutriggerSystem {
@@ -154,6 +378,19 @@ impl OutriggerSystem {
         }
     }
 
+    /// Replace the legs with a model's outrigger geometry
+    pub fn apply_geometry(&mut self, geometry: &OutriggerGeometry) {
+        for outrigger in &mut self.outriggers {
+            if let Some(leg) = geometry.leg(outrigger.position) {
+                *outrigger = OutriggerConfig::with_geometry(
+                    outrigger.position,
+                    leg.clone(),
+                    outrigger.max_extension_m,
+                );
+            }
+        }
+    }
+
     /// Get outrigger by position
     pub fn get_outrigger(&self, position: OutriggerPosition) -> Option<&OutriggerConfig> {
         self.outriggers.iter().find(|o| o.position == position)
@@ -185,29 +422,211 @@ impl OutriggerSystem {
             .collect()
     }
 
+    /// Set one leg's horizontal extension and set it down
+    pub fn set_extension(&mut self, position: OutriggerPosition, extension_m: f32) -> Result<()> {
+        let outrigger = self.get_outrigger_mut(position).ok_or_else(|| {
+            CraneConfigError::UnsafeConfiguration {
+                reason: format!("No {} outrigger", position.as_str()),
+            }
+        })?;
+
+        if extension_m < outrigger.min_extension_m || extension_m > outrigger.max_extension_m {
+            return Err(CraneConfigError::OutriggerExtensionInvalid {
+                extension: extension_m,
+                min: outrigger.min_extension_m,
+                max: outrigger.max_extension_m,
+            });
+        }
+
+        let previous = outrigger.extension_m;
+        outrigger.extension_m = extension_m;
+        if let Err(e) = outrigger.validate() {
+            outrigger.extension_m = previous;
+            return Err(e);
+        }
+        if !outrigger.is_deployed() {
+            outrigger.deployment = OutriggerDeployment::Set {
+                jack_extension_m: 0.5,
+            };
+        }
+        Ok(())
+    }
+
+    /// Pull one leg in off the ground
+    pub fn retract(&mut self, position: OutriggerPosition) -> Result<()> {
+        let outrigger = self.get_outrigger_mut(position).ok_or_else(|| {
+            CraneConfigError::UnsafeConfiguration {
+                reason: format!("No {} outrigger", position.as_str()),
+            }
+        })?;
+        outrigger.deployment = OutriggerDeployment::Retracted;
+        Ok(())
+    }
+
+    /// Set one leg's extension as a percentage of its maximum, dropping to
+    /// the next pinned span below for pinned beams; 0% retracts the leg
+    pub fn set_extension_pct(&mut self, position: OutriggerPosition, pct: f32) -> Result<()> {
+        if pct <= 0.0 {
+            return self.retract(position);
+        }
+        let extension = self
+            .get_outrigger(position)
+            .map(|o| match o.pinned_spans() {
+                Some(_) => o.snap_extension(o.max_extension_m * pct / 100.0),
+                None => o.max_extension_m * pct / 100.0,
+            })
+            .unwrap_or(0.0);
+        self.set_extension(position, extension)
+    }
+
+    /// Extension of each leg as a percentage of its maximum (0 retracted)
+    pub fn extension_pct(&self, position: OutriggerPosition) -> Option<f32> {
+        self.get_outrigger(position).map(|o| {
+            if o.is_deployed() {
+                o.extension_m / o.max_extension_m * 100.0
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// True when the legs are not all at the same span
+    pub fn is_asymmetric(&self) -> bool {
+        self.outriggers
+            .windows(2)
+            .any(|w| (w[0].extension_m - w[1].extension_m).abs() > 1e-3)
+    }
+
+    /// Same system with every leg at full span (deployment unchanged)
+    pub fn fully_extended(&self) -> Self {
+        let mut full = self.clone();
+        for outrigger in &mut full.outriggers {
+            outrigger.extension_m = outrigger.max_extension_m;
+        }
+        full
+    }
+
+    /// Convex hull of the deployed contact points, counter-clockwise
+    pub fn support_polygon(&self) -> Vec<(OutriggerPosition, Point3<f32>)> {
+        let mut points = self.get_all_contact_points();
+        points.sort_by(|a, b| a.1.x.total_cmp(&b.1.x).then(a.1.y.total_cmp(&b.1.y)));
+        if points.len() < 3 {
+            return points;
+        }
+
+        let cross = |o: &Point3<f32>, a: &Point3<f32>, b: &Point3<f32>| {
+            (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+        };
+
+        // Monotone chain: lower hull then upper hull
+        let mut hull: Vec<(OutriggerPosition, Point3<f32>)> = Vec::new();
+        for pass in [points.clone(), points.into_iter().rev().collect()] {
+            let start = hull.len();
+            for point in pass {
+                while hull.len() >= start + 2
+                    && cross(&hull[hull.len() - 2].1, &hull[hull.len() - 1].1, &point.1) <= 0.0
+                {
+                    hull.pop();
+                }
+                hull.push(point);
+            }
+            hull.pop();
+        }
+        hull
+    }
+
+    /// Edges of the support polygon with their distance from the slew centre
+    pub fn tipping_edges(&self) -> Vec<TippingEdge> {
+        let hull = self.support_polygon();
+        if hull.len() < 3 {
+            return Vec::new();
+        }
+
+        (0..hull.len())
+            .filter_map(|i| {
+                let (from, a) = hull[i];
+                let (to, b) = hull[(i + 1) % hull.len()];
+                let along = Vector2::new(b.x - a.x, b.y - a.y);
+                // Right-hand normal points out of a counter-clockwise polygon
+                let normal = Vector2::new(along.y, -along.x).try_normalize(1e-6)?;
+
+                Some(TippingEdge {
+                    from,
+                    to,
+                    normal,
+                    distance_m: normal.dot(&Vector2::new(a.x, a.y)),
+                })
+            })
+            .collect()
+    }
+
+    /// Edge the boom points across at `swing_angle_deg` (0° = front, 90° = right)
+    pub fn boom_side_edge(&self, swing_angle_deg: f32) -> Option<TippingEdge> {
+        let swing = swing_angle_deg.to_radians();
+        let direction = Vector2::new(swing.sin(), swing.cos());
+
+        // First edge hit by a ray from the slew centre
+        self.tipping_edges()
+            .into_iter()
+            .filter(|e| e.normal.dot(&direction) > 1e-6)
+            .min_by(|a, b| {
+                let hit = |e: &TippingEdge| e.distance_m / e.normal.dot(&direction);
+                hit(a).total_cmp(&hit(b))
+            })
+    }
+
     /// Calculate effective support base (polygon area)
     pub fn calculate_support_area(&self) -> f32 {
-        let points = self.get_all_contact_points();
-        if points.len() < 3 {
+        let hull = self.support_polygon();
+        if hull.len() < 3 {
             return 0.0;
         }
 
-        // Simple rectangular approximation for 4 outriggers
-        if points.len() == 4 {
-            let mut x_vals: Vec<f32> = points.iter().map(|(_, p)| p.x).collect();
-            let mut y_vals: Vec<f32> = points.iter().map(|(_, p)| p.y).collect();
+        // Shoelace formula over the convex hull
+        let twice_area: f32 = (0..hull.len())
+            .map(|i| {
+                let a = hull[i].1;
+                let b = hull[(i + 1) % hull.len()].1;
+                a.x * b.y - b.x * a.y
+            })
+            .sum();
+        twice_area.abs() / 2.0
+    }
 
-            x_vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
-            y_vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// Set the ground elevation under one float
+    pub fn set_ground_elevation(&mut self, position: OutriggerPosition, elevation_m: f32) {
+        if let Some(outrigger) = self.get_outrigger_mut(position) {
+            outrigger.ground_elevation_m = elevation_m;
+        }
+    }
 
-            let width = x_vals[3] - x_vals[0];
-            let length = y_vals[3] - y_vals[0];
+    /// Carrier slope (dz/dx, dz/dy) in crane local axes from the jack tops
+    ///
+    /// Least-squares plane through the set legs; None with fewer than three.
+    pub fn chassis_slope(&self) -> Option<Vector2<f32>> {
+        let points: Vec<Point3<f32>> = self
+            .outriggers
+            .iter()
+            .filter_map(|o| {
+                let z = o.jack_top_elevation_m()?;
+                let p = o.get_contact_point(self.crane_base_width_m);
+                Some(Point3::new(p.x, p.y, z))
+            })
+            .collect();
+        if points.len() < 3 {
+            return None;
+        }
 
-            width * length
-        } else {
-            // TODO: Implement proper polygon area calculation
-            0.0
+        // Normal equations for z = a + b·x + c·y
+        let mut ata = Matrix3::zeros();
+        let mut atz = Vector3::zeros();
+        for p in &points {
+            let row = Vector3::new(1.0, p.x, p.y);
+            ata += row * row.transpose();
+            atz += row * p.z;
         }
+        let solution = ata.try_inverse()? * atz;
+        Some(Vector2::new(solution.y, solution.z))
     }
 
     /// Validate entire outrigger system
@@ -243,7 +662,7 @@ impl OutriggerSystem {
     /// Medium extension (75% - good for most lifts)
     pub fn preset_medium_extension(&mut self) {
         for outrigger in &mut self.outriggers {
-            outrigger.extension_m = outrigger.max_extension_m * 0.75;
+            outrigger.extension_m = outrigger.snap_extension(outrigger.max_extension_m * 0.75);
             outrigger.deployment = OutriggerDeployment::Set {
                 jack_extension_m: 0.5,
             };
@@ -267,3 +686,113 @@ impl OutriggerSystem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::test_crane;
+
+    #[test]
+    fn test_support_polygon_edges() {
+        let config = test_crane(30.0, 60.0);
+        let hull = config.outriggers.support_polygon();
+        assert_eq!(hull.len(), 4);
+
+        let edges = config.outriggers.tipping_edges();
+        assert_eq!(edges.len(), 4);
+        assert!(edges.iter().all(|e| e.distance_m > 0.0));
+
+        // Slewed over the right side the boom crosses the right-hand edge
+        let right = config.outriggers.boom_side_edge(90.0).unwrap();
+        assert!(right.normal.x > 0.99);
+        let legs = [right.from, right.to];
+        assert!(legs.contains(&OutriggerPosition::FrontRight));
+        assert!(legs.contains(&OutriggerPosition::RearRight));
+    }
+
+    #[test]
+    fn test_set_extension_per_leg() {
+        let mut system = OutriggerSystem::new(2.75, 3.0, 7.1);
+        system.preset_max_extension();
+        let leg = OutriggerPosition::RearLeft;
+        let min = system.get_outrigger(leg).unwrap().min_extension_m;
+
+        system.set_extension(leg, min).unwrap();
+        assert!(system.is_asymmetric());
+        assert!((system.extension_pct(leg).unwrap() - 50.0).abs() < 1e-3);
+        assert!(system.set_extension(leg, min * 0.5).is_err());
+        assert!(system.set_extension_pct(leg, 120.0).is_err());
+    }
+
+    #[test]
+    fn test_variable_base_capacity_follows_slew() {
+        let mut config = test_crane(30.0, 60.0);
+        for leg in [OutriggerPosition::FrontRight, OutriggerPosition::RearRight] {
+            let min = config
+                .outriggers
+                .get_outrigger(leg)
+                .unwrap()
+                .min_extension_m;
+            config.outriggers.set_extension(leg, min).unwrap();
+        }
+
+        let radius = config.get_radius();
+        config.swing_angle_deg = 270.0;
+        let over_long = config.get_current_capacity().unwrap();
+        assert!((config.get_outrigger_capacity_factor(radius) - 1.0).abs() < 1e-6);
+
+        config.swing_angle_deg = 90.0;
+        let over_short = config.get_current_capacity().unwrap();
+        assert!(config.get_outrigger_capacity_factor(radius) < 1.0);
+        assert!(over_short < over_long);
+
+        let (_, edge) = config.get_tipping_load_kg(radius).unwrap();
+        assert!(edge.normal.x > 0.99);
+    }
+
+    #[test]
+    fn test_model_geometry_and_pinned_spans() {
+        let mut system = test_crane(30.0, 60.0).outriggers;
+        let leg = OutriggerPosition::FrontRight;
+
+        // 7.6 m wide support base at full span, boxes 7.0 m apart
+        let contact = system.get_outrigger(leg).unwrap().get_contact_point(0.0);
+        assert!((contact.x - 3.8).abs() < 1e-4);
+        assert!((contact.y - 3.9).abs() < 1e-4);
+        assert!((system.calculate_support_area() - 7.6 * 7.0).abs() < 1e-3);
+
+        assert!(system.set_extension(leg, 1.5).is_err());
+        system.set_extension(leg, 1.3).unwrap();
+
+        // Percentages drop to the next pinned span
+        system.set_extension_pct(leg, 70.0).unwrap();
+        assert!((system.get_outrigger(leg).unwrap().extension_m - 1.3).abs() < 1e-4);
+        assert!(system.validate().is_ok());
+
+        // 0% pulls the leg in; anything above it takes a pinned span
+        system.set_extension_pct(leg, 0.0).unwrap();
+        assert!(!system.get_outrigger(leg).unwrap().is_deployed());
+        assert_eq!(system.extension_pct(leg), Some(0.0));
+        assert!(!system.all_deployed());
+
+        system.set_extension_pct(leg, 5.0).unwrap();
+        let outrigger = system.get_outrigger(leg).unwrap();
+        assert!(outrigger.is_deployed());
+        assert_eq!(outrigger.extension_m, outrigger.min_extension_m);
+
+        system.preset_medium_extension();
+        assert!(system.outriggers.iter().all(|o| o.extension_m == 1.9));
+    }
+
+    #[test]
+    fn test_support_area_any_count() {
+        let mut system = OutriggerSystem::new(2.75, 3.0, 7.1);
+        system.preset_max_extension();
+        let square = system.calculate_support_area();
+        assert!(square > 0.0);
+
+        // Triangle from three legs is half the square
+        system.outriggers[3].deployment = OutriggerDeployment::Retracted;
+        assert!((system.calculate_support_area() - square / 2.0).abs() < 1e-3);
+    }
+}
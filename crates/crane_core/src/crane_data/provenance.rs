@@ -0,0 +1,203 @@
+// crates/crane_core/src/crane_data/provenance.rs
+
+use super::capacity::{CapacityChart, CapacityLimit, LoadChart};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+/// Where a chart set came from, for audit trails
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChartProvenance {
+    pub manufacturer: String,
+    /// Manufacturer load chart document number
+    pub document_number: String,
+    pub revision: String,
+    /// Revision date as printed (ISO 8601 preferred)
+    #[serde(default)]
+    pub revision_date: Option<String>,
+    /// Crane serial numbers the chart applies to (empty = every serial)
+    #[serde(default)]
+    pub serial_numbers: Vec<String>,
+    /// Units as printed on the source chart, e.g. "t, m"
+    #[serde(default)]
+    pub units: String,
+    /// Tool and format that read the chart in
+    #[serde(default)]
+    pub importer: String,
+    /// File the chart was read from
+    #[serde(default)]
+    pub source_file: Option<String>,
+    /// SHA-256 of the chart content, filled in when the chart is built
+    #[serde(default)]
+    pub content_hash: String,
+}
+
+impl ChartProvenance {
+    pub fn new(manufacturer: &str, document_number: &str, revision: &str) -> Self {
+        Self {
+            manufacturer: manufacturer.to_string(),
+            document_number: document_number.to_string(),
+            revision: revision.to_string(),
+            importer: format!("crane_core {}", env!("CARGO_PKG_VERSION")),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_units(mut self, units: &str) -> Self {
+        self.units = units.to_string();
+        self
+    }
+
+    /// Name the source format after the crate version
+    pub fn with_importer(mut self, format: &str) -> Self {
+        self.importer = format!("crane_core {} {}", env!("CARGO_PKG_VERSION"), format);
+        self
+    }
+
+    pub fn with_source_file(mut self, path: &str) -> Self {
+        self.source_file = Some(path.to_string());
+        self
+    }
+
+    /// Whether the chart covers a crane serial number
+    pub fn applies_to_serial(&self, serial: &str) -> bool {
+        self.serial_numbers.is_empty() || self.serial_numbers.iter().any(|s| s == serial)
+    }
+
+    /// `document rev revision` label
+    pub fn describe(&self) -> String {
+        match &self.revision_date {
+            Some(date) => format!("{} rev {} ({})", self.document_number, self.revision, date),
+            None => format!("{} rev {}", self.document_number, self.revision),
+        }
+    }
+}
+
+impl LoadChart {
+    /// SHA-256 over the chart's points and notes
+    pub fn content_hash(&self) -> String {
+        let mut content = String::new();
+        write_chart(&mut content, self);
+        sha256_hex(&content)
+    }
+}
+
+impl CapacityChart {
+    /// SHA-256 over every chart and the derating factors
+    ///
+    /// Values are hashed by their bit patterns, so the same chart imported
+    /// from CSV, JSON or a matrix gives the same hash. Provenance is not
+    /// part of the content.
+    pub fn content_hash(&self) -> String {
+        let mut content = String::new();
+        for factor in [
+            self.over_side_factor,
+            self.over_rear_factor,
+            self.dynamic_factor,
+            self.outrigger_intermediate_factor,
+            self.on_tires_factor,
+        ] {
+            let _ = write!(content, "{:08x} ", factor.to_bits());
+        }
+        content.push('\n');
+
+        let mut charts: Vec<&LoadChart> = self.charts.values().collect();
+        charts.sort_by_key(|c| c.key());
+        for chart in charts {
+            write_chart(&mut content, chart);
+        }
+        sha256_hex(&content)
+    }
+
+    /// Record the chart's source, stamping its current content hash
+    pub fn set_provenance(&mut self, mut provenance: ChartProvenance) {
+        provenance.content_hash = self.content_hash();
+        self.provenance = Some(provenance);
+    }
+
+    /// Whether the content still matches the recorded hash
+    pub fn verify_integrity(&self) -> bool {
+        self.provenance
+            .as_ref()
+            .is_none_or(|p| p.content_hash == self.content_hash())
+    }
+}
+
+fn write_chart(content: &mut String, chart: &LoadChart) {
+    let _ = writeln!(
+        content,
+        "chart {} {}",
+        chart.key(),
+        chart.notes.as_deref().unwrap_or("")
+    );
+    // JSON charts keep their file order; hash in radius order
+    let mut points: Vec<_> = chart.points.iter().collect();
+    points.sort_by(|a, b| a.radius_m.total_cmp(&b.radius_m));
+    for point in points {
+        let limit = match point.limit {
+            Some(CapacityLimit::Structural) => "S",
+            Some(CapacityLimit::Stability) => "T",
+            None => "-",
+        };
+        let _ = writeln!(
+            content,
+            "{:08x} {:08x} {} {}",
+            point.radius_m.to_bits(),
+            point.capacity_kg.to_bits(),
+            limit,
+            point.footnotes.join(",")
+        );
+    }
+}
+
+fn sha256_hex(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .fold(String::new(), |mut s, b| {
+            let _ = write!(s, "{:02x}", b);
+            s
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{CapacityChartBuilder, test_spec};
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_chart_hash_tracks_content() {
+        let mut chart = test_spec("liebherr_ltm_1100_5_2").capacity_chart;
+        let hash = chart.content_hash();
+        assert_eq!(hash.len(), 64);
+
+        // Same data read from another format hashes the same
+        let csv = "boom_length,radius,capacity\n30.0,3.0,100000\n30.0,5.0,80000";
+        let json = r#"{"charts": [{"boom_length_m": 30.0, "notes": null, "points": [
+            {"radius_m": 5.0, "capacity_kg": 80000}, {"radius_m": 3.0, "capacity_kg": 100000}]}]}"#;
+        let from_csv = CapacityChartBuilder::new()
+            .add_charts_from_csv(csv)
+            .unwrap()
+            .build();
+        let from_json = CapacityChartBuilder::new()
+            .add_charts_from_json(json)
+            .unwrap()
+            .build();
+        assert_eq!(from_csv.content_hash(), from_json.content_hash());
+
+        chart.set_provenance(ChartProvenance::new("Liebherr", "LTM-1100-TAB", "B"));
+        assert!(chart.verify_integrity());
+
+        let key = chart.charts.keys().next().unwrap().clone();
+        chart.charts.get_mut(&key).unwrap().points[0].capacity_kg += 100.0;
+        assert_ne!(chart.content_hash(), hash);
+        assert!(!chart.verify_integrity());
+    }
+}
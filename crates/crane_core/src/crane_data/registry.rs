@@ -0,0 +1,637 @@
+// crates/crane_core/src/crane_data/registry.rs
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+use super::capacity::CapacityChart;
+use super::chart_validation::{ChartFormat, ChartValidator, Severity};
+use super::spec::CraneSpec;
+use super::units::UnitSystem;
+
+/// Where the fleet library lives, relative to the asset root
+pub const DEFAULT_SPEC_DIR: &str = "assets/cranes";
+
+/// Environment variable naming the fleet library directory
+pub const SPEC_DIR_ENV: &str = "CRANE_SPEC_DIR";
+
+/// The fleet library directory
+///
+/// `CRANE_SPEC_DIR` wins when set. Otherwise `DEFAULT_SPEC_DIR` is looked
+/// for under the same roots Bevy uses for its asset folder
+/// (`BEVY_ASSET_ROOT`, `CARGO_MANIFEST_DIR`, the executable's directory),
+/// then the working directory; the first that exists is used.
+pub fn default_spec_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(SPEC_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+
+    let exe_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+    let candidates: Vec<PathBuf> = [
+        std::env::var_os("BEVY_ASSET_ROOT").map(PathBuf::from),
+        std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from),
+        exe_dir,
+        Some(PathBuf::new()),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|root| root.join(DEFAULT_SPEC_DIR))
+    .collect();
+
+    candidates
+        .iter()
+        .find(|dir| dir.is_dir())
+        .unwrap_or(&candidates[0])
+        .clone()
+}
+
+macro_rules! embedded {
+    ($path:literal) => {
+        ($path, include_str!(concat!("../../../../assets/", $path)))
+    };
+}
+
+/// The fleet library built into the binary, keyed by path under `assets/`
+///
+/// Used when the spec directory can't be read, as on the web build where
+/// there is no file system.
+const BUILTIN_LIBRARY: &[(&str, &str)] = &[
+    embedded!("cranes/grove_gmk_5150l.ron"),
+    embedded!("cranes/grove_gmk_6300l.ron"),
+    embedded!("cranes/liebherr_280_ec_h_12.ron"),
+    embedded!("cranes/liebherr_lr_1300_sx.ron"),
+    embedded!("cranes/liebherr_ltm_1100_5_2.ron"),
+    embedded!("cranes/liebherr_ltm_1500_8_1.ron"),
+    embedded!("cranes/link_belt_htc_8690.ron"),
+    embedded!("cranes/tadano_gr_1000xl.ron"),
+    embedded!("cranes/tadano_gr_600xl.ron"),
+    embedded!("cranes/terex_rt_780.ron"),
+    embedded!("load_charts/example_liebherr_280_ec_h_12.csv"),
+    embedded!("load_charts/example_liebherr_lr_1300_end.csv"),
+    embedded!("load_charts/example_liebherr_lr_1300_side.csv"),
+    embedded!("load_charts/example_liebherr_ltm_1100.csv"),
+];
+
+/// Contents of a built-in library file
+fn builtin_file(path: &Path) -> Option<&'static str> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                parts.pop();
+            }
+            Component::Normal(part) => parts.push(part.to_string_lossy()),
+            _ => {}
+        }
+    }
+    let key = parts.join("/");
+    BUILTIN_LIBRARY
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, data)| *data)
+}
+
+/// One crane in the library: the spec and the chart files it uses
+///
+/// Chart paths are relative to the spec file. A chart file replaces the
+/// charts in `spec.capacity_chart`, keeping its derating factors and
+/// provenance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chart_file: Option<String>,
+    /// Crawler over-side chart
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub over_side_chart_file: Option<String>,
+    pub spec: CraneSpec,
+}
+
+impl SpecFile {
+    pub fn new(spec: CraneSpec) -> Self {
+        Self {
+            chart_file: None,
+            over_side_chart_file: None,
+            spec,
+        }
+    }
+
+    /// Parse a `.ron` or `.json` spec file
+    ///
+    /// JSON specs may use imperial field names (see `CraneSpec::from_json`).
+    pub fn parse(path: &Path, data: &str) -> Result<Self, String> {
+        match extension(path).as_str() {
+            "ron" => ron::from_str(data).map_err(|e| format!("RON parse error: {}", e)),
+            "json" => {
+                let value: serde_json::Value =
+                    serde_json::from_str(data).map_err(|e| format!("JSON parse error: {}", e))?;
+                let spec = value.get("spec").ok_or("Spec file has no \"spec\" entry")?;
+                let file_name = |key: &str| match value.get(key) {
+                    None | Some(serde_json::Value::Null) => Ok(None),
+                    Some(serde_json::Value::String(name)) => Ok(Some(name.clone())),
+                    Some(_) => Err(format!("\"{}\" must be a file name", key)),
+                };
+                Ok(Self {
+                    chart_file: file_name("chart_file")?,
+                    over_side_chart_file: file_name("over_side_chart_file")?,
+                    spec: CraneSpec::from_json(&spec.to_string())?,
+                })
+            }
+            other => Err(format!("Unknown spec file type '.{}'", other)),
+        }
+    }
+
+    pub fn to_ron(&self) -> Result<String, String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default().compact_arrays(true))
+            .map_err(|e| format!("RON write error: {}", e))
+    }
+
+    pub fn to_json(&self, units: UnitSystem) -> Result<String, String> {
+        let spec: serde_json::Value = serde_json::from_str(&self.spec.to_json(units)?)
+            .map_err(|e| format!("JSON write error: {}", e))?;
+        let mut value = serde_json::json!({ "spec": spec });
+        if let Some(file) = &self.chart_file {
+            value["chart_file"] = file.clone().into();
+        }
+        if let Some(file) = &self.over_side_chart_file {
+            value["over_side_chart_file"] = file.clone().into();
+        }
+        serde_json::to_string_pretty(&value).map_err(|e| format!("JSON write error: {}", e))
+    }
+}
+
+/// A spec file that could not be used
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecIssue {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for SpecIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// A loaded crane and the files it came from
+#[derive(Debug, Clone)]
+pub struct RegisteredSpec {
+    pub spec: CraneSpec,
+    pub source: PathBuf,
+    pub chart_files: Vec<PathBuf>,
+}
+
+/// Crane specs discovered in a directory of RON/JSON spec files
+///
+/// Files are read in name order; a spec that fails to parse or validate
+/// is reported in `issues` and left out. When a file that loaded before
+/// goes bad on reload, its last good spec is kept.
+#[derive(Debug, Clone, Default)]
+pub struct SpecRegistry {
+    dir: PathBuf,
+    entries: Vec<RegisteredSpec>,
+    issues: Vec<SpecIssue>,
+    /// Fall back to the built-in library when `dir` can't be read
+    builtin_fallback: bool,
+    /// Specs currently come from the built-in library
+    builtin: bool,
+    /// Modification times of every file read, to spot edits
+    stamps: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl SpecRegistry {
+    /// Load every spec in `dir`
+    pub fn load(dir: impl Into<PathBuf>) -> Self {
+        let mut registry = Self {
+            dir: dir.into(),
+            ..Self::default()
+        };
+        registry.reload();
+        registry
+    }
+
+    /// Load the library from `default_spec_dir()`, or the built-in
+    /// library when that can't be read
+    pub fn load_default() -> Self {
+        let mut registry = Self {
+            dir: default_spec_dir(),
+            builtin_fallback: true,
+            ..Self::default()
+        };
+        registry.reload();
+        registry
+    }
+
+    /// Whether the specs came from the built-in library
+    pub fn is_builtin(&self) -> bool {
+        self.builtin
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn specs(&self) -> impl Iterator<Item = &CraneSpec> {
+        self.entries.iter().map(|e| &e.spec)
+    }
+
+    pub fn entries(&self) -> &[RegisteredSpec] {
+        &self.entries
+    }
+
+    pub fn issues(&self) -> &[SpecIssue] {
+        &self.issues
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CraneSpec> {
+        self.specs().find(|s| s.id == id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Re-read the directory
+    pub fn reload(&mut self) {
+        let previous = std::mem::take(&mut self.entries);
+        self.issues.clear();
+        self.stamps.clear();
+        self.builtin = false;
+
+        let files = match spec_files(&self.dir) {
+            Ok(files) => files,
+            Err(_) if self.builtin_fallback => {
+                self.builtin = true;
+                BUILTIN_LIBRARY
+                    .iter()
+                    .map(|(name, _)| PathBuf::from(name))
+                    .filter(|path| path.starts_with("cranes"))
+                    .collect()
+            }
+            Err(message) => {
+                self.issues.push(SpecIssue {
+                    path: self.dir.clone(),
+                    message,
+                });
+                return;
+            }
+        };
+
+        for path in files {
+            let entry = self.read_entry(&path).and_then(|entry| {
+                match self.entries.iter().find(|e| e.spec.id == entry.spec.id) {
+                    Some(other) => Err(format!(
+                        "Crane id '{}' is already used by {}",
+                        entry.spec.id,
+                        other.source.display()
+                    )),
+                    None => Ok(entry),
+                }
+            });
+
+            match entry {
+                Ok(entry) => self.entries.push(entry),
+                Err(message) => {
+                    self.issues.push(SpecIssue {
+                        path: path.clone(),
+                        message,
+                    });
+                    if let Some(old) = previous.iter().find(|e| e.source == path)
+                        && !self.entries.iter().any(|e| e.spec.id == old.spec.id)
+                    {
+                        self.entries.push(old.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reload when a spec or chart file was added, removed or edited
+    pub fn reload_if_changed(&mut self) -> bool {
+        let mut current = BTreeMap::new();
+        if let Ok(files) = spec_files(&self.dir) {
+            for path in files {
+                current.insert(path.clone(), modified(&path));
+            }
+        }
+        for path in self.stamps.keys() {
+            if !current.contains_key(path) && path.exists() {
+                current.insert(path.clone(), modified(path));
+            }
+        }
+
+        if current == self.stamps {
+            return false;
+        }
+        self.reload();
+        true
+    }
+
+    /// Read a library file, noting its modification time
+    fn read_file(&mut self, path: &Path) -> Result<String, String> {
+        if self.builtin {
+            return builtin_file(path)
+                .map(str::to_string)
+                .ok_or_else(|| "Not in the built-in library".to_string());
+        }
+        self.stamps.insert(path.to_path_buf(), modified(path));
+        std::fs::read_to_string(path).map_err(|e| e.to_string())
+    }
+
+    fn read_entry(&mut self, path: &Path) -> Result<RegisteredSpec, String> {
+        let data = self.read_file(path)?;
+        let file = SpecFile::parse(path, &data)?;
+        let mut spec = file.spec;
+        let base = path.parent().unwrap_or(Path::new(""));
+        let mut chart_files = Vec::new();
+
+        if let Some(name) = &file.chart_file {
+            let chart_path = base.join(name);
+            spec.capacity_chart =
+                self.read_chart(&chart_path, spec.capacity_chart.clone(), &spec)?;
+            chart_files.push(chart_path);
+        }
+        if let Some(name) = &file.over_side_chart_file {
+            let chart_path = base.join(name);
+            let chart = spec
+                .crawler
+                .as_ref()
+                .and_then(|c| c.over_side_chart.clone())
+                .ok_or("over_side_chart_file given for a crane without an over-side chart")?;
+            let chart = self.read_chart(&chart_path, chart, &spec)?;
+            if let Some(crawler) = spec.crawler.as_mut() {
+                crawler.over_side_chart = Some(chart);
+            }
+            chart_files.push(chart_path);
+        }
+
+        validate_spec(&spec)?;
+        Ok(RegisteredSpec {
+            spec,
+            source: path.to_path_buf(),
+            chart_files,
+        })
+    }
+
+    /// Chart with its data replaced by a chart file's, provenance restamped
+    fn read_chart(
+        &mut self,
+        path: &Path,
+        mut chart: CapacityChart,
+        spec: &CraneSpec,
+    ) -> Result<CapacityChart, String> {
+        let data = self
+            .read_file(path)
+            .map_err(|e| format!("Chart file {}: {}", path.display(), e))?;
+
+        let format = ChartFormat::detect(path, &data);
+        let report = ChartValidator::default().validate_source(&data, format, Some(spec));
+        if let Some(error) = report
+            .diagnostics
+            .iter()
+            .find(|d| d.severity == Severity::Error)
+        {
+            return Err(format!("Chart file {}: {}", path.display(), error));
+        }
+
+        chart.charts.clear();
+        for load_chart in report.charts {
+            chart.add_chart(load_chart);
+        }
+        if let Some(mut provenance) = chart.provenance.take() {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            provenance.source_file = Some(name.to_string());
+            chart.set_provenance(provenance);
+        }
+        Ok(chart)
+    }
+}
+
+/// Geometry and chart checks a spec must pass to join the fleet
+fn validate_spec(spec: &CraneSpec) -> Result<(), String> {
+    if spec.id.trim().is_empty() {
+        return Err("Crane id is empty".to_string());
+    }
+    let (min_boom, max_boom) = spec.boom_length_range;
+    if !(min_boom > 0.0 && min_boom <= max_boom) {
+        return Err(format!(
+            "Boom length range {}-{} m is invalid",
+            min_boom, max_boom
+        ));
+    }
+    if spec.min_boom_angle_deg > spec.max_boom_angle_deg {
+        return Err(format!(
+            "Boom angle range {}-{}° is invalid",
+            spec.min_boom_angle_deg, spec.max_boom_angle_deg
+        ));
+    }
+    if spec.max_capacity_kg <= 0.0 {
+        return Err("Maximum capacity must be positive".to_string());
+    }
+
+    match ChartValidator::default()
+        .validate_spec(spec)
+        .into_iter()
+        .find(|d| d.severity == Severity::Error)
+    {
+        Some(error) => Err(error.to_string()),
+        None => Ok(()),
+    }
+}
+
+fn spec_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| format!("Cannot read spec directory: {}", e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && matches!(extension(path).as_str(), "ron" | "json"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{test_library, test_spec};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("crane_registry_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_library_loads_fleet() {
+        let registry = test_library();
+        assert!(registry.issues().is_empty(), "{:?}", registry.issues());
+        assert_eq!(registry.len(), 10);
+
+        // Charts kept in separate files are read in and stamped with their source
+        for id in [
+            "liebherr_ltm_1100_5_2",
+            "liebherr_lr_1300_sx",
+            "liebherr_280_ec_h_12",
+        ] {
+            let entry = registry.entries().iter().find(|e| e.spec.id == id).unwrap();
+            assert!(!entry.chart_files.is_empty());
+
+            let provenance = entry.spec.capacity_chart.provenance.as_ref().unwrap();
+            assert!(provenance.source_file.is_some());
+            assert!(entry.spec.capacity_chart.verify_integrity());
+            assert!(!entry.spec.capacity_chart.charts.is_empty());
+        }
+        let lr_1300 = registry.get("liebherr_lr_1300_sx").unwrap();
+        assert!(lr_1300.crawler.as_ref().unwrap().over_side_chart.is_some());
+    }
+
+    #[test]
+    fn test_builtin_library_when_dir_unreadable() {
+        // An explicit directory that can't be read is an issue
+        let missing = SpecRegistry::load("no/such/library");
+        assert!(missing.is_empty());
+        assert_eq!(missing.issues().len(), 1);
+
+        let mut registry = SpecRegistry {
+            dir: PathBuf::from("no/such/library"),
+            builtin_fallback: true,
+            ..SpecRegistry::default()
+        };
+        registry.reload();
+        assert!(registry.is_builtin());
+        assert!(registry.issues().is_empty(), "{:?}", registry.issues());
+        assert!(!registry.reload_if_changed());
+
+        // Same fleet and charts as the files on disk
+        let fleet = test_library();
+        assert_eq!(registry.len(), fleet.len());
+        for spec in fleet.specs() {
+            let builtin = registry.get(&spec.id).unwrap();
+            assert_eq!(
+                builtin.capacity_chart.content_hash(),
+                spec.capacity_chart.content_hash()
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_and_ron_specs_with_chart_file() {
+        let dir = temp_dir("formats");
+        std::fs::write(
+            dir.join("chart.csv"),
+            "boom_length,radius,capacity\n30.0,3.0,50000\n30.0,10.0,20000\n",
+        )
+        .unwrap();
+
+        let mut ron_spec = SpecFile::new(test_spec("terex_rt_780"));
+        ron_spec.chart_file = Some("chart.csv".to_string());
+        std::fs::write(dir.join("a.ron"), ron_spec.to_ron().unwrap()).unwrap();
+
+        let mut json_spec = SpecFile::new(test_spec("grove_gmk_5150l"));
+        json_spec.spec.id = "grove_imperial".to_string();
+        std::fs::write(
+            dir.join("b.json"),
+            json_spec.to_json(UnitSystem::Imperial).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a spec").unwrap();
+
+        let registry = SpecRegistry::load(&dir);
+        assert!(registry.issues().is_empty(), "{:?}", registry.issues());
+        assert_eq!(registry.len(), 2);
+
+        let terex = registry.get("terex_rt_780").unwrap();
+        assert_eq!(terex.capacity_chart.charts.len(), 1);
+        assert_eq!(
+            registry.entries()[0].chart_files,
+            vec![dir.join("chart.csv")]
+        );
+
+        let grove = registry.get("grove_imperial").unwrap();
+        let reference = test_spec("grove_gmk_5150l");
+        assert_eq!(grove.boom_length_range, reference.boom_length_range);
+        assert_eq!(grove.max_capacity_kg, reference.max_capacity_kg);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_invalid_specs_are_reported() {
+        let dir = temp_dir("invalid");
+        let mut bad_range = SpecFile::new(test_spec("terex_rt_780"));
+        bad_range.spec.boom_length_range = (40.0, 20.0);
+        std::fs::write(dir.join("a.ron"), bad_range.to_ron().unwrap()).unwrap();
+
+        let duplicate = SpecFile::new(test_spec("tadano_gr_600xl"));
+        std::fs::write(dir.join("b.ron"), duplicate.to_ron().unwrap()).unwrap();
+        std::fs::write(dir.join("c.ron"), duplicate.to_ron().unwrap()).unwrap();
+
+        let mut missing_chart = SpecFile::new(test_spec("grove_gmk_6300l"));
+        missing_chart.chart_file = Some("missing.csv".to_string());
+        std::fs::write(dir.join("d.ron"), missing_chart.to_ron().unwrap()).unwrap();
+        std::fs::write(dir.join("e.json"), "{ \"spec\": 3 }").unwrap();
+
+        let registry = SpecRegistry::load(&dir);
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get("tadano_gr_600xl").is_some());
+
+        let failed: Vec<_> = registry
+            .issues()
+            .iter()
+            .map(|i| i.path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(failed, vec!["a.ron", "c.ron", "d.ron", "e.json"]);
+        assert!(registry.issues()[0].message.contains("Boom length range"));
+        assert!(registry.issues()[1].message.contains("already used by"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_hot_reload() {
+        let dir = temp_dir("reload");
+        let file = SpecFile::new(test_spec("terex_rt_780"));
+        std::fs::write(dir.join("terex.ron"), file.to_ron().unwrap()).unwrap();
+
+        let mut registry = SpecRegistry::load(&dir);
+        assert!(!registry.reload_if_changed());
+
+        // A new crane is a new file
+        let added = SpecFile::new(test_spec("tadano_gr_1000xl"));
+        std::fs::write(dir.join("tadano.ron"), added.to_ron().unwrap()).unwrap();
+        assert!(registry.reload_if_changed());
+        assert_eq!(registry.len(), 2);
+
+        // A broken edit keeps the last good spec and reports the file
+        std::fs::write(dir.join("terex.ron"), "(spec: (id: ").unwrap();
+        registry.reload();
+        assert_eq!(registry.len(), 2);
+        assert!(registry.get("terex_rt_780").is_some());
+        assert_eq!(registry.issues().len(), 1);
+
+        std::fs::remove_file(dir.join("tadano.ron")).unwrap();
+        assert!(registry.reload_if_changed());
+        assert!(registry.get("tadano_gr_1000xl").is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
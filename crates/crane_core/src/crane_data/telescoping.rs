@@ -0,0 +1,269 @@
+// crates/crane_core/src/crane_data/telescoping.rs
+
+use super::errors::{CraneConfigError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Tolerance when matching section extensions against a mode table (percent)
+const EXTENSION_TOLERANCE_PCT: f32 = 0.5;
+
+/// Telescoping mode (e.g. "T1"): the legal section extension steps
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelescopingMode {
+    pub code: String,
+    pub description: Option<String>,
+    /// Each step lists the extension (0-100%) of every telescopic section
+    pub steps: Vec<Vec<f32>>,
+}
+
+impl TelescopingMode {
+    /// Whether `extension_pct` matches one of the mode's steps
+    pub fn allows(&self, extension_pct: &[f32]) -> bool {
+        self.steps.iter().any(|step| {
+            step.len() == extension_pct.len()
+                && step
+                    .iter()
+                    .zip(extension_pct)
+                    .all(|(a, b)| (a - b).abs() <= EXTENSION_TOLERANCE_PCT)
+        })
+    }
+}
+
+/// Telescopic boom geometry and telescoping mode tables for a crane
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelescopingSystem {
+    /// Boom length with all sections retracted
+    pub retracted_length_m: f32,
+    /// Full stroke of each telescopic section (base section excluded)
+    pub section_strokes_m: Vec<f32>,
+    pub modes: Vec<TelescopingMode>,
+}
+
+impl TelescopingSystem {
+    /// Equal-stroke sections with the usual two modes:
+    /// T1 extends all sections together, T2 extends them one after another
+    pub fn standard(
+        retracted_length_m: f32,
+        extended_length_m: f32,
+        telescopic_sections: usize,
+    ) -> Self {
+        const STEPS_PCT: [f32; 4] = [0.0, 46.0, 92.0, 100.0];
+
+        let n = telescopic_sections.max(1);
+        let stroke = (extended_length_m - retracted_length_m) / n as f32;
+
+        let synchronous = STEPS_PCT.iter().map(|&pct| vec![pct; n]).collect();
+
+        let mut sequential = vec![vec![0.0; n]];
+        for section in 0..n {
+            for &pct in &STEPS_PCT[1..] {
+                let mut step = vec![0.0; n];
+                step[..section].fill(100.0);
+                step[section] = pct;
+                sequential.push(step);
+            }
+        }
+
+        Self {
+            retracted_length_m,
+            section_strokes_m: vec![stroke; n],
+            modes: vec![
+                TelescopingMode {
+                    code: "T1".to_string(),
+                    description: Some("All sections extend together".to_string()),
+                    steps: synchronous,
+                },
+                TelescopingMode {
+                    code: "T2".to_string(),
+                    description: Some("Sections extend in sequence".to_string()),
+                    steps: sequential,
+                },
+            ],
+        }
+    }
+
+    pub fn section_count(&self) -> usize {
+        self.section_strokes_m.len()
+    }
+
+    pub fn get_mode(&self, code: &str) -> Option<&TelescopingMode> {
+        self.modes.iter().find(|m| m.code == code)
+    }
+
+    /// Boom length for a set of section extensions
+    pub fn boom_length_m(&self, extension_pct: &[f32]) -> f32 {
+        self.retracted_length_m
+            + self
+                .section_strokes_m
+                .iter()
+                .zip(extension_pct)
+                .map(|(stroke, pct)| stroke * pct / 100.0)
+                .sum::<f32>()
+    }
+
+    /// All boom lengths available in a mode
+    pub fn available_lengths_m(&self, code: &str) -> Vec<f32> {
+        self.get_mode(code)
+            .map(|mode| {
+                mode.steps
+                    .iter()
+                    .map(|step| self.boom_length_m(step))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Legal state in `code` whose boom length is closest to `boom_length_m`
+    pub fn closest_state(&self, code: &str, boom_length_m: f32) -> Option<TelescopeState> {
+        let mode = self.get_mode(code)?;
+
+        mode.steps
+            .iter()
+            .min_by(|a, b| {
+                let da = (self.boom_length_m(a) - boom_length_m).abs();
+                let db = (self.boom_length_m(b) - boom_length_m).abs();
+                da.total_cmp(&db)
+            })
+            .map(|step| TelescopeState {
+                mode: code.to_string(),
+                section_extension_pct: step.clone(),
+            })
+    }
+
+    /// Check that a telescope state is a legal combination for this boom
+    pub fn validate(&self, state: &TelescopeState) -> Result<()> {
+        let mode =
+            self.get_mode(&state.mode)
+                .ok_or_else(|| CraneConfigError::TelescopingInvalid {
+                    reason: format!("Unknown telescoping mode '{}'", state.mode),
+                })?;
+
+        if state.section_extension_pct.len() != self.section_count() {
+            return Err(CraneConfigError::TelescopingInvalid {
+                reason: format!(
+                    "Expected {} telescopic sections, got {}",
+                    self.section_count(),
+                    state.section_extension_pct.len()
+                ),
+            });
+        }
+
+        if let Some(pct) = state
+            .section_extension_pct
+            .iter()
+            .find(|p| !(0.0..=100.0).contains(*p))
+        {
+            return Err(CraneConfigError::TelescopingInvalid {
+                reason: format!("Section extension {:.0}% is outside 0-100%", pct),
+            });
+        }
+
+        if !mode.allows(&state.section_extension_pct) {
+            return Err(CraneConfigError::TelescopingInvalid {
+                reason: format!(
+                    "Extension {} is not a step of mode {}",
+                    state.describe(),
+                    state.mode
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Current telescoping mode and per-section extension
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TelescopeState {
+    pub mode: String,
+    /// Extension (0-100%) of each telescopic section, innermost first
+    pub section_extension_pct: Vec<f32>,
+}
+
+impl TelescopeState {
+    /// Number of sections extended beyond the base
+    pub fn extended_sections(&self) -> usize {
+        self.section_extension_pct
+            .iter()
+            .filter(|&&p| p > 0.0)
+            .count()
+    }
+
+    /// Extension as a manufacturer-style string, e.g. "0-46-46-46"
+    pub fn describe(&self) -> String {
+        self.section_extension_pct
+            .iter()
+            .map(|p| format!("{:.0}", p))
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_modes() {
+        let system = TelescopingSystem::standard(15.0, 55.0, 4);
+
+        let t1 = system.get_mode("T1").unwrap();
+        assert_eq!(t1.steps.len(), 4);
+        assert!(t1.allows(&[46.0, 46.0, 46.0, 46.0]));
+        assert!(!t1.allows(&[100.0, 46.0, 46.0, 46.0]));
+
+        let t2 = system.get_mode("T2").unwrap();
+        assert_eq!(t2.steps.len(), 13);
+        assert!(t2.allows(&[100.0, 46.0, 0.0, 0.0]));
+
+        assert_eq!(system.boom_length_m(&[100.0; 4]), 55.0);
+        assert_eq!(system.boom_length_m(&[100.0, 100.0, 0.0, 0.0]), 35.0);
+    }
+
+    #[test]
+    fn test_validate_state() {
+        let system = TelescopingSystem::standard(15.0, 55.0, 4);
+
+        let state = system.closest_state("T1", 34.0).unwrap();
+        assert_eq!(state.section_extension_pct, vec![46.0; 4]);
+        assert!(system.validate(&state).is_ok());
+
+        let illegal = TelescopeState {
+            mode: "T1".to_string(),
+            section_extension_pct: vec![100.0, 0.0, 0.0, 0.0],
+        };
+        assert!(system.validate(&illegal).is_err());
+
+        let unknown = TelescopeState {
+            mode: "T9".to_string(),
+            section_extension_pct: vec![0.0; 4],
+        };
+        assert!(system.validate(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_configuration_boom_length_from_sections() {
+        use crate::crane_data::{CraneConfiguration, test_spec};
+
+        let mut config = CraneConfiguration::new(test_spec("liebherr_ltm_1100_5_2"));
+        config.boom_angle_deg = 70.0;
+        config.set_telescoping_mode("T2").unwrap();
+
+        let state = config.telescope.clone().unwrap();
+        let system = config.spec.telescoping.as_ref().unwrap();
+        assert_eq!(
+            config.boom_length_m,
+            system.boom_length_m(&state.section_extension_pct)
+        );
+        assert!(!matches!(
+            config.validate(),
+            Err(CraneConfigError::TelescopingInvalid { .. })
+        ));
+
+        // Boom length changed without moving the sections
+        config.boom_length_m += 3.0;
+        assert!(matches!(
+            config.validate(),
+            Err(CraneConfigError::TelescopingInvalid { .. })
+        ));
+    }
+}
@@ -0,0 +1,33 @@
+// crates/crane_core/src/crane_data/test_support.rs
+
+use std::path::Path;
+
+use super::configuration::CraneConfiguration;
+use super::registry::SpecRegistry;
+use super::spec::CraneSpec;
+
+/// The fleet library in the repository, for tests
+pub(crate) fn test_library() -> SpecRegistry {
+    SpecRegistry::load(Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/cranes"))
+}
+
+/// One spec from the fleet library, for tests
+pub(crate) fn test_spec(id: &str) -> CraneSpec {
+    static LIBRARY: std::sync::OnceLock<SpecRegistry> = std::sync::OnceLock::new();
+    LIBRARY
+        .get_or_init(test_library)
+        .get(id)
+        .cloned()
+        .unwrap_or_else(|| panic!("{} is not in the fleet library", id))
+}
+
+/// Fleet LTM 1100 on full outriggers and counterweight with the boom set,
+/// for tests
+pub(crate) fn test_crane(boom_length_m: f32, boom_angle_deg: f32) -> CraneConfiguration {
+    let mut config = CraneConfiguration::new(test_spec("liebherr_ltm_1100_5_2"));
+    config.outriggers.preset_max_extension();
+    config.counterweight.preset_max().unwrap();
+    config.boom_length_m = boom_length_m;
+    config.boom_angle_deg = boom_angle_deg;
+    config
+}
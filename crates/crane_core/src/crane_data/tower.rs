@@ -0,0 +1,287 @@
+// crates/crane_core/src/crane_data/tower.rs
+
+use super::configuration::CraneConfiguration;
+use super::errors::{CraneConfigError, Result};
+use serde::{Deserialize, Serialize};
+
+const G: f32 = 9.81;
+const AIR_DENSITY_KG_M3: f32 = 1.25;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TowerJibType {
+    /// Hammerhead / flat-top: fixed horizontal jib, radius set by the trolley
+    #[default]
+    Horizontal,
+    /// Luffing jib: radius set by jib angle, hook hangs from the jib tip
+    Luffing,
+}
+
+/// Tower crane structure, masses and wind data
+///
+/// The spec's `boom_pivot_height_m` is the jib pivot (tower) height and
+/// `boom_length_range` the jib length. Counter-jib ballast uses the spec's
+/// counterweight slabs, with the moment arm at the ballast centre. A
+/// luffing jib works between the spec's boom angle limits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TowerSpec {
+    #[serde(default)]
+    pub jib_type: TowerJibType,
+    /// Closest trolley radius (horizontal jibs)
+    pub min_trolley_radius_m: f32,
+    /// Rated capacity at the jib tip
+    pub tip_load_kg: f32,
+    pub counter_jib_length_m: f32,
+
+    pub tower_mass_kg: f32,
+    pub jib_mass_kg: f32,
+    /// Jib centre of mass from the tower axis
+    pub jib_cog_radius_m: f32,
+    pub counter_jib_mass_kg: f32,
+    /// Counter-jib centre of mass from the tower axis (behind the tower)
+    pub counter_jib_cog_radius_m: f32,
+
+    /// Effective projected wind area per metre of tower
+    pub tower_wind_area_m2_per_m: f32,
+    pub jib_wind_area_m2: f32,
+    pub counter_jib_wind_area_m2: f32,
+    pub force_coefficient: f32,
+    pub in_service_wind_speed_ms: f32,
+    pub out_of_service_wind_speed_ms: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindCondition {
+    /// Working with load, slew brake applied, wind at the in-service limit
+    InService,
+    /// Parked without load, slew brake released so the jib weathervanes
+    OutOfService,
+}
+
+/// Reactions at the top of the foundation
+#[derive(Debug, Clone)]
+pub struct FoundationReactions {
+    pub condition: WindCondition,
+    pub wind_speed_ms: f32,
+    pub vertical_kn: f32,
+    pub horizontal_kn: f32,
+    pub overturning_moment_knm: f32,
+    pub slewing_torque_knm: f32,
+}
+
+pub struct TowerFoundationCalculator;
+
+impl TowerSpec {
+    /// Hook radius at the tip of a jib: its length for a trolley jib, or
+    /// luffed down to the lowest jib angle for a luffing jib
+    pub fn tip_radius_m(&self, jib_length_m: f32, min_jib_angle_deg: f32) -> f32 {
+        match self.jib_type {
+            TowerJibType::Horizontal => jib_length_m,
+            TowerJibType::Luffing => jib_length_m * min_jib_angle_deg.to_radians().cos(),
+        }
+    }
+
+    /// Cap a chart capacity at the rated tip load out at the jib tip
+    pub fn cap_at_tip(&self, capacity_kg: f32, tip_radius_m: f32, radius_m: f32) -> f32 {
+        if radius_m >= tip_radius_m - 0.05 {
+            capacity_kg.min(self.tip_load_kg)
+        } else {
+            capacity_kg
+        }
+    }
+}
+
+impl TowerFoundationCalculator {
+    /// Foundation reactions for a hook load and wind condition
+    ///
+    /// Wind is taken in the direction that adds to the static moment;
+    /// out of service the hook is unloaded and the jib aligns with the wind.
+    pub fn reactions(
+        config: &CraneConfiguration,
+        hook_load_kg: f32,
+        condition: WindCondition,
+    ) -> Result<FoundationReactions> {
+        let tower =
+            config
+                .spec
+                .tower
+                .as_ref()
+                .ok_or_else(|| CraneConfigError::UnsafeConfiguration {
+                    reason: format!("{} is not a tower crane", config.spec.model),
+                })?;
+
+        let height = config.spec.boom_pivot_height_m;
+        let ballast_kg = config.counterweight.get_total_weight_kg();
+        let ballast_arm = config.counterweight.moment_arm_m;
+
+        let (load_kg, wind_speed) = match condition {
+            WindCondition::InService => (hook_load_kg, tower.in_service_wind_speed_ms),
+            WindCondition::OutOfService => (0.0, tower.out_of_service_wind_speed_ms),
+        };
+
+        let vertical_kg = tower.tower_mass_kg
+            + tower.jib_mass_kg
+            + tower.counter_jib_mass_kg
+            + ballast_kg
+            + load_kg;
+
+        // Static moment, positive towards the jib
+        let static_moment_kgm = load_kg * config.get_radius()
+            + tower.jib_mass_kg * tower.jib_cog_radius_m
+            - tower.counter_jib_mass_kg * tower.counter_jib_cog_radius_m
+            - ballast_kg * ballast_arm;
+
+        let pressure_kpa = 0.5 * AIR_DENSITY_KG_M3 * wind_speed.powi(2) / 1000.0;
+        let tower_force =
+            pressure_kpa * tower.force_coefficient * tower.tower_wind_area_m2_per_m * height;
+
+        // In service the wind may hit the jib side-on; parked it weathervanes
+        // and sees only the jib's end-on area, taken here as 20%.
+        let jib_area = match condition {
+            WindCondition::InService => tower.jib_wind_area_m2 + tower.counter_jib_wind_area_m2,
+            WindCondition::OutOfService => {
+                0.2 * (tower.jib_wind_area_m2 + tower.counter_jib_wind_area_m2)
+            }
+        };
+        let jib_force = pressure_kpa * tower.force_coefficient * jib_area;
+
+        let wind_moment = tower_force * height / 2.0 + jib_force * height;
+
+        // Side wind on an unbalanced jib area twists the slew ring
+        let slewing_torque_knm = match condition {
+            WindCondition::InService => {
+                let jib_arm = config.spec.boom_length_range.1 / 2.0;
+                let counter_arm = tower.counter_jib_length_m / 2.0;
+                pressure_kpa
+                    * tower.force_coefficient
+                    * (tower.jib_wind_area_m2 * jib_arm
+                        - tower.counter_jib_wind_area_m2 * counter_arm)
+                        .abs()
+            }
+            WindCondition::OutOfService => 0.0,
+        };
+
+        Ok(FoundationReactions {
+            condition,
+            wind_speed_ms: wind_speed,
+            vertical_kn: vertical_kg * G / 1000.0,
+            horizontal_kn: tower_force + jib_force,
+            overturning_moment_knm: static_moment_kgm.abs() * G / 1000.0 + wind_moment,
+            slewing_torque_knm,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::test_spec;
+
+    fn tower_config() -> CraneConfiguration {
+        let mut config = CraneConfiguration::new(test_spec("liebherr_280_ec_h_12"));
+        config.counterweight.preset_max().unwrap();
+        config
+    }
+
+    #[test]
+    fn test_trolley_radius_and_hook_height() {
+        let mut config = tower_config();
+        config.set_trolley_radius(40.0).unwrap();
+        config.hoist_length_m = 20.0;
+
+        assert!((config.get_radius() - 40.0).abs() < 1e-4);
+        let hook = config.get_hook_position();
+        assert!((hook.z - (config.spec.boom_pivot_height_m - 20.0)).abs() < 1e-4);
+        assert!(config.set_trolley_radius(80.0).is_err());
+    }
+
+    #[test]
+    fn test_tip_load_capacity() {
+        let mut config = tower_config();
+        let tip_radius = config.spec.boom_length_range.1;
+        config.set_trolley_radius(tip_radius).unwrap();
+
+        let capacity = config.get_current_capacity().unwrap();
+        let tip_load = config.spec.tower.as_ref().unwrap().tip_load_kg;
+        assert!((capacity - tip_load).abs() < 1.0);
+
+        // A chart rating the tip above the tip load is capped at it
+        for chart in config.spec.capacity_chart.charts.values_mut() {
+            for point in &mut chart.points {
+                point.capacity_kg *= 1.5;
+            }
+        }
+        let capacity = config.get_current_capacity().unwrap();
+        assert!((capacity - tip_load).abs() < 1.0);
+        config.set_trolley_radius(tip_radius - 10.0).unwrap();
+        assert!(config.get_current_capacity().unwrap() > tip_load * 1.2);
+    }
+
+    #[test]
+    fn test_luffing_jib_radius_and_capacity() {
+        let mut spec = test_spec("liebherr_280_ec_h_12");
+        spec.tower.as_mut().unwrap().jib_type = TowerJibType::Luffing;
+        spec.min_boom_angle_deg = 15.0;
+        spec.max_boom_angle_deg = 85.0;
+
+        let mut config = CraneConfiguration::new(spec);
+        config.counterweight.preset_max().unwrap();
+        assert!(config.trolley_radius_m.is_none());
+        assert!(config.set_trolley_radius(30.0).is_err());
+
+        // Radius comes from the jib angle, the hook hangs from the jib tip
+        config.boom_angle_deg = 60.0;
+        config.hoist_length_m = 20.0;
+        assert!((config.get_radius() - 35.0).abs() < 1e-3);
+        let tip_z = config.spec.boom_pivot_height_m + 70.0 * 60.0_f32.to_radians().sin();
+        assert!((config.get_hook_position().z - (tip_z - 20.0)).abs() < 1e-3);
+
+        // Radius chart: 7400 kg at 30 m, 5200 kg at 40 m
+        let capacity = config.get_current_capacity().unwrap();
+        assert!((capacity - 6_300.0).abs() < 1.0);
+
+        // Luffed right down, the hook is at the tip and gets the tip load
+        config.boom_angle_deg = 15.0;
+        let tip_load = config.spec.tower.as_ref().unwrap().tip_load_kg;
+        let chart = config
+            .spec
+            .capacity_chart
+            .get_capacity(70.0, config.get_radius(), 0.0, 1.0, false)
+            .unwrap();
+        assert!(chart > tip_load);
+        assert!((config.get_current_capacity().unwrap() - tip_load).abs() < 1.0);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_chart_below_tip_load_is_rejected() {
+        let mut spec = test_spec("liebherr_280_ec_h_12");
+        spec.tower.as_mut().unwrap().tip_load_kg = 3_500.0;
+
+        let diagnostics = crate::crane_data::ChartValidator::default().validate_spec(&spec);
+        assert!(diagnostics.iter().any(|d| d.kind
+            == crate::crane_data::DiagnosticKind::BelowTipLoad
+            && d.severity == crate::crane_data::Severity::Error));
+    }
+
+    #[test]
+    fn test_foundation_reactions() {
+        let mut config = tower_config();
+        config.set_trolley_radius(30.0).unwrap();
+
+        let in_service =
+            TowerFoundationCalculator::reactions(&config, 6_000.0, WindCondition::InService)
+                .unwrap();
+        let out_of_service =
+            TowerFoundationCalculator::reactions(&config, 6_000.0, WindCondition::OutOfService)
+                .unwrap();
+
+        // Load only counts in service
+        assert!(
+            (in_service.vertical_kn - out_of_service.vertical_kn - 6_000.0 * G / 1000.0).abs()
+                < 0.1
+        );
+        assert!(in_service.slewing_torque_knm > 0.0);
+        assert_eq!(out_of_service.slewing_torque_knm, 0.0);
+        assert!(out_of_service.horizontal_kn > 0.0);
+    }
+}
@@ -0,0 +1,503 @@
+// crates/crane_core/src/crane_data/units.rs
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// Exact definitions (international yard and pound, 1959)
+const FOOT_M: f64 = 0.3048;
+const INCH_M: f64 = 0.0254;
+const POUND_KG: f64 = 0.453_592_37;
+const POUND_FORCE_KN: f64 = 4.448_221_615_260_5e-3;
+
+/// Measurement system for importing and displaying values
+///
+/// Everything is stored in metric (`_m`, `_kg`, `_kpa`); other systems
+/// only exist at the edges.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnitSystem {
+    #[default]
+    Metric,
+    /// US customary: feet, pounds, psf
+    Imperial,
+}
+
+/// Kind of physical quantity a value measures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Quantity {
+    Length,
+    Area,
+    Mass,
+    Force,
+    Pressure,
+}
+
+/// A unit a value can be read or shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Unit {
+    Metre,
+    Foot,
+    SquareMetre,
+    SquareFoot,
+    Kilogram,
+    Tonne,
+    Pound,
+    /// 1000 lb
+    Kip,
+    Kilonewton,
+    /// 1000 lbf
+    KipForce,
+    Kilopascal,
+    PoundsPerSquareFoot,
+    PoundsPerSquareInch,
+}
+
+impl UnitSystem {
+    pub fn all() -> [UnitSystem; 2] {
+        [UnitSystem::Metric, UnitSystem::Imperial]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "Metric",
+            UnitSystem::Imperial => "Imperial",
+        }
+    }
+
+    /// Unit this system shows a quantity in
+    pub fn unit(&self, quantity: Quantity) -> Unit {
+        match (self, quantity) {
+            (UnitSystem::Metric, quantity) => Unit::metric(quantity),
+            (UnitSystem::Imperial, Quantity::Length) => Unit::Foot,
+            (UnitSystem::Imperial, Quantity::Area) => Unit::SquareFoot,
+            (UnitSystem::Imperial, Quantity::Mass) => Unit::Pound,
+            (UnitSystem::Imperial, Quantity::Force) => Unit::KipForce,
+            (UnitSystem::Imperial, Quantity::Pressure) => Unit::PoundsPerSquareFoot,
+        }
+    }
+
+    /// Metric value converted for display
+    pub fn to_display(&self, quantity: Quantity, metric: f32) -> f64 {
+        self.unit(quantity).from_metric(metric)
+    }
+
+    /// Displayed value converted back to metric
+    ///
+    /// Inverse of [`UnitSystem::to_display`]: a metric value shown and
+    /// read back comes out bit-for-bit unchanged.
+    pub fn from_display(&self, quantity: Quantity, value: f64) -> f32 {
+        self.unit(quantity).to_metric(value)
+    }
+
+    /// Metric value formatted in this system, e.g. `12.5m` or `41.0ft`
+    pub fn format(&self, quantity: Quantity, metric: f32, decimals: usize) -> String {
+        let unit = self.unit(quantity);
+        format!("{:.*}{}", decimals, unit.from_metric(metric), unit.symbol())
+    }
+
+    pub fn length(&self, metres: f32) -> String {
+        self.format(Quantity::Length, metres, 1)
+    }
+
+    pub fn mass(&self, kg: f32) -> String {
+        self.format(Quantity::Mass, kg, 0)
+    }
+
+    pub fn pressure(&self, kpa: f32) -> String {
+        self.format(Quantity::Pressure, kpa, 1)
+    }
+}
+
+impl Unit {
+    /// The metric unit values are stored in
+    pub fn metric(quantity: Quantity) -> Unit {
+        match quantity {
+            Quantity::Length => Unit::Metre,
+            Quantity::Area => Unit::SquareMetre,
+            Quantity::Mass => Unit::Kilogram,
+            Quantity::Force => Unit::Kilonewton,
+            Quantity::Pressure => Unit::Kilopascal,
+        }
+    }
+
+    pub fn quantity(&self) -> Quantity {
+        match self {
+            Unit::Metre | Unit::Foot => Quantity::Length,
+            Unit::SquareMetre | Unit::SquareFoot => Quantity::Area,
+            Unit::Kilogram | Unit::Tonne | Unit::Pound | Unit::Kip => Quantity::Mass,
+            Unit::Kilonewton | Unit::KipForce => Quantity::Force,
+            Unit::Kilopascal | Unit::PoundsPerSquareFoot | Unit::PoundsPerSquareInch => {
+                Quantity::Pressure
+            }
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::Metre => "m",
+            Unit::Foot => "ft",
+            Unit::SquareMetre => "m²",
+            Unit::SquareFoot => "ft²",
+            Unit::Kilogram => "kg",
+            Unit::Tonne => "t",
+            Unit::Pound => "lb",
+            Unit::Kip => "kip",
+            Unit::Kilonewton => "kN",
+            Unit::KipForce => "kip",
+            Unit::Kilopascal => "kPa",
+            Unit::PoundsPerSquareFoot => "psf",
+            Unit::PoundsPerSquareInch => "psi",
+        }
+    }
+
+    /// Unit named by a symbol or word (`ft`, `lbs`, `tonnes`, `kPa`...)
+    ///
+    /// `kip` reads as mass, the way load charts print it.
+    pub fn parse(text: &str) -> Option<Unit> {
+        Some(match text.trim().to_lowercase().as_str() {
+            "m" | "metre" | "metres" | "meter" | "meters" => Unit::Metre,
+            "ft" | "feet" | "foot" | "'" => Unit::Foot,
+            "m2" | "m²" | "sqm" => Unit::SquareMetre,
+            "ft2" | "ft²" | "sqft" => Unit::SquareFoot,
+            "kg" | "kgs" => Unit::Kilogram,
+            "t" | "tonne" | "tonnes" => Unit::Tonne,
+            "lb" | "lbs" | "pound" | "pounds" => Unit::Pound,
+            "kip" | "kips" => Unit::Kip,
+            "kn" => Unit::Kilonewton,
+            "kpa" => Unit::Kilopascal,
+            "psf" => Unit::PoundsPerSquareFoot,
+            "psi" => Unit::PoundsPerSquareInch,
+            _ => return None,
+        })
+    }
+
+    /// Size of one unit in the metric unit of its quantity
+    pub fn factor(&self) -> f64 {
+        match self {
+            Unit::Metre
+            | Unit::SquareMetre
+            | Unit::Kilogram
+            | Unit::Kilonewton
+            | Unit::Kilopascal => 1.0,
+            Unit::Foot => FOOT_M,
+            Unit::SquareFoot => FOOT_M * FOOT_M,
+            Unit::Tonne => 1000.0,
+            Unit::Pound => POUND_KG,
+            Unit::Kip => 1000.0 * POUND_KG,
+            Unit::KipForce => 1000.0 * POUND_FORCE_KN,
+            Unit::PoundsPerSquareFoot => POUND_FORCE_KN / (FOOT_M * FOOT_M),
+            Unit::PoundsPerSquareInch => POUND_FORCE_KN / (INCH_M * INCH_M),
+        }
+    }
+
+    /// A value in this unit, as stored (metric)
+    ///
+    /// Conversions run in f64 and round once, so metric values survive
+    /// a trip through any unit unchanged.
+    pub fn to_metric(&self, value: f64) -> f32 {
+        (value * self.factor()) as f32
+    }
+
+    /// A stored (metric) value in this unit
+    pub fn from_metric(&self, metric: f32) -> f64 {
+        metric as f64 / self.factor()
+    }
+
+    /// Number text in this unit, as stored (metric)
+    pub fn parse_value(&self, text: &str) -> Option<f32> {
+        if self.factor() == 1.0 {
+            // Metric text keeps its own f32 rounding
+            text.trim().parse().ok()
+        } else {
+            text.trim().parse::<f64>().ok().map(|v| self.to_metric(v))
+        }
+    }
+}
+
+/// Units a load chart's lengths and capacities are printed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChartUnits {
+    pub length: Unit,
+    pub mass: Unit,
+}
+
+impl Default for ChartUnits {
+    fn default() -> Self {
+        Self::metric()
+    }
+}
+
+impl ChartUnits {
+    pub fn metric() -> Self {
+        Self {
+            length: Unit::Metre,
+            mass: Unit::Kilogram,
+        }
+    }
+
+    /// Feet and pounds
+    pub fn imperial() -> Self {
+        Self {
+            length: Unit::Foot,
+            mass: Unit::Pound,
+        }
+    }
+
+    /// Units named in a header or title, metres and kilograms otherwise
+    ///
+    /// Reads `ft`, `lb`, `kip` and `tonne` words and bracketed symbols
+    /// such as `(t)` or `[lbs]`. A bare `t` is too common to count.
+    pub fn detect(text: &str) -> Self {
+        let mut units = Self::metric();
+        let lower = text.to_lowercase();
+        for word in lower.split(|c: char| !c.is_alphabetic()) {
+            let bracketed = ["(", "["]
+                .iter()
+                .zip([")", "]"])
+                .any(|(open, close)| lower.contains(&format!("{}{}{}", open, word, close)));
+            match Unit::parse(word) {
+                Some(Unit::Tonne) if word == "t" && !bracketed => {}
+                Some(unit) if unit.quantity() == Quantity::Length => units.length = unit,
+                Some(unit) if unit.quantity() == Quantity::Mass => units.mass = unit,
+                _ => {}
+            }
+        }
+        units
+    }
+
+    pub fn is_metric(&self) -> bool {
+        self.length == Unit::Metre && self.mass == Unit::Kilogram
+    }
+
+    /// `ft, lb` style label for provenance records
+    pub fn describe(&self) -> String {
+        format!("{}, {}", self.mass.symbol(), self.length.symbol())
+    }
+}
+
+/// Imperial field suffixes in data files and their stored (metric) names
+const FIELD_SUFFIXES: [(&str, &str, Unit); 7] = [
+    ("_ft_per_min", "_m_per_min", Unit::Foot),
+    ("_ft_per_s", "_m_per_s", Unit::Foot),
+    ("_ft2", "_m2", Unit::SquareFoot),
+    ("_ft", "_m", Unit::Foot),
+    ("_lb", "_kg", Unit::Pound),
+    ("_kip", "_kn", Unit::KipForce),
+    ("_psf", "_kpa", Unit::PoundsPerSquareFoot),
+];
+
+/// Length fields stored without a unit suffix
+const UNSUFFIXED_LENGTHS: [&str; 2] = ["boom_length_range", "hoist_length_range"];
+
+/// Stored field name and unit for an imperial field (`radius_ft`)
+fn metric_field(key: &str) -> Option<(String, Unit)> {
+    FIELD_SUFFIXES.iter().find_map(|(imperial, metric, unit)| {
+        let stem = key.strip_suffix(imperial)?;
+        if *unit == Unit::Foot && UNSUFFIXED_LENGTHS.contains(&stem) {
+            return Some((stem.to_string(), *unit));
+        }
+        Some((format!("{}{}", stem, metric), *unit))
+    })
+}
+
+/// Imperial field name and unit for a stored field (`radius_m`)
+fn imperial_field(key: &str) -> Option<(String, Unit)> {
+    if UNSUFFIXED_LENGTHS.contains(&key) {
+        return Some((format!("{}_ft", key), Unit::Foot));
+    }
+    FIELD_SUFFIXES.iter().find_map(|(imperial, metric, unit)| {
+        let stem = key.strip_suffix(metric)?;
+        Some((format!("{}{}", stem, imperial), *unit))
+    })
+}
+
+/// Whether a data file uses imperial field names anywhere
+pub fn has_imperial_fields(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .any(|(key, value)| metric_field(key).is_some() || has_imperial_fields(value)),
+        Value::Array(items) => items.iter().any(has_imperial_fields),
+        _ => false,
+    }
+}
+
+/// Rename imperial fields (`radius_ft`, `capacity_lb`) to their stored
+/// names, converting the values to metric
+pub fn fields_to_metric(value: &mut Value) {
+    rename_fields(value, true);
+}
+
+/// Rename stored fields to imperial, converting the values
+pub fn fields_to_imperial(value: &mut Value) {
+    rename_fields(value, false);
+}
+
+fn rename_fields(value: &mut Value, to_metric: bool) {
+    match value {
+        Value::Object(map) => {
+            for (key, mut value) in std::mem::take(map) {
+                let renamed = if to_metric {
+                    metric_field(&key)
+                } else {
+                    imperial_field(&key)
+                };
+                match renamed {
+                    Some((name, unit)) => {
+                        convert_numbers(&mut value, unit, to_metric);
+                        map.insert(name, value);
+                    }
+                    None => {
+                        rename_fields(&mut value, to_metric);
+                        map.insert(key, value);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rename_fields(item, to_metric);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn convert_numbers(value: &mut Value, unit: Unit, to_metric: bool) {
+    match value {
+        Value::Number(number) => {
+            let converted = number.as_f64().and_then(|v| {
+                let v = if to_metric {
+                    unit.to_metric(v) as f64
+                } else {
+                    unit.from_metric(v as f32)
+                };
+                serde_json::Number::from_f64(v)
+            });
+            if let Some(converted) = converted {
+                *number = converted;
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                convert_numbers(item, unit, to_metric);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_is_exact() {
+        let mut value = 0.013_f32;
+        while value < 500_000.0 {
+            for system in UnitSystem::all() {
+                for quantity in [Quantity::Length, Quantity::Mass, Quantity::Pressure] {
+                    let shown = system.to_display(quantity, value);
+                    assert_eq!(system.from_display(quantity, shown), value);
+                }
+            }
+            for unit in [Unit::Tonne, Unit::Kip, Unit::PoundsPerSquareInch] {
+                assert_eq!(unit.to_metric(unit.from_metric(value)), value);
+            }
+            value *= 1.37;
+        }
+
+        // Whole imperial values read back whole
+        let metres = Unit::Foot.to_metric(100.0);
+        assert_eq!(metres, 30.48);
+        assert_eq!(Unit::Foot.from_metric(metres) as f32, 100.0);
+        assert_eq!(Unit::Pound.parse_value("2204.62262"), Some(1000.0));
+        assert!((Unit::PoundsPerSquareInch.factor() - 6.894_757).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_format_and_detect() {
+        assert_eq!(UnitSystem::Metric.length(12.0), "12.0m");
+        assert_eq!(UnitSystem::Imperial.length(30.48), "100.0ft");
+        assert_eq!(UnitSystem::Imperial.mass(453.59237), "1000lb");
+        assert_eq!(UnitSystem::Imperial.pressure(47.880_26), "1000.0psf");
+
+        assert_eq!(
+            ChartUnits::detect("boom_length_ft,radius_ft,capacity_lb"),
+            ChartUnits::imperial()
+        );
+        let units = ChartUnits::detect("Radius (ft) / Boom (ft) (kips)");
+        assert_eq!(units.mass, Unit::Kip);
+        assert_eq!(
+            ChartUnits::detect("Radius (m) / Boom (m) (t)").mass,
+            Unit::Tonne
+        );
+        assert!(ChartUnits::detect("boom_length,radius,capacity").is_metric());
+        assert!(ChartUnits::detect("With 22 t counterweight").is_metric());
+    }
+
+    #[test]
+    fn test_error_in_units() {
+        let error = crate::crane_data::CraneConfigError::LoadExceedsCapacity {
+            load_kg: 10_000.0,
+            capacity_kg: 9_071.847,
+            radius_m: 12.192,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Load 10000kg exceeds capacity 9072kg at 12.2m radius"
+        );
+        assert_eq!(
+            error.describe(UnitSystem::Imperial),
+            "Load 22046lb exceeds capacity 20000lb at 40.0ft radius"
+        );
+    }
+
+    #[test]
+    fn test_spec_imperial_round_trip() {
+        for spec in crate::crane_data::test_library().specs() {
+            let imperial = spec.to_json(UnitSystem::Imperial).unwrap();
+            assert!(imperial.contains("\"max_capacity_lb\""));
+            assert!(!imperial.contains("\"max_capacity_kg\""));
+
+            let reread = crate::crane_data::CraneSpec::from_json(&imperial).unwrap();
+            assert_eq!(
+                reread.to_json(UnitSystem::Metric).unwrap(),
+                spec.to_json(UnitSystem::Metric).unwrap(),
+                "{} drifted through imperial",
+                spec.id
+            );
+            assert!(reread.capacity_chart.verify_integrity());
+        }
+    }
+
+    #[test]
+    fn test_field_conversion() {
+        let mut value: Value = serde_json::from_str(
+            r#"{"boom_length_range_ft": [40, 160], "points": [{"radius_ft": 10, "capacity_lb": 2000}],
+                "max_boom_angle_deg": 82}"#,
+        )
+        .unwrap();
+        assert!(has_imperial_fields(&value));
+        let original = value.clone();
+
+        fields_to_metric(&mut value);
+        assert!(!has_imperial_fields(&value));
+        assert_eq!(
+            value["boom_length_range"][1].as_f64().unwrap() as f32,
+            48.768
+        );
+        assert_eq!(
+            value["points"][0]["radius_m"].as_f64().unwrap() as f32,
+            3.048
+        );
+        assert_eq!(value["max_boom_angle_deg"].as_f64(), Some(82.0));
+
+        fields_to_imperial(&mut value);
+        let radius = value["points"][0]["radius_ft"].as_f64().unwrap();
+        assert!((radius - 10.0).abs() < 1e-6);
+        assert_eq!(
+            value.as_object().unwrap().keys().collect::<Vec<_>>(),
+            original.as_object().unwrap().keys().collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use core::f32;
 
+use crate::crane_data::CraneConfiguration;
 use nalgebra::Point3;
 use serde::{Deserialize, Serialize};
 
@@ -399,3 +400,120 @@ impl GroundBearingCalculator {
         })
     }
 }
+
+impl GroundBearingCalculator {
+    /// Outrigger support points (world positions) for the current slew and hook load
+    ///
+    /// Rigid-chassis distribution: the total weight and overturning moment are
+    /// shared over the deployed outriggers in proportion to their distance from
+    /// the support centroid. Outriggers that would go into tension carry nothing.
+    pub fn outrigger_support_points(
+        config: &CraneConfiguration,
+        hook_load_kg: f32,
+        pad_diameter_m: f32,
+        pad_material: PadMaterial,
+    ) -> Result<Vec<SupportPoint>, String> {
+        let contacts = config.outriggers.get_all_contact_points();
+        if contacts.len() < 3 {
+            return Err("Fewer than three outriggers deployed".to_string());
+        }
+
+        // Crane local frame: x to the right, y forward, slew from forward
+        let swing_rad = config.swing_angle_deg.to_radians();
+        let slew_dir = (swing_rad.sin(), swing_rad.cos());
+        let radius = config.get_loaded_radius(hook_load_kg);
+
+        let base_kg = config.spec.base_weight_kg;
+        let counterweight_kg = config.counterweight.get_total_weight_kg();
+        let counterweight_arm = -config.counterweight.moment_arm_m;
+        let total_kg = base_kg + counterweight_kg + hook_load_kg;
+
+        // Resultant vertical load position (base weight acts at the slew centre)
+        let cx =
+            (hook_load_kg * radius + counterweight_kg * counterweight_arm) * slew_dir.0 / total_kg;
+        let cy =
+            (hook_load_kg * radius + counterweight_kg * counterweight_arm) * slew_dir.1 / total_kg;
+
+        let n = contacts.len() as f32;
+        let centroid_x = contacts.iter().map(|(_, p)| p.x).sum::<f32>() / n;
+        let centroid_y = contacts.iter().map(|(_, p)| p.y).sum::<f32>() / n;
+
+        let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+        for (_, p) in &contacts {
+            let (dx, dy) = (p.x - centroid_x, p.y - centroid_y);
+            sxx += dx * dx;
+            syy += dy * dy;
+            sxy += dx * dy;
+        }
+        let det = sxx * syy - sxy * sxy;
+        if det.abs() < f32::EPSILON {
+            return Err("Outrigger contact points are collinear".to_string());
+        }
+
+        // Moment about the centroid, solved for the linear reaction field
+        let mx = total_kg * (cx - centroid_x);
+        let my = total_kg * (cy - centroid_y);
+        let a = (mx * syy - my * sxy) / det;
+        let b = (my * sxx - mx * sxy) / det;
+
+        let heading_rad = config.heading_deg.to_radians();
+        let (sin_h, cos_h) = heading_rad.sin_cos();
+
+        Ok(contacts
+            .iter()
+            .map(|(_, p)| {
+                let load_kg =
+                    (total_kg / n + a * (p.x - centroid_x) + b * (p.y - centroid_y)).max(0.0);
+                let world = Point3::new(
+                    config.position.x + p.x * cos_h + p.y * sin_h,
+                    config.position.y - p.x * sin_h + p.y * cos_h,
+                    config.position.z + p.z,
+                );
+                SupportPoint::with_pad(world, load_kg, pad_diameter_m, pad_material)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::test_crane;
+
+    #[test]
+    fn test_outrigger_loads_follow_slew() {
+        let mut config = test_crane(30.0, 60.0);
+
+        let supports = GroundBearingCalculator::outrigger_support_points(
+            &config,
+            10_000.0,
+            0.6,
+            PadMaterial::Steel,
+        )
+        .unwrap();
+        let total: f32 = supports.iter().map(|s| s.load_kg).sum();
+        let expected = config.get_total_weight_kg() + 10_000.0;
+        assert!((total - expected).abs() / expected < 1e-3);
+
+        // A heavy load swung over the right side loads the right-hand outriggers
+        config.swing_angle_deg = 90.0;
+        let supports = GroundBearingCalculator::outrigger_support_points(
+            &config,
+            30_000.0,
+            0.6,
+            PadMaterial::Steel,
+        )
+        .unwrap();
+        let right: f32 = supports
+            .iter()
+            .filter(|s| s.position.x > 0.0)
+            .map(|s| s.load_kg)
+            .sum();
+        let left: f32 = supports
+            .iter()
+            .filter(|s| s.position.x < 0.0)
+            .map(|s| s.load_kg)
+            .sum();
+        assert!(right > left);
+    }
+}
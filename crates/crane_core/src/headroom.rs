@@ -0,0 +1,359 @@
+// crates/crane_core/src/headroom.rs
+
+use nalgebra::{Point2, Point3, Vector3};
+use serde::{Deserialize, Serialize};
+
+use crate::crane_data::CraneConfiguration;
+use crate::kinematics::calculate_boom_tip_position;
+use crate::rigging::{Load, RiggingConfiguration, Sling};
+
+/// Clearance requirements for a headroom check
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct HeadroomLimits {
+    /// Height of the hook block above the hook saddle
+    pub hook_block_height_m: f32,
+    /// Minimum distance between hook block and boom tip sheaves
+    pub min_two_block_margin_m: f32,
+    /// Minimum clearance between load/rigging and the boom centreline
+    /// (include half the boom section depth here)
+    pub min_boom_clearance_m: f32,
+    /// Minimum clearance between the bottom of the load and the ground
+    pub min_ground_clearance_m: f32,
+}
+
+impl Default for HeadroomLimits {
+    fn default() -> Self {
+        Self {
+            hook_block_height_m: 1.5,
+            min_two_block_margin_m: 1.0,
+            min_boom_clearance_m: 1.0,
+            min_ground_clearance_m: 0.0,
+        }
+    }
+}
+
+/// Result of a headroom check
+#[derive(Debug, Clone)]
+pub struct HeadroomAnalysis {
+    /// Elevation of the tip the hoist line runs over (jib tip when rigged)
+    pub boom_tip_elevation_m: f32,
+    pub hook_elevation_m: f32,
+    /// Vertical distance from the hook to the pick points
+    pub rigging_height_m: f32,
+    pub top_of_load_elevation_m: f32,
+    pub bottom_of_load_elevation_m: f32,
+    /// Distance from the top of the hook block to the tip sheave
+    pub two_block_margin_m: f32,
+    /// Minimum distance from the hanging load and rigging to the boom centreline
+    /// (negative when the envelope intersects the boom)
+    pub boom_clearance_m: f32,
+    /// Bottom of load above the set-down surface
+    pub ground_clearance_m: f32,
+    /// Whether the load can be landed on the set-down surface with the
+    /// maximum hoist length
+    pub set_down_reachable: bool,
+    pub is_safe: bool,
+    pub warnings: Vec<String>,
+}
+
+pub struct HeadroomCalculator;
+
+impl HeadroomCalculator {
+    /// Vertical drop from hook to load given sling lengths and leg spread
+    pub fn rigging_height_from_slings(slings: &[Sling]) -> f32 {
+        slings
+            .iter()
+            .map(|s| {
+                let horizontal = (s.attachment_point.xy() - s.hook_point.xy()).norm();
+                (s.spec.length_m.powi(2) - horizontal.powi(2))
+                    .max(0.0)
+                    .sqrt()
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// Check headroom for a load hanging `rigging_height_m` below the hook
+    ///
+    /// `rigging_height_m` is the hook-to-pick-point height, e.g. from
+    /// `RiggingAssembly::rigging_height_m` or `rigging_height_from_slings`.
+    /// `set_down_elevation_m` is the elevation of the landing surface.
+    pub fn analyze(
+        config: &CraneConfiguration,
+        load: &Load,
+        rigging_height_m: f32,
+        set_down_elevation_m: f32,
+        limits: &HeadroomLimits,
+    ) -> HeadroomAnalysis {
+        let pick_height = load
+            .pick_points
+            .iter()
+            .filter(|p| p.active)
+            .map(|p| p.position.z)
+            .reduce(f32::max)
+            .unwrap_or(load.dimensions.z);
+
+        Self::analyze_hanging(
+            config,
+            load.dimensions,
+            rigging_height_m,
+            pick_height,
+            set_down_elevation_m,
+            limits,
+        )
+    }
+
+    /// Check headroom using the slings of a rigging configuration
+    pub fn analyze_rigging(
+        config: &CraneConfiguration,
+        rigging: &RiggingConfiguration,
+        set_down_elevation_m: f32,
+        limits: &HeadroomLimits,
+    ) -> HeadroomAnalysis {
+        let pick_height = rigging
+            .slings
+            .iter()
+            .map(|s| s.attachment_point.z)
+            .reduce(f32::max)
+            .unwrap_or(rigging.load.dimensions.z);
+
+        Self::analyze_hanging(
+            config,
+            rigging.load.dimensions,
+            Self::rigging_height_from_slings(&rigging.slings),
+            pick_height,
+            set_down_elevation_m,
+            limits,
+        )
+    }
+
+    fn analyze_hanging(
+        config: &CraneConfiguration,
+        load_dimensions: Vector3<f32>,
+        rigging_height_m: f32,
+        pick_height_m: f32,
+        set_down_elevation_m: f32,
+        limits: &HeadroomLimits,
+    ) -> HeadroomAnalysis {
+        let tip = config.get_sheave_position();
+        let hook = config.get_hook_position();
+
+        let bottom = hook.z - rigging_height_m - pick_height_m;
+        let top = bottom + load_dimensions.z;
+
+        let two_block_margin = config.hoist_length_m - limits.hook_block_height_m;
+        let boom_clearance = Self::boom_clearance(config, hook, load_dimensions, top, bottom);
+        let ground_clearance = bottom - set_down_elevation_m;
+
+        let lowest_bottom =
+            tip.z - config.spec.hoist_length_range.1 - rigging_height_m - pick_height_m;
+        let set_down_reachable = lowest_bottom <= set_down_elevation_m;
+
+        let mut warnings = Vec::new();
+
+        if two_block_margin < limits.min_two_block_margin_m {
+            warnings.push(format!(
+                "Two-block margin {:.2}m is below the required {:.2}m",
+                two_block_margin, limits.min_two_block_margin_m
+            ));
+        }
+
+        if boom_clearance < limits.min_boom_clearance_m {
+            warnings.push(format!(
+                "Load/rigging clearance to boom {:.2}m is below the required {:.2}m",
+                boom_clearance, limits.min_boom_clearance_m
+            ));
+        }
+
+        if ground_clearance < limits.min_ground_clearance_m {
+            warnings.push(format!(
+                "Bottom of load clearance to set-down surface {:.2}m is below the required {:.2}m",
+                ground_clearance, limits.min_ground_clearance_m
+            ));
+        }
+
+        if !set_down_reachable {
+            warnings.push(format!(
+                "Load cannot be landed: lowest bottom of load is {:.2}m above set-down surface",
+                lowest_bottom - set_down_elevation_m
+            ));
+        }
+
+        HeadroomAnalysis {
+            boom_tip_elevation_m: tip.z,
+            hook_elevation_m: hook.z,
+            rigging_height_m,
+            top_of_load_elevation_m: top,
+            bottom_of_load_elevation_m: bottom,
+            two_block_margin_m: two_block_margin,
+            boom_clearance_m: boom_clearance,
+            ground_clearance_m: ground_clearance,
+            set_down_reachable,
+            is_safe: warnings.is_empty(),
+            warnings,
+        }
+    }
+
+    /// Minimum distance from the boom (and jib) centreline to the hanging envelope
+    ///
+    /// The load may rotate about the hoist line, so the envelope is a solid of
+    /// revolution about the hook: a cylinder enclosing the load footprint with
+    /// a cone of rigging tapering up to the hook.
+    fn boom_clearance(
+        config: &CraneConfiguration,
+        hook: Point3<f32>,
+        load_dimensions: Vector3<f32>,
+        top: f32,
+        bottom: f32,
+    ) -> f32 {
+        const SAMPLES: usize = 64;
+
+        let pivot = calculate_boom_tip_position(
+            config.position,
+            0.0,
+            config.boom_angle_deg,
+            config.swing_angle_deg + config.heading_deg,
+            config.spec.boom_pivot_height_m,
+        );
+        let mut centreline = vec![pivot, config.get_boom_tip_position()];
+        centreline.extend(config.get_jib_tip_position());
+
+        let radius = (load_dimensions.x.powi(2) + load_dimensions.y.powi(2)).sqrt() / 2.0;
+        let profile = [
+            Point2::new(0.0, hook.z),
+            Point2::new(radius, top),
+            Point2::new(radius, bottom),
+            Point2::new(0.0, bottom),
+        ];
+
+        centreline
+            .windows(2)
+            .flat_map(|segment| {
+                (0..=SAMPLES)
+                    .map(|i| segment[0] + (segment[1] - segment[0]) * (i as f32 / SAMPLES as f32))
+            })
+            .map(|p| {
+                let r = (p.xy() - hook.xy()).norm();
+                signed_distance_to_profile(Point2::new(r, p.z), &profile)
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+}
+
+/// Signed distance from a point to a convex (r, z) profile, negative inside
+fn signed_distance_to_profile(p: Point2<f32>, profile: &[Point2<f32>]) -> f32 {
+    let mut distance = f32::INFINITY;
+    let mut inside = true;
+
+    for i in 0..profile.len() {
+        let a = profile[i];
+        let b = profile[(i + 1) % profile.len()];
+        let ab = b - a;
+        let ap = p - a;
+
+        let t = if ab.norm_squared() > 0.0 {
+            (ap.dot(&ab) / ab.norm_squared()).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        distance = distance.min((ap - ab * t).norm());
+
+        // Profile is wound clockwise in (r, z)
+        if ab.x * ap.y - ab.y * ap.x > 0.0 {
+            inside = false;
+        }
+    }
+
+    if inside { -distance } else { distance }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{JibState, test_crane};
+
+    fn test_config(boom_angle_deg: f32, hoist_length_m: f32) -> CraneConfiguration {
+        let mut config = test_crane(40.0, boom_angle_deg);
+        config.hoist_length_m = hoist_length_m;
+        config
+    }
+
+    fn test_load() -> Load {
+        Load {
+            weight_kg: 5000.0,
+            center_of_gravity: Point3::new(0.0, 0.0, 1.0),
+            dimensions: Vector3::new(4.0, 2.0, 2.0),
+            pick_points: vec![],
+        }
+    }
+
+    #[test]
+    fn test_load_elevations() {
+        let config = test_config(60.0, 10.0);
+        let analysis = HeadroomCalculator::analyze(
+            &config,
+            &test_load(),
+            3.0,
+            0.0,
+            &HeadroomLimits::default(),
+        );
+
+        // Pick points default to the top of the load
+        let hook_z = config.get_hook_height();
+        assert!((analysis.bottom_of_load_elevation_m - (hook_z - 5.0)).abs() < 1e-3);
+        assert!((analysis.top_of_load_elevation_m - (hook_z - 3.0)).abs() < 1e-3);
+        assert!((analysis.two_block_margin_m - 8.5).abs() < 1e-3);
+        assert!(analysis.boom_clearance_m > 1.0);
+        assert!(analysis.set_down_reachable);
+    }
+
+    #[test]
+    fn test_load_swings_into_boom_at_high_angle() {
+        let config = test_config(85.0, 4.0);
+        let mut load = test_load();
+        load.dimensions = Vector3::new(12.0, 2.0, 2.0);
+
+        let analysis =
+            HeadroomCalculator::analyze(&config, &load, 2.0, 0.0, &HeadroomLimits::default());
+        assert!(analysis.boom_clearance_m < 0.0);
+        assert!(!analysis.is_safe);
+    }
+
+    #[test]
+    fn test_jib_rigged_headroom() {
+        let mut config = test_config(60.0, 4.0);
+        config.boom_length_m = 50.0;
+        config
+            .set_jib(Some(JibState {
+                jib_id: "ltm_1100_fly_jib".to_string(),
+                length_m: 10.5,
+                offset_deg: 0.0,
+            }))
+            .unwrap();
+        let mut load = test_load();
+        load.dimensions = Vector3::new(12.0, 2.0, 2.0);
+
+        let analysis =
+            HeadroomCalculator::analyze(&config, &load, 2.0, 0.0, &HeadroomLimits::default());
+
+        // Measured from the jib tip the hook hangs from, and the jib is in the way
+        let jib_tip = config.get_jib_tip_position().unwrap();
+        assert!((analysis.boom_tip_elevation_m - jib_tip.z).abs() < 1e-4);
+        assert!((analysis.hook_elevation_m - (jib_tip.z - 4.0)).abs() < 1e-4);
+        assert!(analysis.boom_clearance_m < 0.0);
+        assert!(!analysis.is_safe);
+    }
+
+    #[test]
+    fn test_two_block_margin() {
+        let config = test_config(60.0, 1.8);
+        let analysis = HeadroomCalculator::analyze(
+            &config,
+            &test_load(),
+            3.0,
+            0.0,
+            &HeadroomLimits::default(),
+        );
+        assert!(analysis.two_block_margin_m < 1.0);
+        assert!(analysis.warnings.iter().any(|w| w.contains("Two-block")));
+    }
+}
@@ -0,0 +1,316 @@
+// crates/crane_core/src/inverse_kinematics.rs
+
+use crate::crane_data::{CraneConfiguration, TelescopeState, TowerJibType};
+use nalgebra::Point3;
+
+/// A point the hook has to reach
+#[derive(Debug, Clone, Copy)]
+pub struct LiftTarget {
+    /// Pick or set point; the hook is placed directly above it
+    pub location: Point3<f32>,
+    /// Required hook elevation (world Z)
+    pub hook_height_m: f32,
+}
+
+impl LiftTarget {
+    pub fn new(location: Point3<f32>, hook_height_m: f32) -> Self {
+        Self {
+            location,
+            hook_height_m,
+        }
+    }
+
+    /// Hook `height_m` above the point
+    pub fn above(location: Point3<f32>, height_m: f32) -> Self {
+        Self::new(location, location.z + height_m)
+    }
+}
+
+/// Crane motion axes that put the hook on one target
+#[derive(Debug, Clone, Copy)]
+pub struct HookPose {
+    pub boom_angle_deg: f32,
+    pub swing_angle_deg: f32,
+    pub hoist_length_m: f32,
+    /// Trolley radius (horizontal tower crane jibs)
+    pub trolley_radius_m: Option<f32>,
+    pub radius_m: f32,
+    /// Chart capacity at the loaded radius
+    pub capacity_kg: f32,
+    pub utilization_percent: f32,
+}
+
+/// Feasible boom setup for a pick and set
+#[derive(Debug, Clone)]
+pub struct IkSolution {
+    pub boom_length_m: f32,
+    pub telescope: Option<TelescopeState>,
+    pub pick: HookPose,
+    pub set: HookPose,
+    /// Governing (higher) utilisation of the pick and set
+    pub utilization_percent: f32,
+}
+
+impl IkSolution {
+    /// Put `config` into this setup with the hook at `pose`
+    pub fn apply(&self, config: &mut CraneConfiguration, pose: &HookPose) {
+        config.jib = None;
+        config.telescope = self.telescope.clone();
+        config.boom_length_m = self.boom_length_m;
+        config.boom_angle_deg = pose.boom_angle_deg;
+        config.swing_angle_deg = pose.swing_angle_deg;
+        config.trolley_radius_m = pose.trolley_radius_m;
+        config.hoist_length_m = pose.hoist_length_m;
+    }
+
+    /// Configuration with the hook at the pick point
+    pub fn pick_configuration(&self, base: &CraneConfiguration) -> CraneConfiguration {
+        let mut config = base.clone();
+        self.apply(&mut config, &self.pick);
+        config
+    }
+
+    /// Configuration with the hook at the set point
+    pub fn set_configuration(&self, base: &CraneConfiguration) -> CraneConfiguration {
+        let mut config = base.clone();
+        self.apply(&mut config, &self.set);
+        config
+    }
+}
+
+/// Solves boom length, telescoping mode, boom angle, swing and hoist for a lift
+pub struct IkSolver;
+
+impl IkSolver {
+    /// All feasible setups that reach both targets with `load_kg`,
+    /// lowest utilisation first
+    ///
+    /// The crane keeps the position, heading, supports and counterweight of
+    /// `config`. Boom length and telescoping stay fixed between pick and set;
+    /// the boom may luff. Only the main boom is considered (no jib).
+    pub fn solve(
+        config: &CraneConfiguration,
+        pick: &LiftTarget,
+        set: &LiftTarget,
+        load_kg: f32,
+    ) -> Vec<IkSolution> {
+        let mut solutions: Vec<IkSolution> = Self::candidate_setups(config)
+            .into_iter()
+            .filter_map(|(boom_length_m, telescope)| {
+                let mut trial = config.clone();
+                trial.jib = None;
+                trial.telescope = telescope.clone();
+                trial.boom_length_m = boom_length_m;
+
+                let pick = Self::solve_pose(&trial, pick, load_kg)?;
+                let set = Self::solve_pose(&trial, set, load_kg)?;
+
+                Some(IkSolution {
+                    boom_length_m,
+                    telescope,
+                    utilization_percent: pick.utilization_percent.max(set.utilization_percent),
+                    pick,
+                    set,
+                })
+            })
+            .collect();
+
+        solutions.sort_by(|a, b| {
+            a.utilization_percent
+                .total_cmp(&b.utilization_percent)
+                .then(a.boom_length_m.total_cmp(&b.boom_length_m))
+        });
+
+        solutions
+    }
+
+    /// Solve the hook pose for one target with the boom length already set
+    pub fn solve_pose(
+        config: &CraneConfiguration,
+        target: &LiftTarget,
+        load_kg: f32,
+    ) -> Option<HookPose> {
+        let spec = &config.spec;
+        let dx = target.location.x - config.position.x;
+        let dy = target.location.y - config.position.y;
+        let radius = (dx * dx + dy * dy).sqrt();
+
+        // 0° = forward, 90° = right, relative to the crane heading
+        let swing_angle_deg = (dx.atan2(dy).to_degrees() - config.heading_deg).rem_euclid(360.0);
+
+        let pivot_z = config.position.z + spec.boom_pivot_height_m;
+        let (boom_angle_deg, trolley_radius_m, tip_z) = match &spec.tower {
+            Some(tower) if tower.jib_type == TowerJibType::Horizontal => {
+                if radius < tower.min_trolley_radius_m || radius > config.boom_length_m {
+                    return None;
+                }
+                (0.0, Some(radius), pivot_z)
+            }
+            _ => {
+                if radius > config.boom_length_m
+                    || radius < spec.min_radius_m
+                    || radius > spec.max_radius_m
+                {
+                    return None;
+                }
+                let angle = (radius / config.boom_length_m).acos().to_degrees();
+                if angle < spec.min_boom_angle_deg || angle > spec.max_boom_angle_deg {
+                    return None;
+                }
+                (
+                    angle,
+                    None,
+                    pivot_z + config.boom_length_m * angle.to_radians().sin(),
+                )
+            }
+        };
+
+        let hoist_length_m = tip_z - target.hook_height_m;
+        if hoist_length_m < spec.hoist_length_range.0 || hoist_length_m > spec.hoist_length_range.1
+        {
+            return None;
+        }
+
+        let mut posed = config.clone();
+        posed.boom_angle_deg = boom_angle_deg;
+        posed.swing_angle_deg = swing_angle_deg;
+        posed.trolley_radius_m = trolley_radius_m;
+        posed.hoist_length_m = hoist_length_m;
+        posed.validate().ok()?;
+
+        let capacity_kg = posed.get_loaded_capacity(load_kg)?;
+        if capacity_kg <= 0.0 || load_kg > capacity_kg {
+            return None;
+        }
+
+        Some(HookPose {
+            boom_angle_deg,
+            swing_angle_deg,
+            hoist_length_m,
+            trolley_radius_m,
+            radius_m: radius,
+            capacity_kg,
+            utilization_percent: load_kg / capacity_kg * 100.0,
+        })
+    }
+
+    /// Boom lengths to try: telescoping mode steps, or the chart lengths
+    /// for a continuously variable boom
+    fn candidate_setups(config: &CraneConfiguration) -> Vec<(f32, Option<TelescopeState>)> {
+        let spec = &config.spec;
+
+        if spec.tower.is_some() {
+            return vec![(config.boom_length_m, None)];
+        }
+
+        if let Some(system) = &spec.telescoping {
+            return system
+                .modes
+                .iter()
+                .flat_map(|mode| {
+                    mode.steps.iter().map(|step| {
+                        (
+                            system.boom_length_m(step),
+                            Some(TelescopeState {
+                                mode: mode.code.clone(),
+                                section_extension_pct: step.clone(),
+                            }),
+                        )
+                    })
+                })
+                .collect();
+        }
+
+        let (min, max) = spec.boom_length_range;
+        let mut lengths: Vec<f32> = spec
+            .capacity_chart
+            .charts
+            .values()
+            .map(|c| c.boom_length_m)
+            .filter(|l| (min..=max).contains(l))
+            .chain([min, max])
+            .collect();
+        lengths.sort_by(f32::total_cmp);
+        lengths.dedup_by(|a, b| (*a - *b).abs() < 0.05);
+
+        lengths.into_iter().map(|l| (l, None)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{OutriggerPosition, test_crane, test_spec};
+
+    #[test]
+    fn test_solutions_reach_targets() {
+        let config = test_crane(30.0, 60.0);
+        let pick = LiftTarget::above(Point3::new(0.0, 12.0, 0.0), 3.0);
+        let set = LiftTarget::above(Point3::new(-14.0, 0.0, 2.0), 4.0);
+
+        let solutions = IkSolver::solve(&config, &pick, &set, 8_000.0);
+        assert!(!solutions.is_empty());
+
+        for solution in &solutions {
+            let at_pick = solution.pick_configuration(&config);
+            let hook = at_pick.get_hook_position();
+            assert!((hook.x - 0.0).abs() < 1e-3 && (hook.y - 12.0).abs() < 1e-3);
+            assert!((hook.z - 3.0).abs() < 1e-3);
+
+            let at_set = solution.set_configuration(&config);
+            let hook = at_set.get_hook_position();
+            assert!((hook.x + 14.0).abs() < 1e-3 && hook.y.abs() < 1e-3);
+            assert!((hook.z - 6.0).abs() < 1e-3);
+            assert!((at_set.swing_angle_deg - 270.0).abs() < 1e-3);
+
+            assert!(solution.utilization_percent <= 100.0);
+        }
+
+        // Ranked by utilisation
+        assert!(
+            solutions
+                .windows(2)
+                .all(|w| w[0].utilization_percent <= w[1].utilization_percent)
+        );
+    }
+
+    #[test]
+    fn test_no_solution_when_overloaded_or_out_of_reach() {
+        let config = test_crane(30.0, 60.0);
+        let pick = LiftTarget::above(Point3::new(0.0, 12.0, 0.0), 3.0);
+        let far = LiftTarget::above(Point3::new(0.0, 80.0, 0.0), 3.0);
+
+        assert!(IkSolver::solve(&config, &pick, &pick, 500_000.0).is_empty());
+        assert!(IkSolver::solve(&config, &pick, &far, 1_000.0).is_empty());
+    }
+
+    #[test]
+    fn test_no_solution_for_invalid_configuration() {
+        let mut config = test_crane(30.0, 60.0);
+        let pick = LiftTarget::above(Point3::new(0.0, 12.0, 0.0), 3.0);
+        assert!(IkSolver::solve_pose(&config, &pick, 1_000.0).is_some());
+
+        // Reachable and within the chart, but a leg is off the ground
+        config
+            .outriggers
+            .retract(OutriggerPosition::RearRight)
+            .unwrap();
+        assert!(IkSolver::solve_pose(&config, &pick, 1_000.0).is_none());
+        assert!(IkSolver::solve(&config, &pick, &pick, 1_000.0).is_empty());
+    }
+
+    #[test]
+    fn test_tower_crane_trolley_solution() {
+        let mut config = CraneConfiguration::new(test_spec("liebherr_280_ec_h_12"));
+        config.counterweight.preset_max().unwrap();
+        let pick = LiftTarget::above(Point3::new(30.0, 0.0, 0.0), 2.0);
+        let set = LiftTarget::above(Point3::new(0.0, -50.0, 20.0), 2.0);
+
+        let solutions = IkSolver::solve(&config, &pick, &set, 3_000.0);
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(solutions[0].pick.trolley_radius_m, Some(30.0));
+
+        let hook = solutions[0].set_configuration(&config).get_hook_position();
+        assert!((hook - Point3::new(0.0, -50.0, 22.0)).norm() < 1e-3);
+    }
+}
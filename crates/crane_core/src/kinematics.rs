@@ -60,6 +60,39 @@ pub fn calculate_boom_tip_position(
     Point3::new(crane_base.x + x, crane_base.y + y, crane_base.z + z)
 }
 
+/// Calculate jib tip position from boom and jib geometry
+///
+/// # Arguments
+/// * `boom_tip` - Boom tip position from `calculate_boom_tip_position`
+/// * `boom_angle_deg` - Boom angle from horizontal
+/// * `swing_angle_deg` - Swing angle (0° = forward, 90° = right, etc.)
+/// * `jib_pivot_offset_m` - Distance from boom tip to jib pivot along the boom axis
+/// * `jib_length_m` - Length of jib in meters
+/// * `jib_offset_deg` - Angle between boom and jib, positive below the boom
+pub fn calculate_jib_tip_position(
+    boom_tip: Point3<f32>,
+    boom_angle_deg: f32,
+    swing_angle_deg: f32,
+    jib_pivot_offset_m: f32,
+    jib_length_m: f32,
+    jib_offset_deg: f32,
+) -> Point3<f32> {
+    let boom_angle_rad = boom_angle_deg.to_radians();
+    let jib_angle_rad = (boom_angle_deg - jib_offset_deg).to_radians();
+    let swing_angle_rad = swing_angle_deg.to_radians();
+
+    let horizontal_reach =
+        jib_pivot_offset_m * boom_angle_rad.cos() + jib_length_m * jib_angle_rad.cos();
+    let vertical_reach =
+        jib_pivot_offset_m * boom_angle_rad.sin() + jib_length_m * jib_angle_rad.sin();
+
+    Point3::new(
+        boom_tip.x + horizontal_reach * swing_angle_rad.sin(),
+        boom_tip.y + horizontal_reach * swing_angle_rad.cos(),
+        boom_tip.z + vertical_reach,
+    )
+}
+
 /// Calculate boom angle needed to reach a specific hook height at given radius
 ///
 /// Returns None if the target is unreachable with given boom length
@@ -85,6 +118,14 @@ pub fn calculate_boom_angle_for_height(
     Some(angle_rad.to_degrees())
 }
 
+/// Shortest signed slew from one swing angle to another
+///
+/// In (-180°, 180°]; a half turn is taken clockwise (positive).
+pub fn shortest_swing_delta(from_deg: f32, to_deg: f32) -> f32 {
+    let delta = (to_deg - from_deg).rem_euclid(360.0);
+    if delta > 180.0 { delta - 360.0 } else { delta }
+}
+
 /// Calculate the load path as crane swings
 ///
 /// Returns vector of hook positions during swing from start_angle to end_angle
@@ -1,18 +1,50 @@
+pub mod capacity_contour;
+pub mod collision;
+pub mod composite_load;
 pub mod crane_data;
 pub mod ground_bearing;
+pub mod headroom;
+pub mod inverse_kinematics;
 pub mod kinematics;
+pub mod lift_plan;
+pub mod path_planner;
+pub mod placement;
+pub mod range_diagram;
 pub mod rigging;
+pub mod rigging_assembly;
+pub mod selection;
 
 // Re-export commonly used types
 pub use crane_data::{
-    CapacityChart, CapacityPoint, CounterweightConfig, CounterweightSlab, CraneConfigError,
-    CraneConfiguration, CraneSpec, CraneState, CraneType, LoadChart, OutriggerConfig,
-    OutriggerPosition, OutriggerSystem,
+    BoomDeflection, BoomDeflectionModel, CapacityChart, CapacityPoint, ChartDiagnostic,
+    ChartFormat, ChartProvenance, ChartReport, ChartUnits, ChartValidator, CounterweightConfig,
+    CounterweightSlab, CraneConfigError, CraneConfiguration, CraneSpec, CraneState, CraneType,
+    CrawlerCalculator, CrawlerSpec, DEFAULT_SPEC_DIR, JibSpec, JibState, JibType, LoadChart,
+    OutriggerConfig, OutriggerPosition, OutriggerSystem, Quantity, RegisteredSpec, SPEC_DIR_ENV,
+    Severity, SpecFile, SpecIssue, SpecRegistry, TelescopeState, TelescopingMode,
+    TelescopingSystem, TowerFoundationCalculator, TowerSpec, Unit, UnitSystem, ValidationOptions,
+    WindCondition, default_spec_dir,
 };
 
+pub use capacity_contour::{
+    CapacityContour, CapacityContourCalculator, ContourOptions, ContourSector,
+};
+
+pub use collision::{
+    CollisionChecker, CollisionEvent, CollisionLoad, CollisionObstacle, CollisionOptions,
+    CollisionPart, CollisionReport, CollisionShape, Contact, MotionSample, ObstacleClearance,
+};
+
+pub use composite_load::{CogEnvelope, CompositeLoad, CylinderAxis, LoadPart, PartShape};
+
 pub use rigging::{
-    Load, PickPoint, RiggingAnalysis, RiggingCalculator, RiggingConfiguration, Sling,
-    SlingMaterial, SlingSpec,
+    CogEnvelopeAnalysis, Load, PickPoint, RiggingAnalysis, RiggingCalculator, RiggingConfiguration,
+    Sling, SlingMaterial, SlingSpec,
+};
+
+pub use rigging_assembly::{
+    MemberForce, MemberKind, RiggingAssembly, RiggingAssemblyAnalysis, RiggingMember, RiggingNode,
+    RiggingNodeKind,
 };
 
 pub use ground_bearing::{
@@ -20,8 +52,30 @@ pub use ground_bearing::{
     MatMaterial, PadMaterial, SoilType, SupportPoint, SupportType,
 };
 
+pub use headroom::{HeadroomAnalysis, HeadroomCalculator, HeadroomLimits};
+
+pub use inverse_kinematics::{HookPose, IkSolution, IkSolver, LiftTarget};
+
+pub use path_planner::{LiftPath, PathPlanError, PathPlanner, PlannerOptions, TrajectoryPoint};
+
+pub use placement::{
+    GroundZone, NoGoZone, PlacementCandidate, PlacementHeatMap, PlacementOptimizer,
+    PlacementOptions, PlacementResult, Site, SiteObstacle,
+};
+
+pub use selection::{
+    CraneSelection, CraneSelector, LiftRequirement, OutriggerSetup, SelectionOptions,
+    SelectionOrder,
+};
+
+pub use lift_plan::{ChartReference, ChartRole, LiftPlan, PlanWarning};
+
+pub use range_diagram::{
+    RangeCurve, RangeCurveKind, RangeDiagram, RangeDiagramGenerator, RangeDiagramOptions,
+};
+
 pub use kinematics::{
     calculate_boom_angle_for_height, calculate_boom_tip_position,
-    calculate_hoist_length_for_height, calculate_hook_position, calculate_swing_path,
-    check_clearance,
+    calculate_hoist_length_for_height, calculate_hook_position, calculate_jib_tip_position,
+    calculate_swing_path, check_clearance, shortest_swing_delta,
 };
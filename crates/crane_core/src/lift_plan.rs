@@ -0,0 +1,475 @@
+// crates/crane_core/src/lift_plan.rs
+
+use nalgebra::Point3;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::crane_data::{
+    CapacityChart, CounterweightConfig, CraneConfiguration, CraneSpec, JibState, OutriggerSystem,
+    TelescopeState,
+};
+
+/// Which of a crane's charts a reference is to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ChartRole {
+    /// Main boom chart (over-end chart when there is an over-side chart)
+    Main,
+    /// Crawler over-side chart
+    OverSide,
+    /// Jib chart for one jib length and offset (or main boom angle)
+    Jib {
+        jib_id: String,
+        length_m: f32,
+        offset_deg: Option<f32>,
+        boom_angle_deg: Option<f32>,
+    },
+}
+
+impl fmt::Display for ChartRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Main => write!(f, "main chart"),
+            Self::OverSide => write!(f, "over-side chart"),
+            Self::Jib {
+                jib_id,
+                length_m,
+                offset_deg,
+                boom_angle_deg,
+            } => {
+                write!(f, "{} {:.1}m", jib_id, length_m)?;
+                if let Some(offset) = offset_deg {
+                    write!(f, " {:.0}°", offset)?;
+                }
+                if let Some(angle) = boom_angle_deg {
+                    write!(f, " at {:.0}° boom", angle)?;
+                }
+                write!(f, " jib chart")
+            }
+        }
+    }
+}
+
+/// A load chart a lift plan was checked against
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartReference {
+    pub crane_id: String,
+    pub role: ChartRole,
+    /// Empty when the chart carries no provenance
+    #[serde(default)]
+    pub document_number: String,
+    #[serde(default)]
+    pub revision: String,
+    /// SHA-256 of the chart content at the time the plan was saved
+    pub content_hash: String,
+    #[serde(default)]
+    pub source_file: Option<String>,
+}
+
+impl ChartReference {
+    /// Reference to a chart as it is now
+    pub fn from_chart(crane_id: &str, role: ChartRole, chart: &CapacityChart) -> Self {
+        let provenance = chart.provenance.as_ref();
+        Self {
+            crane_id: crane_id.to_string(),
+            role,
+            document_number: provenance
+                .map(|p| p.document_number.clone())
+                .unwrap_or_default(),
+            revision: provenance.map(|p| p.revision.clone()).unwrap_or_default(),
+            content_hash: chart.content_hash(),
+            source_file: provenance.and_then(|p| p.source_file.clone()),
+        }
+    }
+
+    /// Every chart a setup is rated from: the main chart, the crawler
+    /// over-side chart and the rigged jib's chart
+    pub fn for_setup(
+        spec: &CraneSpec,
+        jib: Option<&JibState>,
+        boom_angle_deg: f32,
+    ) -> Vec<ChartReference> {
+        let mut references = vec![Self::from_chart(
+            &spec.id,
+            ChartRole::Main,
+            &spec.capacity_chart,
+        )];
+
+        if let Some(over_side) = spec
+            .crawler
+            .as_ref()
+            .and_then(|c| c.over_side_chart.as_ref())
+        {
+            references.push(Self::from_chart(&spec.id, ChartRole::OverSide, over_side));
+        }
+
+        if let Some(state) = jib
+            && let Some(jib_chart) = spec
+                .get_jib(&state.jib_id)
+                .and_then(|j| j.find_chart(state, boom_angle_deg))
+        {
+            let role = ChartRole::Jib {
+                jib_id: state.jib_id.clone(),
+                length_m: jib_chart.jib_length_m,
+                offset_deg: jib_chart.offset_deg,
+                boom_angle_deg: jib_chart.boom_angle_deg,
+            };
+            references.push(Self::from_chart(&spec.id, role, &jib_chart.chart));
+        }
+        references
+    }
+
+    fn describe_revision(&self) -> String {
+        if self.revision.is_empty() {
+            "(none)".to_string()
+        } else {
+            self.revision.clone()
+        }
+    }
+}
+
+/// Reasons a saved plan may no longer match the crane data
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlanWarning {
+    /// Plan was made for another crane model
+    CraneMismatch { planned: String, found: String },
+    /// Chart document revision differs from the one the plan was checked against
+    ChartRevisionChanged {
+        chart: String,
+        document: String,
+        planned: String,
+        found: String,
+    },
+    /// Chart values changed without a revision change
+    ChartContentChanged {
+        chart: String,
+        planned: String,
+        found: String,
+    },
+    /// A chart the plan was checked against is no longer in the crane data
+    ChartRemoved { chart: String },
+    /// The setup is now rated from a chart the plan was not checked against
+    ChartAdded { chart: String },
+    /// Chart does not list the planned crane's serial number
+    SerialNotCovered { serial: String },
+}
+
+impl fmt::Display for PlanWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let short = |hash: &str| hash.chars().take(12).collect::<String>();
+        match self {
+            Self::CraneMismatch { planned, found } => {
+                write!(f, "Plan was made for {}, not {}", planned, found)
+            }
+            Self::ChartRevisionChanged {
+                chart,
+                document,
+                planned,
+                found,
+            } => write!(
+                f,
+                "Load chart {} ({}) is now rev {} (plan checked against rev {})",
+                document, chart, found, planned
+            ),
+            Self::ChartContentChanged {
+                chart,
+                planned,
+                found,
+            } => write!(
+                f,
+                "Load chart content has changed in the {} (hash {} -> {})",
+                chart,
+                short(planned),
+                short(found)
+            ),
+            Self::ChartRemoved { chart } => {
+                write!(f, "The {} the plan was checked against is gone", chart)
+            }
+            Self::ChartAdded { chart } => {
+                write!(f, "The plan was not checked against the {}", chart)
+            }
+            Self::SerialNotCovered { serial } => {
+                write!(f, "Load chart does not cover crane serial {}", serial)
+            }
+        }
+    }
+}
+
+/// A saved crane setup for one lift, tied to the charts it was checked against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiftPlan {
+    pub name: String,
+    pub crane_id: String,
+    #[serde(default)]
+    pub crane_serial: Option<String>,
+    pub load_kg: f32,
+
+    pub position: Point3<f32>,
+    pub heading_deg: f32,
+    pub boom_length_m: f32,
+    pub boom_angle_deg: f32,
+    pub swing_angle_deg: f32,
+    pub hoist_length_m: f32,
+    #[serde(default)]
+    pub telescope: Option<TelescopeState>,
+    #[serde(default)]
+    pub jib: Option<JibState>,
+    #[serde(default)]
+    pub trolley_radius_m: Option<f32>,
+    pub outriggers: OutriggerSystem,
+    pub counterweight: CounterweightConfig,
+
+    /// Every chart the setup was checked against, main chart first
+    pub charts: Vec<ChartReference>,
+}
+
+impl LiftPlan {
+    /// Record a configuration and the charts it is checked against
+    pub fn capture(name: &str, config: &CraneConfiguration, load_kg: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            crane_id: config.spec.id.clone(),
+            crane_serial: None,
+            load_kg,
+            position: config.position,
+            heading_deg: config.heading_deg,
+            boom_length_m: config.boom_length_m,
+            boom_angle_deg: config.boom_angle_deg,
+            swing_angle_deg: config.swing_angle_deg,
+            hoist_length_m: config.hoist_length_m,
+            telescope: config.telescope.clone(),
+            jib: config.jib.clone(),
+            trolley_radius_m: config.trolley_radius_m,
+            outriggers: config.outriggers.clone(),
+            counterweight: config.counterweight.clone(),
+            charts: ChartReference::for_setup(
+                &config.spec,
+                config.jib.as_ref(),
+                config.boom_angle_deg,
+            ),
+        }
+    }
+
+    /// Reference to the main chart
+    pub fn main_chart(&self) -> Option<&ChartReference> {
+        self.charts.iter().find(|c| c.role == ChartRole::Main)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("JSON write error: {}", e))
+    }
+
+    pub fn from_json(data: &str) -> Result<Self, String> {
+        serde_json::from_str(data).map_err(|e| format!("JSON parse error: {}", e))
+    }
+
+    /// Compare the plan's chart references against a spec's current charts
+    pub fn check_chart(&self, spec: &CraneSpec) -> Vec<PlanWarning> {
+        let mut warnings = Vec::new();
+        if spec.id != self.crane_id {
+            warnings.push(PlanWarning::CraneMismatch {
+                planned: self.crane_id.clone(),
+                found: spec.id.clone(),
+            });
+        }
+
+        let current = ChartReference::for_setup(spec, self.jib.as_ref(), self.boom_angle_deg);
+        for planned in &self.charts {
+            let chart = planned.role.to_string();
+            let Some(found) = current.iter().find(|c| c.role == planned.role) else {
+                warnings.push(PlanWarning::ChartRemoved { chart });
+                continue;
+            };
+
+            if found.revision != planned.revision
+                || found.document_number != planned.document_number
+            {
+                warnings.push(PlanWarning::ChartRevisionChanged {
+                    chart,
+                    document: found.document_number.clone(),
+                    planned: planned.describe_revision(),
+                    found: found.describe_revision(),
+                });
+            } else if found.content_hash != planned.content_hash {
+                warnings.push(PlanWarning::ChartContentChanged {
+                    chart,
+                    planned: planned.content_hash.clone(),
+                    found: found.content_hash.clone(),
+                });
+            }
+        }
+        for found in &current {
+            if !self.charts.iter().any(|c| c.role == found.role) {
+                warnings.push(PlanWarning::ChartAdded {
+                    chart: found.role.to_string(),
+                });
+            }
+        }
+
+        if let (Some(serial), Some(provenance)) =
+            (&self.crane_serial, &spec.capacity_chart.provenance)
+            && !provenance.applies_to_serial(serial)
+        {
+            warnings.push(PlanWarning::SerialNotCovered {
+                serial: serial.clone(),
+            });
+        }
+        warnings
+    }
+
+    /// Rebuild the configuration on `spec`, with any chart warnings
+    pub fn restore(&self, spec: CraneSpec) -> (CraneConfiguration, Vec<PlanWarning>) {
+        let warnings = self.check_chart(&spec);
+        let mut config = CraneConfiguration::new(spec);
+        config.position = self.position;
+        config.heading_deg = self.heading_deg;
+        config.boom_length_m = self.boom_length_m;
+        config.boom_angle_deg = self.boom_angle_deg;
+        config.swing_angle_deg = self.swing_angle_deg;
+        config.hoist_length_m = self.hoist_length_m;
+        config.telescope = self.telescope.clone();
+        config.jib = self.jib.clone();
+        config.trolley_radius_m = self.trolley_radius_m;
+        config.outriggers = self.outriggers.clone();
+        config.counterweight = self.counterweight.clone();
+        (config, warnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{ChartProvenance, test_crane, test_spec};
+
+    fn planned_config() -> CraneConfiguration {
+        let mut config = test_crane(40.0, 62.0);
+        config.swing_angle_deg = 45.0;
+        config
+    }
+
+    #[test]
+    fn test_plan_round_trip() {
+        let config = planned_config();
+        let plan = LiftPlan::capture("Roof unit", &config, 12_000.0);
+        assert_eq!(plan.charts.len(), 1);
+        let main = plan.main_chart().unwrap();
+        assert_eq!(main.content_hash.len(), 64);
+        assert!(!main.revision.is_empty());
+
+        let reopened = LiftPlan::from_json(&plan.to_json().unwrap()).unwrap();
+        let (restored, warnings) = reopened.restore(test_spec("liebherr_ltm_1100_5_2"));
+        assert!(warnings.is_empty());
+        assert_eq!(restored.boom_angle_deg, 62.0);
+        assert_eq!(restored.swing_angle_deg, 45.0);
+        assert_eq!(
+            restored.counterweight.get_total_weight_kg(),
+            config.counterweight.get_total_weight_kg()
+        );
+    }
+
+    #[test]
+    fn test_reopen_against_new_revision() {
+        let plan = LiftPlan::capture("Roof unit", &planned_config(), 12_000.0);
+
+        let mut spec = test_spec("liebherr_ltm_1100_5_2");
+        let mut provenance = spec.capacity_chart.provenance.clone().unwrap();
+        provenance.revision = "2".to_string();
+        spec.capacity_chart.set_provenance(provenance);
+
+        let warnings = plan.check_chart(&spec);
+        assert!(matches!(
+            warnings.as_slice(),
+            [PlanWarning::ChartRevisionChanged { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_reopen_against_edited_chart() {
+        let mut plan = LiftPlan::capture("Roof unit", &planned_config(), 12_000.0);
+        plan.crane_serial = Some("075 123".to_string());
+
+        // Same revision, but a value was edited
+        let mut spec = test_spec("liebherr_ltm_1100_5_2");
+        let chart = spec.capacity_chart.charts.values_mut().next().unwrap();
+        chart.points[0].capacity_kg -= 500.0;
+        let mut provenance = ChartProvenance::new("Liebherr", &plan.charts[0].document_number, "1");
+        provenance.serial_numbers = vec!["075 999".to_string()];
+        spec.capacity_chart.provenance = Some(provenance);
+
+        let warnings = plan.check_chart(&spec);
+        assert!(warnings.contains(&PlanWarning::SerialNotCovered {
+            serial: "075 123".to_string()
+        }));
+        assert!(
+            warnings
+                .iter()
+                .any(|w| matches!(w, PlanWarning::ChartContentChanged { .. }))
+        );
+    }
+
+    #[test]
+    fn test_jib_and_over_side_charts_tracked() {
+        let mut config = planned_config();
+        config.boom_length_m = 50.0;
+        config.jib = Some(JibState {
+            jib_id: "ltm_1100_fly_jib".to_string(),
+            length_m: 10.5,
+            offset_deg: 20.0,
+        });
+        let plan = LiftPlan::capture("Tower top", &config, 3_000.0);
+        assert_eq!(plan.charts.len(), 2);
+        assert!(plan.check_chart(&config.spec).is_empty());
+
+        // Only the rigged jib chart is edited
+        let mut spec = config.spec.clone();
+        let jib = spec.jibs.iter_mut().next().unwrap();
+        let rigged = jib
+            .load_charts
+            .iter_mut()
+            .find(|c| c.offset_deg == Some(20.0))
+            .unwrap();
+        for chart in rigged.chart.charts.values_mut() {
+            chart.points[0].capacity_kg += 250.0;
+        }
+        let warnings = plan.check_chart(&spec);
+        assert!(matches!(
+            warnings.as_slice(),
+            [PlanWarning::ChartContentChanged { chart, .. }] if chart.contains("jib")
+        ));
+
+        let crawler = CraneConfiguration::new(test_spec("liebherr_lr_1300_sx"));
+        let plan = LiftPlan::capture("Crawler lift", &crawler, 20_000.0);
+        assert!(plan.charts.iter().any(|c| c.role == ChartRole::OverSide));
+
+        let mut spec = crawler.spec.clone();
+        let over_side = spec
+            .crawler
+            .as_mut()
+            .and_then(|c| c.over_side_chart.as_mut())
+            .unwrap();
+        over_side.charts.values_mut().next().unwrap().points[0].capacity_kg -= 500.0;
+        let warnings = plan.check_chart(&spec);
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            PlanWarning::ChartContentChanged { chart, .. } | PlanWarning::ChartRevisionChanged { chart, .. }
+                if chart == "over-side chart"
+        )));
+
+        spec.crawler.as_mut().unwrap().over_side_chart = None;
+        assert!(
+            plan.check_chart(&spec)
+                .contains(&PlanWarning::ChartRemoved {
+                    chart: "over-side chart".to_string()
+                })
+        );
+    }
+
+    #[test]
+    fn test_warning_text_with_short_hash() {
+        let warning = PlanWarning::ChartContentChanged {
+            chart: "main chart".to_string(),
+            planned: "ä".repeat(20),
+            found: "abc".to_string(),
+        };
+        assert!(warning.to_string().contains(&"ä".repeat(12)));
+    }
+}
@@ -0,0 +1,739 @@
+// crates/crane_core/src/path_planner.rs
+
+use crate::collision::{
+    CollisionChecker, CollisionLoad, CollisionObstacle, CollisionOptions, MotionSample,
+};
+use crate::crane_data::{CraneConfiguration, CraneState, TowerJibType};
+use crate::kinematics::shortest_swing_delta;
+use nalgebra::Point3;
+
+/// Planner axes: swing, boom angle, boom length, hoist, trolley
+type Axes = [f32; 5];
+
+const SWING: usize = 0;
+const LUFF: usize = 1;
+const TELESCOPE: usize = 2;
+const HOIST: usize = 3;
+const TROLLEY: usize = 4;
+
+/// Axis movement treated as one unit of configuration-space distance
+const AXIS_STEP: Axes = [0.5, 0.5, 0.25, 0.25, 0.25];
+
+#[derive(Debug, Clone)]
+pub enum PathPlanError {
+    StartInvalid(String),
+    GoalInvalid(String),
+    NoPathFound { iterations: usize },
+}
+
+impl std::fmt::Display for PathPlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StartInvalid(reason) => write!(f, "Start state invalid: {}", reason),
+            Self::GoalInvalid(reason) => write!(f, "Goal state invalid: {}", reason),
+            Self::NoPathFound { iterations } => {
+                write!(f, "No safe path found after {} iterations", iterations)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathPlanError {}
+
+/// Planner limits and search settings
+#[derive(Debug, Clone)]
+pub struct PlannerOptions {
+    /// Highest capacity utilisation allowed anywhere on the path
+    pub max_utilization_percent: f32,
+    /// Allow telescoping during the move (continuously variable booms only;
+    /// booms with telescoping modes keep their section extensions)
+    pub allow_telescoping: bool,
+    /// Used when the spec has no slew speed
+    pub default_swing_speed_rpm: f32,
+    /// Used when the spec has no hoist speed
+    pub default_hoist_speed_m_per_min: f32,
+    pub luff_speed_deg_per_s: f32,
+    pub telescope_speed_m_per_s: f32,
+    pub trolley_speed_m_per_s: f32,
+    /// Fraction of the rated axis speeds to plan with
+    pub speed_factor: f32,
+    /// Time between output trajectory points
+    pub output_step_s: f32,
+    pub max_iterations: usize,
+    /// Longest tree edge, in axis steps
+    pub extend_distance: f32,
+    pub smoothing_passes: usize,
+    pub seed: u64,
+    pub collision: CollisionOptions,
+}
+
+impl Default for PlannerOptions {
+    fn default() -> Self {
+        Self {
+            max_utilization_percent: 100.0,
+            allow_telescoping: true,
+            default_swing_speed_rpm: 1.0,
+            default_hoist_speed_m_per_min: 60.0,
+            luff_speed_deg_per_s: 1.5,
+            telescope_speed_m_per_s: 0.3,
+            trolley_speed_m_per_s: 1.0,
+            speed_factor: 1.0,
+            output_step_s: 0.5,
+            max_iterations: 4_000,
+            extend_distance: 20.0,
+            smoothing_passes: 100,
+            seed: 0x2b10c,
+            collision: CollisionOptions::default(),
+        }
+    }
+}
+
+/// State along the planned path
+#[derive(Debug, Clone, Copy)]
+pub struct TrajectoryPoint {
+    pub time_s: f32,
+    pub state: CraneState,
+    pub hook_position: Point3<f32>,
+    pub capacity_kg: f32,
+    pub utilization_percent: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiftPath {
+    /// Corner states of the smoothed path
+    pub waypoints: Vec<CraneState>,
+    /// Time-parameterised states at `output_step_s` (plus every waypoint)
+    pub trajectory: Vec<TrajectoryPoint>,
+    pub duration_s: f32,
+    pub max_utilization_percent: f32,
+}
+
+impl LiftPath {
+    /// Trajectory as collision-check samples
+    pub fn motion_samples(&self) -> Vec<MotionSample> {
+        self.trajectory
+            .iter()
+            .map(|p| MotionSample {
+                time_s: p.time_s,
+                state: p.state,
+                load_yaw_deg: 0.0,
+            })
+            .collect()
+    }
+
+    /// Interpolated state at a time along the path
+    pub fn state_at(&self, time_s: f32) -> Option<CraneState> {
+        let first = self.trajectory.first()?;
+        if time_s <= first.time_s {
+            return Some(first.state);
+        }
+
+        self.trajectory
+            .windows(2)
+            .find(|w| time_s <= w[1].time_s)
+            .map(|w| {
+                let span = (w[1].time_s - w[0].time_s).max(f32::EPSILON);
+                w[0].state.lerp(&w[1].state, (time_s - w[0].time_s) / span)
+            })
+            .or(self.trajectory.last().map(|p| p.state))
+    }
+}
+
+/// Plans collision-free, capacity-safe crane moves
+pub struct PathPlanner;
+
+impl PathPlanner {
+    /// Plan a move from `start` to `goal` with `load_kg` on the hook
+    ///
+    /// Tries the straight multi-axis move first, then searches configuration
+    /// space (RRT-Connect) and shortcuts the result.
+    pub fn plan(
+        config: &CraneConfiguration,
+        start: &CraneState,
+        goal: &CraneState,
+        load_kg: f32,
+        load: Option<&CollisionLoad>,
+        obstacles: &[CollisionObstacle],
+        options: &PlannerOptions,
+    ) -> Result<LiftPath, PathPlanError> {
+        // Aim the short way round; the search may still slew the other way.
+        // Path swing angles run on from the start, unwrapped.
+        let goal = &CraneState {
+            swing_angle_deg: start.swing_angle_deg
+                + shortest_swing_delta(start.swing_angle_deg, goal.swing_angle_deg),
+            ..*goal
+        };
+        let mut space = ConfigSpace::new(config, start, goal, load_kg, load, obstacles, options);
+
+        let from = to_axes(start);
+        let to = to_axes(goal);
+        space.check(&from).map_err(PathPlanError::StartInvalid)?;
+        space.check(&to).map_err(PathPlanError::GoalInvalid)?;
+
+        let path = if space.edge_valid(&from, &to) {
+            vec![from, to]
+        } else {
+            let path = space
+                .rrt_connect(from, to)
+                .ok_or(PathPlanError::NoPathFound {
+                    iterations: options.max_iterations,
+                })?;
+            space.shortcut(path)
+        };
+
+        Ok(space.time_parameterise(&path))
+    }
+}
+
+fn to_axes(state: &CraneState) -> Axes {
+    [
+        state.swing_angle_deg,
+        state.boom_angle_deg,
+        state.boom_length_m,
+        state.hoist_length_m,
+        state.trolley_radius_m.unwrap_or(0.0),
+    ]
+}
+
+fn distance(a: &Axes, b: &Axes) -> f32 {
+    a.iter()
+        .zip(b)
+        .zip(AXIS_STEP)
+        .map(|((x, y), step)| ((x - y) / step).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+fn lerp(a: &Axes, b: &Axes, t: f32) -> Axes {
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+/// Small deterministic generator (xorshift64*) so plans are repeatable
+struct Rng(u64);
+
+impl Rng {
+    fn next_f32(&mut self) -> f32 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        let value = self.0.wrapping_mul(0x2545_f491_4f6c_dd1d);
+        (value >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+struct Node {
+    axes: Axes,
+    parent: Option<usize>,
+}
+
+struct ConfigSpace<'a> {
+    posed: CraneConfiguration,
+    template: CraneState,
+    bounds: [(f32, f32); 5],
+    load_kg: f32,
+    load: Option<&'a CollisionLoad>,
+    obstacles: &'a [CollisionObstacle],
+    options: &'a PlannerOptions,
+    rng: Rng,
+}
+
+impl<'a> ConfigSpace<'a> {
+    fn new(
+        config: &CraneConfiguration,
+        start: &CraneState,
+        goal: &CraneState,
+        load_kg: f32,
+        load: Option<&'a CollisionLoad>,
+        obstacles: &'a [CollisionObstacle],
+        options: &'a PlannerOptions,
+    ) -> Self {
+        let spec = &config.spec;
+        let fixed = |v: f32| (v, v);
+
+        let horizontal_tower = spec
+            .tower
+            .as_ref()
+            .is_some_and(|t| t.jib_type == TowerJibType::Horizontal);
+
+        // Either way round is allowed for the slew
+        let swing = (
+            start.swing_angle_deg.min(goal.swing_angle_deg) - 180.0,
+            start.swing_angle_deg.max(goal.swing_angle_deg) + 180.0,
+        );
+        let luff = if horizontal_tower {
+            fixed(start.boom_angle_deg)
+        } else {
+            (spec.min_boom_angle_deg, spec.max_boom_angle_deg)
+        };
+        // Moded booms keep their section extensions, as IkSolver does; only a
+        // continuously variable boom telescopes during the move
+        let telescope =
+            if options.allow_telescoping && spec.tower.is_none() && spec.telescoping.is_none() {
+                spec.boom_length_range
+            } else {
+                fixed(start.boom_length_m)
+            };
+        let trolley = match (&spec.tower, start.trolley_radius_m) {
+            (Some(tower), Some(_)) if horizontal_tower => {
+                (tower.min_trolley_radius_m, start.boom_length_m)
+            }
+            _ => fixed(start.trolley_radius_m.unwrap_or(0.0)),
+        };
+
+        Self {
+            posed: config.clone(),
+            template: *start,
+            bounds: [swing, luff, telescope, spec.hoist_length_range, trolley],
+            load_kg,
+            load,
+            obstacles,
+            options,
+            rng: Rng(options.seed.max(1)),
+        }
+    }
+
+    fn state(&self, axes: &Axes) -> CraneState {
+        CraneState {
+            swing_angle_deg: axes[SWING],
+            boom_angle_deg: axes[LUFF],
+            boom_length_m: axes[TELESCOPE],
+            hoist_length_m: axes[HOIST],
+            trolley_radius_m: self.template.trolley_radius_m.map(|_| axes[TROLLEY]),
+            position: self.template.position,
+        }
+    }
+
+    /// Capacity and utilisation of a state, or why it is not allowed
+    fn evaluate(&mut self, axes: &Axes) -> Result<(f32, f32), String> {
+        let state = self.state(axes);
+        self.posed.apply_state(&state);
+
+        let capacity = self
+            .posed
+            .get_loaded_capacity(self.load_kg)
+            .filter(|c| *c > 0.0)
+            .ok_or_else(|| format!("No chart capacity at {:.1}m", self.posed.get_radius()))?;
+
+        Ok((capacity, self.load_kg / capacity * 100.0))
+    }
+
+    fn check(&mut self, axes: &Axes) -> Result<(), String> {
+        let names = [
+            "Swing",
+            "Boom angle",
+            "Boom length",
+            "Hoist length",
+            "Trolley",
+        ];
+        for ((value, (min, max)), name) in axes.iter().zip(self.bounds).zip(names) {
+            if *value < min - 1e-3 || *value > max + 1e-3 {
+                return Err(format!(
+                    "{} {:.1} outside {:.1}-{:.1}",
+                    name, value, min, max
+                ));
+            }
+        }
+
+        let (_, utilization) = self.evaluate(axes)?;
+        self.posed.validate().map_err(|e| e.to_string())?;
+        if utilization > self.options.max_utilization_percent {
+            return Err(format!(
+                "{:.0}% utilisation exceeds the {:.0}% limit",
+                utilization, self.options.max_utilization_percent
+            ));
+        }
+
+        let clearances = CollisionChecker::check_state(
+            &self.posed,
+            self.load,
+            0.0,
+            self.obstacles,
+            &self.options.collision,
+        );
+        if let Some(((part, clearance), obstacle)) = clearances
+            .iter()
+            .zip(self.obstacles)
+            .find(|((_, c), _)| *c <= self.options.collision.required_clearance_m)
+        {
+            return Err(format!(
+                "{} within {:.2}m of {}",
+                part, clearance, obstacle.name
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn edge_valid(&mut self, from: &Axes, to: &Axes) -> bool {
+        let steps = distance(from, to).ceil().max(1.0) as usize;
+        (1..=steps).all(|i| self.check(&lerp(from, to, i as f32 / steps as f32)).is_ok())
+    }
+
+    fn sample(&mut self) -> Axes {
+        std::array::from_fn(|i| {
+            let (min, max) = self.bounds[i];
+            min + (max - min) * self.rng.next_f32()
+        })
+    }
+
+    fn nearest(tree: &[Node], target: &Axes) -> usize {
+        (0..tree.len())
+            .min_by(|&a, &b| {
+                distance(&tree[a].axes, target).total_cmp(&distance(&tree[b].axes, target))
+            })
+            .unwrap_or(0)
+    }
+
+    /// Grow `tree` one step towards `target`; returns the new node and whether it reached
+    fn extend(&mut self, tree: &mut Vec<Node>, target: &Axes) -> Option<(usize, bool)> {
+        let near = Self::nearest(tree, target);
+        let from = tree[near].axes;
+        let gap = distance(&from, target);
+        let (to, reached) = if gap <= self.options.extend_distance {
+            (*target, true)
+        } else {
+            (
+                lerp(&from, target, self.options.extend_distance / gap),
+                false,
+            )
+        };
+
+        if !self.edge_valid(&from, &to) {
+            return None;
+        }
+
+        tree.push(Node {
+            axes: to,
+            parent: Some(near),
+        });
+        Some((tree.len() - 1, reached))
+    }
+
+    fn rrt_connect(&mut self, start: Axes, goal: Axes) -> Option<Vec<Axes>> {
+        let mut trees = [
+            vec![Node {
+                axes: start,
+                parent: None,
+            }],
+            vec![Node {
+                axes: goal,
+                parent: None,
+            }],
+        ];
+        let mut active = 0;
+
+        for _ in 0..self.options.max_iterations {
+            let target = self.sample();
+            let (grown, other) = if active == 0 {
+                let [a, b] = &mut trees;
+                (a, b)
+            } else {
+                let [a, b] = &mut trees;
+                (b, a)
+            };
+
+            if let Some((new, _)) = self.extend(grown, &target) {
+                let bridge = grown[new].axes;
+
+                // Pull the other tree towards the new node until blocked or joined
+                loop {
+                    match self.extend(other, &bridge) {
+                        Some((joined, true)) => {
+                            let mut path = Self::branch(grown, new);
+                            let mut tail = Self::branch(other, joined);
+                            tail.reverse();
+                            path.extend(tail.into_iter().skip(1));
+                            if active == 1 {
+                                path.reverse();
+                            }
+                            return Some(path);
+                        }
+                        Some((_, false)) => continue,
+                        None => break,
+                    }
+                }
+            }
+
+            active = 1 - active;
+        }
+
+        None
+    }
+
+    /// Path from the tree root to `node`
+    fn branch(tree: &[Node], node: usize) -> Vec<Axes> {
+        let mut path = Vec::new();
+        let mut current = Some(node);
+        while let Some(i) = current {
+            path.push(tree[i].axes);
+            current = tree[i].parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Remove detours by joining random pairs of waypoints directly
+    fn shortcut(&mut self, mut path: Vec<Axes>) -> Vec<Axes> {
+        for _ in 0..self.options.smoothing_passes {
+            if path.len() <= 2 {
+                break;
+            }
+            let i = (self.rng.next_f32() * (path.len() - 2) as f32) as usize;
+            let j = i + 2 + (self.rng.next_f32() * (path.len() - i - 2) as f32) as usize;
+            let j = j.min(path.len() - 1);
+
+            if self.edge_valid(&path[i], &path[j]) {
+                path.drain(i + 1..j);
+            }
+        }
+        path
+    }
+
+    /// Axis speeds (per second) from the spec and options
+    fn axis_speeds(&self) -> Axes {
+        let spec = &self.posed.spec;
+        let factor = self.options.speed_factor.clamp(0.01, 1.0);
+        let swing_rpm = spec
+            .max_swing_speed_rpm
+            .unwrap_or(self.options.default_swing_speed_rpm);
+        let hoist = spec
+            .max_hoist_speed_m_per_min
+            .unwrap_or(self.options.default_hoist_speed_m_per_min);
+
+        [
+            swing_rpm * 6.0 * factor,
+            self.options.luff_speed_deg_per_s * factor,
+            self.options.telescope_speed_m_per_s * factor,
+            hoist / 60.0 * factor,
+            self.options.trolley_speed_m_per_s * factor,
+        ]
+    }
+
+    /// Synchronised axes: each segment lasts as long as its slowest axis needs
+    fn time_parameterise(&mut self, path: &[Axes]) -> LiftPath {
+        let speeds = self.axis_speeds();
+        let step = self.options.output_step_s.max(0.01);
+
+        let mut trajectory = Vec::new();
+        let mut time = 0.0;
+        let mut max_utilization: f32 = 0.0;
+
+        let mut push = |space: &mut Self, axes: &Axes, time_s: f32| {
+            let (capacity_kg, utilization_percent) = space.evaluate(axes).unwrap_or((0.0, 0.0));
+            max_utilization = max_utilization.max(utilization_percent);
+            trajectory.push(TrajectoryPoint {
+                time_s,
+                state: space.state(axes),
+                hook_position: space.posed.get_hook_position(),
+                capacity_kg,
+                utilization_percent,
+            });
+        };
+
+        push(self, &path[0], 0.0);
+        for pair in path.windows(2) {
+            let duration = (0..5)
+                .map(|i| (pair[1][i] - pair[0][i]).abs() / speeds[i].max(f32::EPSILON))
+                .fold(0.0, f32::max);
+            let steps = (duration / step).ceil().max(1.0) as usize;
+
+            for i in 1..=steps {
+                let t = i as f32 / steps as f32;
+                push(self, &lerp(&pair[0], &pair[1], t), time + duration * t);
+            }
+            time += duration;
+        }
+
+        LiftPath {
+            waypoints: path.iter().map(|a| self.state(a)).collect(),
+            trajectory,
+            duration_s: time,
+            max_utilization_percent: max_utilization,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{OutriggerPosition, test_crane};
+    use nalgebra::Vector3;
+
+    fn crane() -> CraneConfiguration {
+        let mut config = test_crane(30.0, 60.0);
+        config.hoist_length_m = 20.0;
+        config
+    }
+
+    fn state(config: &CraneConfiguration, swing_deg: f32) -> CraneState {
+        let mut state = CraneState::from(config);
+        state.swing_angle_deg = swing_deg;
+        state
+    }
+
+    #[test]
+    fn test_direct_move_respects_swing_speed() {
+        let config = crane();
+        let path = PathPlanner::plan(
+            &config,
+            &state(&config, 0.0),
+            &state(&config, 90.0),
+            5_000.0,
+            None,
+            &[],
+            &PlannerOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(path.waypoints.len(), 2);
+
+        // 90° at 1.8 rpm (10.8°/s)
+        assert!((path.duration_s - 90.0 / 10.8).abs() < 1e-3);
+        assert!(path.max_utilization_percent > 0.0);
+        let end = path.state_at(path.duration_s).unwrap();
+        assert!((end.swing_angle_deg - 90.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_swing_takes_short_way_round() {
+        let config = crane();
+        let (from, to) = (state(&config, 350.0), state(&config, 10.0));
+
+        let mid = from.lerp(&to, 0.5);
+        assert!(mid.swing_angle_deg.rem_euclid(360.0) < 1e-3);
+
+        let path = PathPlanner::plan(
+            &config,
+            &from,
+            &to,
+            5_000.0,
+            None,
+            &[],
+            &PlannerOptions::default(),
+        )
+        .unwrap();
+
+        // 20° through the front, not 340° over the rear
+        assert!((path.duration_s - 20.0 / 10.8).abs() < 1e-3);
+        let end = path.state_at(path.duration_s).unwrap();
+        assert!((end.swing_angle_deg.rem_euclid(360.0) - 10.0).abs() < 1e-3);
+        assert!(path.trajectory.iter().all(|p| {
+            let swing = p.state.swing_angle_deg.rem_euclid(360.0);
+            !(10.5..349.5).contains(&swing)
+        }));
+    }
+
+    #[test]
+    fn test_plans_around_obstacle() {
+        let config = crane();
+        let load = CollisionLoad::from_box(Vector3::new(2.0, 2.0, 2.0), 2.0);
+
+        // Tall block in the way of the direct slew at hook radius
+        let block = CollisionObstacle::from_box(
+            "Tank",
+            Point3::new(15.0, 0.0, 5.0),
+            Vector3::new(4.0, 4.0, 10.0),
+            0.0,
+        );
+        let obstacles = std::slice::from_ref(&block);
+
+        let path = PathPlanner::plan(
+            &config,
+            &state(&config, 0.0),
+            &state(&config, 180.0),
+            5_000.0,
+            Some(&load),
+            obstacles,
+            &PlannerOptions::default(),
+        )
+        .unwrap();
+
+        let report = CollisionChecker::check_motion(
+            &config,
+            &path.motion_samples(),
+            Some(&load),
+            obstacles,
+            &CollisionOptions::default(),
+        );
+        assert!(report.is_clear);
+        assert!(path.max_utilization_percent <= 100.0);
+        assert!(
+            path.trajectory
+                .windows(2)
+                .all(|w| w[1].time_s >= w[0].time_s)
+        );
+    }
+
+    #[test]
+    fn test_overload_rejected() {
+        let config = crane();
+        let result = PathPlanner::plan(
+            &config,
+            &state(&config, 0.0),
+            &state(&config, 90.0),
+            500_000.0,
+            None,
+            &[],
+            &PlannerOptions::default(),
+        );
+        assert!(matches!(result, Err(PathPlanError::StartInvalid(_))));
+    }
+
+    #[test]
+    fn test_invalid_configuration_rejected() {
+        // A retracted leg fails validation even though the chart has capacity
+        let mut config = crane();
+        config
+            .outriggers
+            .retract(OutriggerPosition::FrontLeft)
+            .unwrap();
+
+        let result = PathPlanner::plan(
+            &config,
+            &state(&config, 0.0),
+            &state(&config, 90.0),
+            5_000.0,
+            None,
+            &[],
+            &PlannerOptions::default(),
+        );
+        assert!(matches!(result, Err(PathPlanError::StartInvalid(_))));
+    }
+
+    #[test]
+    fn test_moded_boom_keeps_its_sections() {
+        let mut config = crane();
+        config.set_telescoping_mode("T1").unwrap();
+        let start = state(&config, 0.0);
+
+        let path = PathPlanner::plan(
+            &config,
+            &start,
+            &state(&config, 90.0),
+            5_000.0,
+            None,
+            &[],
+            &PlannerOptions::default(),
+        )
+        .unwrap();
+        assert!(
+            path.trajectory
+                .iter()
+                .all(|p| p.state.boom_length_m == start.boom_length_m)
+        );
+
+        // Reaching another length would need a telescoping step mid-move
+        let mut goal = state(&config, 90.0);
+        goal.boom_length_m += 5.0;
+        let result = PathPlanner::plan(
+            &config,
+            &start,
+            &goal,
+            5_000.0,
+            None,
+            &[],
+            &PlannerOptions::default(),
+        );
+        assert!(matches!(result, Err(PathPlanError::GoalInvalid(_))));
+    }
+}
@@ -0,0 +1,601 @@
+// crates/crane_core/src/placement.rs
+
+use crate::crane_data::{CraneConfiguration, CrawlerCalculator};
+use crate::ground_bearing::{GroundBearingCalculator, GroundConfiguration, PadMaterial, SoilType};
+use crate::inverse_kinematics::{HookPose, IkSolution, IkSolver, LiftTarget};
+use crate::kinematics::check_clearance;
+use crate::rigging::Load;
+use nalgebra::{Point2, Point3, Vector3};
+
+/// Area the crane must not be set up on (excavations, buried services, roads)
+#[derive(Debug, Clone)]
+pub struct NoGoZone {
+    pub name: String,
+    pub polygon: Vec<Point2<f32>>,
+}
+
+/// Area with known ground conditions
+#[derive(Debug, Clone)]
+pub struct GroundZone {
+    pub name: String,
+    pub polygon: Vec<Point2<f32>>,
+    pub soil_type: SoilType,
+}
+
+/// Obstacle the load must clear, as an axis-aligned box
+#[derive(Debug, Clone)]
+pub struct SiteObstacle {
+    pub name: String,
+    /// Box centre
+    pub position: Point3<f32>,
+    pub dimensions: Vector3<f32>,
+}
+
+/// Site layout in plan (world X/Y)
+#[derive(Debug, Clone)]
+pub struct Site {
+    pub boundary: Vec<Point2<f32>>,
+    pub no_go_zones: Vec<NoGoZone>,
+    pub ground_zones: Vec<GroundZone>,
+    /// Soil outside every ground zone
+    pub default_soil: SoilType,
+    pub obstacles: Vec<SiteObstacle>,
+    /// Ground elevation for crane set-up
+    pub ground_elevation_m: f32,
+}
+
+impl Site {
+    pub fn new(boundary: Vec<Point2<f32>>, default_soil: SoilType) -> Self {
+        Self {
+            boundary,
+            no_go_zones: Vec::new(),
+            ground_zones: Vec::new(),
+            default_soil,
+            obstacles: Vec::new(),
+            ground_elevation_m: 0.0,
+        }
+    }
+
+    /// Soil at a plan point (last matching ground zone wins)
+    pub fn soil_at(&self, point: &Point2<f32>) -> SoilType {
+        self.ground_zones
+            .iter()
+            .rev()
+            .find(|z| point_in_polygon(point, &z.polygon))
+            .map_or(self.default_soil, |z| z.soil_type)
+    }
+
+    /// Whether a footprint lies inside the boundary and clear of every no-go zone
+    pub fn footprint_allowed(&self, footprint: &[Point2<f32>]) -> bool {
+        footprint
+            .iter()
+            .all(|p| point_in_polygon(p, &self.boundary))
+            && !self
+                .no_go_zones
+                .iter()
+                .any(|z| polygons_overlap(footprint, &z.polygon))
+    }
+}
+
+/// Search settings and acceptance limits
+#[derive(Debug, Clone)]
+pub struct PlacementOptions {
+    pub grid_spacing_m: f32,
+    pub heading_step_deg: f32,
+    /// Maximum capacity utilisation at pick and set
+    pub max_utilization_percent: f32,
+    /// Maximum ground bearing utilisation under any support
+    pub max_ground_utilization_percent: f32,
+    pub pad_diameter_m: f32,
+    pub pad_material: PadMaterial,
+    /// Factor applied on top of the soil's allowable bearing pressure
+    pub soil_safety_factor: f32,
+    /// Clearance the load must keep from obstacles
+    pub clearance_margin_m: f32,
+    /// Samples along the pick-to-set motion for the obstacle check
+    pub path_steps: usize,
+
+    /// Score weights (radius, capacity, ground) on values normalised to their limits
+    pub radius_weight: f32,
+    pub utilization_weight: f32,
+    pub ground_weight: f32,
+}
+
+impl Default for PlacementOptions {
+    fn default() -> Self {
+        Self {
+            grid_spacing_m: 2.0,
+            heading_step_deg: 45.0,
+            max_utilization_percent: 75.0,
+            max_ground_utilization_percent: 100.0,
+            pad_diameter_m: 1.0,
+            pad_material: PadMaterial::Composite,
+            soil_safety_factor: 1.0,
+            clearance_margin_m: 0.5,
+            path_steps: 24,
+            radius_weight: 0.5,
+            utilization_weight: 0.3,
+            ground_weight: 0.2,
+        }
+    }
+}
+
+/// Feasible crane set-up position
+#[derive(Debug, Clone)]
+pub struct PlacementCandidate {
+    pub position: Point3<f32>,
+    pub heading_deg: f32,
+    /// Lowest-utilisation setup from the IK solver at this position
+    pub solution: IkSolution,
+    /// Larger of the pick and set radii
+    pub max_radius_m: f32,
+    pub utilization_percent: f32,
+    /// Highest ground bearing utilisation (None for tower crane foundations)
+    pub ground_utilization_percent: Option<f32>,
+    /// Weighted score, lower is better
+    pub score: f32,
+}
+
+/// Best score per grid cell over all headings (None where nothing fits)
+#[derive(Debug, Clone)]
+pub struct PlacementHeatMap {
+    /// Centre of cell (0, 0)
+    pub origin: Point2<f32>,
+    pub cell_size_m: f32,
+    pub columns: usize,
+    pub rows: usize,
+    /// Row-major scores
+    pub scores: Vec<Option<f32>>,
+}
+
+impl PlacementHeatMap {
+    pub fn get(&self, column: usize, row: usize) -> Option<f32> {
+        if column >= self.columns || row >= self.rows {
+            return None;
+        }
+        self.scores[row * self.columns + column]
+    }
+
+    pub fn cell_center(&self, column: usize, row: usize) -> Point2<f32> {
+        Point2::new(
+            self.origin.x + column as f32 * self.cell_size_m,
+            self.origin.y + row as f32 * self.cell_size_m,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlacementResult {
+    /// Feasible positions, best first
+    pub candidates: Vec<PlacementCandidate>,
+    pub heat_map: PlacementHeatMap,
+}
+
+/// Searches crane positions and headings over a site
+pub struct PlacementOptimizer;
+
+impl PlacementOptimizer {
+    /// Grid search over the site for positions that reach the pick and set
+    ///
+    /// `config` supplies the crane, supports and counterweight; its position
+    /// and heading are replaced by each candidate.
+    pub fn optimize(
+        config: &CraneConfiguration,
+        site: &Site,
+        pick: &LiftTarget,
+        set: &LiftTarget,
+        load: &Load,
+        options: &PlacementOptions,
+    ) -> PlacementResult {
+        let spacing = options.grid_spacing_m.max(0.1);
+        let (min, max) = bounding_box(&site.boundary);
+        let columns = ((max.x - min.x) / spacing).floor() as usize + 1;
+        let rows = ((max.y - min.y) / spacing).floor() as usize + 1;
+        let heading_steps = (360.0 / options.heading_step_deg.max(1.0)).round().max(1.0) as usize;
+
+        let mut candidates = Vec::new();
+        let mut scores = vec![None; columns * rows];
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let plan = Point2::new(
+                    min.x + column as f32 * spacing,
+                    min.y + row as f32 * spacing,
+                );
+                if !point_in_polygon(&plan, &site.boundary) {
+                    continue;
+                }
+
+                // Skip positions out of reach of the pick or the set
+                let reach = config
+                    .spec
+                    .max_radius_m
+                    .max(config.spec.boom_length_range.1);
+                if (pick.location.xy() - plan).norm() > reach
+                    || (set.location.xy() - plan).norm() > reach
+                {
+                    continue;
+                }
+
+                for step in 0..heading_steps {
+                    let mut trial = config.clone();
+                    trial.position = Point3::new(plan.x, plan.y, site.ground_elevation_m);
+                    trial.heading_deg = step as f32 * 360.0 / heading_steps as f32;
+
+                    let Some(candidate) = Self::evaluate(&trial, site, pick, set, load, options)
+                    else {
+                        continue;
+                    };
+
+                    let cell = &mut scores[row * columns + column];
+                    if cell.is_none_or(|best: f32| candidate.score < best) {
+                        *cell = Some(candidate.score);
+                    }
+                    candidates.push(candidate);
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| a.score.total_cmp(&b.score));
+
+        PlacementResult {
+            candidates,
+            heat_map: PlacementHeatMap {
+                origin: min,
+                cell_size_m: spacing,
+                columns,
+                rows,
+                scores,
+            },
+        }
+    }
+
+    /// Check one position and heading against every constraint
+    pub fn evaluate(
+        config: &CraneConfiguration,
+        site: &Site,
+        pick: &LiftTarget,
+        set: &LiftTarget,
+        load: &Load,
+        options: &PlacementOptions,
+    ) -> Option<PlacementCandidate> {
+        if !site.footprint_allowed(&Self::footprint(config)) {
+            return None;
+        }
+
+        let solution = IkSolver::solve(config, pick, set, load.weight_kg)
+            .into_iter()
+            .find(|s| s.utilization_percent <= options.max_utilization_percent)?;
+
+        let mut ground_utilization: Option<f32> = None;
+        for pose in [&solution.pick, &solution.set] {
+            let mut posed = config.clone();
+            solution.apply(&mut posed, pose);
+
+            if let Some(utilization) = Self::ground_utilization(&posed, site, load, options) {
+                if utilization > options.max_ground_utilization_percent {
+                    return None;
+                }
+                ground_utilization =
+                    Some(ground_utilization.map_or(utilization, |u| u.max(utilization)));
+            }
+        }
+
+        if !Self::path_clear(config, &solution, site, load, options) {
+            return None;
+        }
+
+        let max_radius_m = solution.pick.radius_m.max(solution.set.radius_m);
+        let score = options.radius_weight * max_radius_m / config.spec.max_radius_m.max(1.0)
+            + options.utilization_weight * solution.utilization_percent
+                / options.max_utilization_percent
+            + options.ground_weight * ground_utilization.unwrap_or(0.0)
+                / options.max_ground_utilization_percent;
+
+        Some(PlacementCandidate {
+            position: config.position,
+            heading_deg: config.heading_deg,
+            utilization_percent: solution.utilization_percent,
+            solution,
+            max_radius_m,
+            ground_utilization_percent: ground_utilization,
+            score,
+        })
+    }
+
+    /// Plan footprint: outrigger pads, crawler tracks or the carrier body
+    pub fn footprint(config: &CraneConfiguration) -> Vec<Point2<f32>> {
+        let spec = &config.spec;
+
+        let local: Vec<(f32, f32)> = if let Some(crawler) = &spec.crawler {
+            let half_x = (crawler.track_gauge_m + crawler.track_shoe_width_m) / 2.0;
+            let half_y = crawler.track_length_m / 2.0;
+            vec![
+                (-half_x, -half_y),
+                (half_x, -half_y),
+                (half_x, half_y),
+                (-half_x, half_y),
+            ]
+        } else {
+            let contacts = config.outriggers.get_all_contact_points();
+            if spec.tower.is_none() && contacts.len() >= 3 {
+                // Pads in order around the carrier
+                let mut points: Vec<(f32, f32)> =
+                    contacts.iter().map(|(_, p)| (p.x, p.y)).collect();
+                points.sort_by(|a, b| a.1.atan2(a.0).total_cmp(&b.1.atan2(b.0)));
+                points
+            } else {
+                let half_x = spec.width_m / 2.0;
+                let half_y = spec.length_m / 2.0;
+                vec![
+                    (-half_x, -half_y),
+                    (half_x, -half_y),
+                    (half_x, half_y),
+                    (-half_x, half_y),
+                ]
+            }
+        };
+
+        let (sin_h, cos_h) = config.heading_deg.to_radians().sin_cos();
+        local
+            .into_iter()
+            .map(|(x, y)| {
+                Point2::new(
+                    config.position.x + x * cos_h + y * sin_h,
+                    config.position.y - x * sin_h + y * cos_h,
+                )
+            })
+            .collect()
+    }
+
+    /// Highest bearing utilisation under the crane's supports
+    fn ground_utilization(
+        config: &CraneConfiguration,
+        site: &Site,
+        load: &Load,
+        options: &PlacementOptions,
+    ) -> Option<f32> {
+        if config.spec.crawler.is_some() {
+            let pressure = CrawlerCalculator::ground_pressure(config, load.weight_kg).ok()?;
+            let allowable = site
+                .soil_at(&config.position.xy())
+                .allowable_bearing_capacity_kpa()
+                / options.soil_safety_factor;
+            return Some(pressure.max_pressure_kpa / allowable * 100.0);
+        }
+
+        if config.spec.tower.is_some() {
+            return None;
+        }
+
+        let supports = GroundBearingCalculator::outrigger_support_points(
+            config,
+            load.weight_kg,
+            options.pad_diameter_m,
+            options.pad_material,
+        )
+        .ok()?;
+
+        supports
+            .into_iter()
+            .filter_map(|support| {
+                let soil_type = site.soil_at(&support.position.xy());
+                GroundBearingCalculator::analyze(&GroundConfiguration {
+                    support_points: vec![support],
+                    soil_type,
+                    safety_factor: options.soil_safety_factor,
+                })
+                .ok()
+            })
+            .flat_map(|a| a.bearing_pressures)
+            .map(|p| p.utilization_percent)
+            .reduce(f32::max)
+    }
+
+    /// Whether the load clears every obstacle moving from pick to set
+    fn path_clear(
+        config: &CraneConfiguration,
+        solution: &IkSolution,
+        site: &Site,
+        load: &Load,
+        options: &PlacementOptions,
+    ) -> bool {
+        if site.obstacles.is_empty() {
+            return true;
+        }
+
+        let steps = options.path_steps.max(2);
+        let swing_delta = crate::kinematics::shortest_swing_delta(
+            solution.pick.swing_angle_deg,
+            solution.set.swing_angle_deg,
+        );
+
+        let mut posed = config.clone();
+        let hook_path: Vec<Point3<f32>> = (0..steps)
+            .map(|i| {
+                let t = i as f32 / (steps - 1) as f32;
+                let lerp = |a: f32, b: f32| a + (b - a) * t;
+                let pose = HookPose {
+                    boom_angle_deg: lerp(solution.pick.boom_angle_deg, solution.set.boom_angle_deg),
+                    swing_angle_deg: solution.pick.swing_angle_deg + swing_delta * t,
+                    hoist_length_m: lerp(solution.pick.hoist_length_m, solution.set.hoist_length_m),
+                    trolley_radius_m: solution
+                        .pick
+                        .trolley_radius_m
+                        .zip(solution.set.trolley_radius_m)
+                        .map(|(a, b)| lerp(a, b)),
+                    ..solution.pick
+                };
+                solution.apply(&mut posed, &pose);
+                posed.get_hook_position()
+            })
+            .collect();
+
+        site.obstacles.iter().all(|obstacle| {
+            check_clearance(
+                &hook_path,
+                load.dimensions,
+                obstacle.position,
+                obstacle.dimensions,
+                options.clearance_margin_m,
+            )
+        })
+    }
+}
+
+fn bounding_box(polygon: &[Point2<f32>]) -> (Point2<f32>, Point2<f32>) {
+    polygon.iter().fold(
+        (
+            Point2::new(f32::MAX, f32::MAX),
+            Point2::new(f32::MIN, f32::MIN),
+        ),
+        |(min, max), p| {
+            (
+                Point2::new(min.x.min(p.x), min.y.min(p.y)),
+                Point2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        },
+    )
+}
+
+/// Even-odd point in polygon test
+pub fn point_in_polygon(point: &Point2<f32>, polygon: &[Point2<f32>]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[j]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+/// Whether two simple polygons overlap (edge crossing or containment)
+pub fn polygons_overlap(a: &[Point2<f32>], b: &[Point2<f32>]) -> bool {
+    if a.iter().any(|p| point_in_polygon(p, b)) || b.iter().any(|p| point_in_polygon(p, a)) {
+        return true;
+    }
+
+    let edges = |poly: &[Point2<f32>]| {
+        (0..poly.len())
+            .map(|i| (poly[i], poly[(i + 1) % poly.len()]))
+            .collect::<Vec<_>>()
+    };
+
+    let cross = |o: Point2<f32>, p: Point2<f32>, q: Point2<f32>| {
+        (p.x - o.x) * (q.y - o.y) - (p.y - o.y) * (q.x - o.x)
+    };
+
+    edges(a).iter().any(|&(p1, p2)| {
+        edges(b).iter().any(|&(q1, q2)| {
+            cross(p1, p2, q1) * cross(p1, p2, q2) < 0.0
+                && cross(q1, q2, p1) * cross(q1, q2, p2) < 0.0
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::test_crane;
+
+    fn rect(x0: f32, y0: f32, x1: f32, y1: f32) -> Vec<Point2<f32>> {
+        vec![
+            Point2::new(x0, y0),
+            Point2::new(x1, y0),
+            Point2::new(x1, y1),
+            Point2::new(x0, y1),
+        ]
+    }
+
+    fn setup() -> (CraneConfiguration, Site, LiftTarget, LiftTarget, Load) {
+        let config = test_crane(30.0, 60.0);
+
+        let site = Site::new(rect(0.0, 0.0, 40.0, 30.0), SoilType::DenseGravel);
+        let pick = LiftTarget::above(Point3::new(5.0, 15.0, 0.0), 4.0);
+        let set = LiftTarget::above(Point3::new(35.0, 15.0, 0.0), 6.0);
+        let load = Load {
+            weight_kg: 5_000.0,
+            center_of_gravity: Point3::new(0.0, 0.0, 0.5),
+            dimensions: Vector3::new(2.0, 2.0, 1.0),
+            pick_points: Vec::new(),
+        };
+
+        (config, site, pick, set, load)
+    }
+
+    fn options() -> PlacementOptions {
+        PlacementOptions {
+            grid_spacing_m: 5.0,
+            heading_step_deg: 90.0,
+            pad_diameter_m: 1.5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_candidates_ranked_inside_site() {
+        let (config, site, pick, set, load) = setup();
+        let result = PlacementOptimizer::optimize(&config, &site, &pick, &set, &load, &options());
+
+        assert!(!result.candidates.is_empty());
+        assert!(
+            result
+                .candidates
+                .windows(2)
+                .all(|w| w[0].score <= w[1].score)
+        );
+        for candidate in &result.candidates {
+            let mut placed = config.clone();
+            placed.position = candidate.position;
+            placed.heading_deg = candidate.heading_deg;
+            assert!(site.footprint_allowed(&PlacementOptimizer::footprint(&placed)));
+            assert!(candidate.utilization_percent <= 75.0);
+        }
+
+        assert_eq!(result.heat_map.columns, 9);
+        assert_eq!(result.heat_map.rows, 7);
+        assert!(result.heat_map.scores.iter().any(|s| s.is_some()));
+    }
+
+    #[test]
+    fn test_no_go_zone_excludes_positions() {
+        let (config, mut site, pick, set, load) = setup();
+        site.no_go_zones.push(NoGoZone {
+            name: "Excavation".to_string(),
+            polygon: rect(10.0, 5.0, 30.0, 25.0),
+        });
+
+        let result = PlacementOptimizer::optimize(&config, &site, &pick, &set, &load, &options());
+        let zone = &site.no_go_zones[0].polygon;
+        for candidate in &result.candidates {
+            assert!(!point_in_polygon(&candidate.position.xy(), zone));
+        }
+
+        // Whole site fenced off
+        site.no_go_zones.push(NoGoZone {
+            name: "Everything".to_string(),
+            polygon: rect(-1.0, -1.0, 41.0, 31.0),
+        });
+        let result = PlacementOptimizer::optimize(&config, &site, &pick, &set, &load, &options());
+        assert!(result.candidates.is_empty());
+    }
+
+    #[test]
+    fn test_polygon_helpers() {
+        let square = rect(0.0, 0.0, 10.0, 10.0);
+        assert!(point_in_polygon(&Point2::new(5.0, 5.0), &square));
+        assert!(!point_in_polygon(&Point2::new(15.0, 5.0), &square));
+
+        // Crossing bars: no vertex inside either, edges intersect
+        let bar_a = rect(-5.0, 4.0, 15.0, 6.0);
+        assert!(polygons_overlap(&square, &bar_a));
+        assert!(!polygons_overlap(&square, &rect(20.0, 0.0, 30.0, 10.0)));
+    }
+}
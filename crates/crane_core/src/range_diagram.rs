@@ -0,0 +1,656 @@
+// crates/crane_core/src/range_diagram.rs
+
+use nalgebra::Point2;
+use std::fmt::Write;
+
+use crate::crane_data::{CraneConfiguration, CraneSpec, JibState, JibType, Quantity, UnitSystem};
+
+/// What a range diagram curve traces
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeCurveKind {
+    /// Boom tip over the boom angle range at one boom length
+    BoomLength { boom_length_m: f32 },
+    /// Boom tip over the boom length range at one boom angle
+    BoomAngle { boom_angle_deg: f32 },
+    /// Jib tip over the boom angle range on the longest boom
+    Jib {
+        jib_id: String,
+        boom_length_m: f32,
+        jib_length_m: f32,
+        offset_deg: f32,
+    },
+    /// Trolley travel along a horizontal tower crane jib
+    Trolley { jib_length_m: f32 },
+}
+
+/// One curve of a range diagram, as (radius, height above ground) points
+#[derive(Debug, Clone)]
+pub struct RangeCurve {
+    pub kind: RangeCurveKind,
+    /// Tip the hoist line runs over
+    pub tip: Vec<Point2<f32>>,
+    /// Highest hook position below the tip (tip less the hook clearance)
+    pub hook: Vec<Point2<f32>>,
+}
+
+impl RangeCurve {
+    pub fn label(&self, units: UnitSystem) -> String {
+        match &self.kind {
+            RangeCurveKind::BoomLength { boom_length_m } => units.length(*boom_length_m),
+            RangeCurveKind::BoomAngle { boom_angle_deg } => format!("{:.0}°", boom_angle_deg),
+            RangeCurveKind::Jib {
+                jib_length_m,
+                offset_deg,
+                ..
+            } => format!("jib {} {:.0}°", units.length(*jib_length_m), offset_deg),
+            RangeCurveKind::Trolley { jib_length_m } => {
+                format!("jib {}", units.length(*jib_length_m))
+            }
+        }
+    }
+}
+
+/// Sampling settings for a range diagram
+#[derive(Debug, Clone)]
+pub struct RangeDiagramOptions {
+    /// Boom lengths to draw (empty = the chart's boom lengths)
+    pub boom_lengths_m: Vec<f32>,
+    pub angle_step_deg: f32,
+    /// Spacing of the constant boom angle lines
+    pub angle_line_step_deg: f32,
+    /// Hook block height plus two-block margin below the tip
+    pub hook_clearance_m: f32,
+    pub include_jibs: bool,
+    /// Radius bins for the capacity envelope
+    pub envelope_radius_step_m: f32,
+    /// Boom lengths sampled for the capacity envelope
+    pub envelope_boom_steps: usize,
+}
+
+impl Default for RangeDiagramOptions {
+    fn default() -> Self {
+        Self {
+            boom_lengths_m: Vec::new(),
+            angle_step_deg: 1.0,
+            angle_line_step_deg: 15.0,
+            hook_clearance_m: 2.5,
+            include_jibs: true,
+            envelope_radius_step_m: 0.5,
+            envelope_boom_steps: 24,
+        }
+    }
+}
+
+/// Working range of a crane, optionally with a configuration and load
+#[derive(Debug, Clone)]
+pub struct RangeDiagram {
+    pub crane_model: String,
+    pub curves: Vec<RangeCurve>,
+    /// Current hook position (radius, height)
+    pub hook: Option<Point2<f32>>,
+    /// Load the capacity envelope is drawn for
+    pub load_kg: Option<f32>,
+    /// Highest hook position at each radius where the load is within
+    /// capacity, as runs of (radius, height) broken where it is not
+    pub capacity_envelope: Vec<Vec<Point2<f32>>>,
+}
+
+impl RangeDiagram {
+    /// Largest radius and height on the diagram
+    pub fn extent(&self) -> (f32, f32) {
+        self.curves
+            .iter()
+            .flat_map(|c| &c.tip)
+            .chain(self.hook.iter())
+            .fold((0.0, 0.0), |(r, h), p| (p.x.max(r), p.y.max(h)))
+    }
+
+    /// Highest hook height at `radius_m` that still carries the load
+    pub fn max_hook_height_at(&self, radius_m: f32) -> Option<f32> {
+        self.capacity_envelope.iter().find_map(|run| {
+            let first = run.first()?;
+            let last = run.last()?;
+            if radius_m < first.x || radius_m > last.x {
+                return None;
+            }
+            run.windows(2)
+                .find(|w| radius_m <= w[1].x)
+                .map(|w| {
+                    let t = (radius_m - w[0].x) / (w[1].x - w[0].x).max(1e-6);
+                    w[0].y + (w[1].y - w[0].y) * t
+                })
+                .or(Some(first.y))
+        })
+    }
+
+    /// Whether the hook sits where the load is within capacity
+    pub fn hook_in_envelope(&self) -> bool {
+        self.hook.is_some_and(|hook| {
+            self.max_hook_height_at(hook.x)
+                .is_some_and(|height| hook.y <= height + 1e-3)
+        })
+    }
+
+    /// Draw the diagram as an SVG document, to scale, in `units`
+    pub fn to_svg(&self, units: UnitSystem) -> String {
+        const WIDTH: f32 = 800.0;
+        const HEIGHT: f32 = 600.0;
+        const MARGIN: f32 = 60.0;
+
+        let (max_radius, max_height) = self.extent();
+        let max_radius = max_radius.max(1.0) * 1.05;
+        let max_height = max_height.max(1.0) * 1.05;
+        let scale = ((WIDTH - 2.0 * MARGIN) / max_radius).min((HEIGHT - 2.0 * MARGIN) / max_height);
+        let x = |radius: f32| MARGIN + radius * scale;
+        let y = |height: f32| HEIGHT - MARGIN - height * scale;
+        let path = |points: &[Point2<f32>]| {
+            points
+                .iter()
+                .map(|p| format!("{:.1},{:.1}", x(p.x), y(p.y)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="11">"#,
+            w = WIDTH,
+            h = HEIGHT
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="20" font-size="14">{} working range</text>"#,
+            MARGIN, self.crane_model
+        );
+
+        // Grid in display units
+        let unit = units.unit(Quantity::Length);
+        let step = grid_step(unit.from_metric(max_radius.max(max_height)) / 10.0);
+        let mut value = 0.0;
+        while unit.to_metric(value) <= max_radius {
+            let px = x(unit.to_metric(value));
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{px:.1}" y1="{:.1}" x2="{px:.1}" y2="{:.1}" stroke="#ddd"/><text x="{px:.1}" y="{:.1}" text-anchor="middle">{}</text>"##,
+                y(0.0),
+                y(max_height),
+                y(0.0) + 15.0,
+                value
+            );
+            value += step;
+        }
+        let mut value = 0.0;
+        while unit.to_metric(value) <= max_height {
+            let py = y(unit.to_metric(value));
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{:.1}" y1="{py:.1}" x2="{:.1}" y2="{py:.1}" stroke="#ddd"/><text x="{:.1}" y="{:.1}" text-anchor="end">{}</text>"##,
+                x(0.0),
+                x(max_radius),
+                x(0.0) - 5.0,
+                py + 4.0,
+                value
+            );
+            value += step;
+        }
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle">Radius ({sym})</text><text x="15" y="{:.1}" transform="rotate(-90 15 {:.1})" text-anchor="middle">Height ({sym})</text>"#,
+            x(max_radius / 2.0),
+            HEIGHT - 20.0,
+            y(max_height / 2.0),
+            y(max_height / 2.0),
+            sym = unit.symbol()
+        );
+
+        for run in &self.capacity_envelope {
+            let (Some(first), Some(last)) = (run.first(), run.last()) else {
+                continue;
+            };
+            let mut outline = run.clone();
+            outline.push(Point2::new(last.x, 0.0));
+            outline.push(Point2::new(first.x, 0.0));
+            let _ = writeln!(
+                svg,
+                r##"<polygon points="{}" fill="#2e9d4b" fill-opacity="0.25" stroke="none"/>"##,
+                path(&outline)
+            );
+        }
+
+        for curve in &self.curves {
+            let (color, dash) = match curve.kind {
+                RangeCurveKind::BoomLength { .. } | RangeCurveKind::Trolley { .. } => {
+                    ("#1f5fa8", "")
+                }
+                RangeCurveKind::BoomAngle { .. } => ("#888", r#" stroke-dasharray="4 3""#),
+                RangeCurveKind::Jib { .. } => ("#d9822b", ""),
+            };
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="1.5"{}/>"#,
+                path(&curve.tip),
+                color,
+                dash
+            );
+            if !matches!(curve.kind, RangeCurveKind::BoomAngle { .. }) && !curve.hook.is_empty() {
+                let _ = writeln!(
+                    svg,
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="0.75" stroke-dasharray="2 2"/>"#,
+                    path(&curve.hook),
+                    color
+                );
+            }
+            if let Some(end) = curve.tip.iter().max_by(|a, b| a.y.total_cmp(&b.y)) {
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{:.1}" y="{:.1}" fill="{}">{}</text>"#,
+                    x(end.x) + 3.0,
+                    y(end.y) - 3.0,
+                    color,
+                    curve.label(units)
+                );
+            }
+        }
+
+        if let Some(hook) = self.hook {
+            let color = if self.hook_in_envelope() {
+                "#2e9d4b"
+            } else {
+                "#d62728"
+            };
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{:.1}" cy="{:.1}" r="5" fill="{}"/><text x="{:.1}" y="{:.1}">hook {} / {}</text>"#,
+                x(hook.x),
+                y(hook.y),
+                color,
+                x(hook.x) + 8.0,
+                y(hook.y) + 4.0,
+                units.length(hook.x),
+                units.length(hook.y)
+            );
+        }
+        if let Some(load_kg) = self.load_kg {
+            let _ = writeln!(
+                svg,
+                r#"<text x="{:.1}" y="20" text-anchor="end">Shaded: within capacity for {}</text>"#,
+                WIDTH - MARGIN,
+                units.mass(load_kg)
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Builds range diagrams from crane geometry and charts
+pub struct RangeDiagramGenerator;
+
+impl RangeDiagramGenerator {
+    /// Boom length, boom angle and jib curves for a spec
+    ///
+    /// Heights are above the ground at the slew centre, with the crane
+    /// level and the boom unloaded.
+    pub fn generate(spec: &CraneSpec, options: &RangeDiagramOptions) -> RangeDiagram {
+        let base = CraneConfiguration::new(spec.clone());
+        let mut curves = Vec::new();
+
+        let boom_lengths = Self::boom_lengths(spec, options);
+        if let Some(min_trolley) = spec.tower.as_ref().map(|t| t.min_trolley_radius_m)
+            && base.trolley_radius_m.is_some()
+        {
+            for length in boom_lengths {
+                let mut config = base.clone();
+                config.boom_length_m = length;
+                let tip = steps(min_trolley, length, options.envelope_radius_step_m.max(0.5))
+                    .into_iter()
+                    .map(|radius| {
+                        config.trolley_radius_m = Some(radius);
+                        sheave(&config)
+                    })
+                    .collect();
+                curves.push(Self::curve(
+                    RangeCurveKind::Trolley {
+                        jib_length_m: length,
+                    },
+                    tip,
+                    options,
+                ));
+            }
+            return RangeDiagram {
+                crane_model: spec.model.clone(),
+                curves,
+                hook: None,
+                load_kg: None,
+                capacity_envelope: Vec::new(),
+            };
+        }
+
+        let angles = steps(
+            spec.min_boom_angle_deg,
+            spec.max_boom_angle_deg,
+            options.angle_step_deg,
+        );
+        let sweep = |config: &mut CraneConfiguration| -> Vec<Point2<f32>> {
+            angles
+                .iter()
+                .map(|angle| {
+                    config.boom_angle_deg = *angle;
+                    sheave(config)
+                })
+                .collect()
+        };
+
+        for length in &boom_lengths {
+            let mut config = base.clone();
+            config.boom_length_m = *length;
+            let tip = sweep(&mut config);
+            curves.push(Self::curve(
+                RangeCurveKind::BoomLength {
+                    boom_length_m: *length,
+                },
+                tip,
+                options,
+            ));
+        }
+
+        let (min_length, max_length) = spec.boom_length_range;
+        let mut line_angles = steps(
+            spec.min_boom_angle_deg,
+            spec.max_boom_angle_deg,
+            options.angle_line_step_deg,
+        );
+        line_angles.dedup_by(|a, b| (*a - *b).abs() < 1e-3);
+        for angle in line_angles {
+            let mut config = base.clone();
+            config.boom_angle_deg = angle;
+            let tip = steps(min_length, max_length, (max_length - min_length) / 8.0)
+                .into_iter()
+                .map(|length| {
+                    config.boom_length_m = length;
+                    sheave(&config)
+                })
+                .collect();
+            curves.push(Self::curve(
+                RangeCurveKind::BoomAngle {
+                    boom_angle_deg: angle,
+                },
+                tip,
+                options,
+            ));
+        }
+
+        if options.include_jibs {
+            for jib in &spec.jibs {
+                let offsets = match jib.jib_type {
+                    JibType::FlyJib => jib.offsets_deg.clone(),
+                    JibType::LuffingJib => jib
+                        .offset_range_deg
+                        .map(|(min, max)| vec![min, max])
+                        .unwrap_or_default(),
+                };
+                for length in &jib.lengths_m {
+                    for offset in &offsets {
+                        let mut config = base.clone();
+                        config.boom_length_m = max_length;
+                        config.jib = Some(JibState {
+                            jib_id: jib.id.clone(),
+                            length_m: *length,
+                            offset_deg: *offset,
+                        });
+                        let tip = sweep(&mut config);
+                        curves.push(Self::curve(
+                            RangeCurveKind::Jib {
+                                jib_id: jib.id.clone(),
+                                boom_length_m: max_length,
+                                jib_length_m: *length,
+                                offset_deg: *offset,
+                            },
+                            tip,
+                            options,
+                        ));
+                    }
+                }
+            }
+        }
+
+        RangeDiagram {
+            crane_model: spec.model.clone(),
+            curves,
+            hook: None,
+            load_kg: None,
+            capacity_envelope: Vec::new(),
+        }
+    }
+
+    /// Range diagram for a configuration, with its hook position and the
+    /// region where `load_kg` is within capacity
+    ///
+    /// The envelope keeps the configuration's supports, counterweight,
+    /// slew and jib, and varies boom length and angle.
+    pub fn for_configuration(
+        config: &CraneConfiguration,
+        load_kg: f32,
+        options: &RangeDiagramOptions,
+    ) -> RangeDiagram {
+        let mut diagram = Self::generate(&config.spec, options);
+        let hook = config.get_hook_position();
+        diagram.hook = Some(Point2::new(config.get_radius(), hook.z - config.position.z));
+        diagram.load_kg = Some(load_kg);
+
+        let spec = &config.spec;
+        let bin = options.envelope_radius_step_m.max(0.05);
+        let mut heights: Vec<Option<f32>> = Vec::new();
+        let mut record = |sample: Point2<f32>, sampled: &CraneConfiguration| {
+            if sampled
+                .get_capacity_at_radius(sample.x)
+                .is_none_or(|capacity| capacity < load_kg)
+            {
+                return;
+            }
+            let index = (sample.x / bin).round() as usize;
+            if heights.len() <= index {
+                heights.resize(index + 1, None);
+            }
+            let height = (sample.y - options.hook_clearance_m).max(0.0);
+            heights[index] = Some(heights[index].map_or(height, |h: f32| h.max(height)));
+        };
+
+        let (min_length, max_length) = spec.boom_length_range;
+        let length_step = (max_length - min_length) / options.envelope_boom_steps.max(1) as f32;
+        let mut sampled = config.clone();
+        match (&spec.tower, config.trolley_radius_m) {
+            (Some(tower), Some(_)) => {
+                for radius in steps(tower.min_trolley_radius_m, config.boom_length_m, bin) {
+                    sampled.trolley_radius_m = Some(radius);
+                    record(sheave(&sampled), &sampled);
+                }
+            }
+            _ => {
+                for length in steps(min_length, max_length, length_step) {
+                    sampled.boom_length_m = length;
+                    for angle in steps(
+                        spec.min_boom_angle_deg,
+                        spec.max_boom_angle_deg,
+                        options.angle_step_deg * 0.5,
+                    ) {
+                        sampled.boom_angle_deg = angle;
+                        record(sheave(&sampled), &sampled);
+                    }
+                }
+            }
+        }
+
+        let mut run = Vec::new();
+        for (index, height) in heights.into_iter().enumerate() {
+            match height {
+                Some(height) => run.push(Point2::new(index as f32 * bin, height)),
+                None if !run.is_empty() => diagram.capacity_envelope.push(std::mem::take(&mut run)),
+                None => {}
+            }
+        }
+        if !run.is_empty() {
+            diagram.capacity_envelope.push(run);
+        }
+
+        diagram
+    }
+
+    fn boom_lengths(spec: &CraneSpec, options: &RangeDiagramOptions) -> Vec<f32> {
+        if !options.boom_lengths_m.is_empty() {
+            return options.boom_lengths_m.clone();
+        }
+
+        let (min, max) = spec.boom_length_range;
+        let mut lengths: Vec<f32> = spec
+            .capacity_chart
+            .charts
+            .values()
+            .map(|c| c.boom_length_m)
+            .filter(|l| (min - 0.05..=max + 0.05).contains(l))
+            .collect();
+        lengths.sort_by(f32::total_cmp);
+        lengths.dedup_by(|a, b| (*a - *b).abs() < 0.05);
+        if lengths.is_empty() {
+            lengths = steps(min, max, (max - min) / 4.0);
+        }
+        lengths
+    }
+
+    fn curve(
+        kind: RangeCurveKind,
+        tip: Vec<Point2<f32>>,
+        options: &RangeDiagramOptions,
+    ) -> RangeCurve {
+        let hook = tip
+            .iter()
+            .map(|p| Point2::new(p.x, p.y - options.hook_clearance_m))
+            .filter(|p| p.y >= 0.0)
+            .collect();
+        RangeCurve { kind, tip, hook }
+    }
+}
+
+/// Radius and height of the sheave the hoist line runs over
+fn sheave(config: &CraneConfiguration) -> Point2<f32> {
+    let sheave = config.get_sheave_position();
+    Point2::new(config.get_radius(), sheave.z - config.position.z)
+}
+
+/// Evenly spaced values from `min` to `max` inclusive, at most `step` apart
+fn steps(min: f32, max: f32, step: f32) -> Vec<f32> {
+    if max <= min || step <= 0.0 {
+        return vec![min];
+    }
+    let count = ((max - min) / step).ceil() as usize;
+    (0..=count)
+        .map(|i| min + (max - min) * i as f32 / count as f32)
+        .collect()
+}
+
+/// 1, 2 or 5 times a power of ten, at least `rough`
+fn grid_step(rough: f64) -> f64 {
+    let power = 10f64.powf(rough.max(1e-3).log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|m| m * power)
+        .find(|step| *step >= rough)
+        .unwrap_or(10.0 * power)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{test_crane, test_spec};
+
+    #[test]
+    fn test_boom_length_curves() {
+        let spec = test_spec("liebherr_ltm_1100_5_2");
+        let diagram = RangeDiagramGenerator::generate(&spec, &RangeDiagramOptions::default());
+
+        let longest = diagram
+            .curves
+            .iter()
+            .filter_map(|c| match c.kind {
+                RangeCurveKind::BoomLength { boom_length_m } => Some((boom_length_m, c)),
+                _ => None,
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap();
+        let (length, curve) = longest;
+
+        // Flat boom reaches its length; steepest boom is highest
+        let flat = curve.tip.first().unwrap();
+        assert!((flat.x - length).abs() < 1e-3);
+        assert!((flat.y - spec.boom_pivot_height_m).abs() < 1e-3);
+        let top = curve.tip.last().unwrap();
+        let expected =
+            spec.boom_pivot_height_m + length * spec.max_boom_angle_deg.to_radians().sin();
+        assert!((top.y - expected).abs() < 1e-3);
+        assert!(curve.hook.iter().all(|p| p.y >= 0.0));
+
+        assert!(
+            diagram
+                .curves
+                .iter()
+                .any(|c| matches!(c.kind, RangeCurveKind::BoomAngle { .. }))
+        );
+
+        // The fly jib reaches beyond the bare boom
+        let (max_radius, _) = diagram.extent();
+        let jib_reach = diagram
+            .curves
+            .iter()
+            .filter(|c| matches!(c.kind, RangeCurveKind::Jib { .. }))
+            .flat_map(|c| &c.tip)
+            .map(|p| p.x)
+            .fold(0.0, f32::max);
+        assert!(jib_reach > length);
+        assert_eq!(jib_reach, max_radius);
+    }
+
+    #[test]
+    fn test_capacity_envelope() {
+        let mut config = test_crane(30.0, 70.0);
+        config.hoist_length_m = 10.0;
+
+        let options = RangeDiagramOptions::default();
+        let light = RangeDiagramGenerator::for_configuration(&config, 5_000.0, &options);
+        let heavy = RangeDiagramGenerator::for_configuration(&config, 60_000.0, &options);
+
+        let reach = |d: &RangeDiagram| {
+            d.capacity_envelope
+                .iter()
+                .flatten()
+                .map(|p| p.x)
+                .fold(0.0, f32::max)
+        };
+        assert!(reach(&light) > reach(&heavy));
+        assert!(light.hook_in_envelope());
+
+        // Nothing lifts the load beyond the chart
+        let far = RangeDiagramGenerator::for_configuration(&config, 500_000.0, &options);
+        assert!(far.capacity_envelope.is_empty());
+        assert!(!far.hook_in_envelope());
+
+        let svg = light.to_svg(UnitSystem::Imperial);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Radius (ft)"));
+        assert!(svg.contains("<polygon"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_tower_trolley_range() {
+        let spec = test_spec("liebherr_280_ec_h_12");
+        let diagram = RangeDiagramGenerator::generate(&spec, &RangeDiagramOptions::default());
+        assert!(!diagram.curves.is_empty());
+        for curve in &diagram.curves {
+            assert!(matches!(curve.kind, RangeCurveKind::Trolley { .. }));
+            let height = curve.tip[0].y;
+            assert!(curve.tip.iter().all(|p| (p.y - height).abs() < 1e-3));
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use core::f32;
 
+use crate::composite_load::CogEnvelope;
 use nalgebra as na;
 use nalgebra::{Matrix3, Point3, Vector3};
 use serde::{Deserialize, Serialize};
@@ -102,7 +103,7 @@ pub enum HardwareType {
 }
 
 /// The load being lifted
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Load {
     pub weight_kg: f32,
     /// Center of gravity relative to load origin (0, 0, 0)
@@ -113,7 +114,7 @@ pub struct Load {
     pub pick_points: Vec<PickPoint>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PickPoint {
     pub id: String,
     pub position: Point3<f32>,
@@ -151,6 +152,42 @@ pub struct SlingTensionAnalysis {
     pub is_safe: bool,
 }
 
+/// Worst-case rigging results over a CoG uncertainty envelope
+#[derive(Debug, Clone)]
+pub struct CogEnvelopeAnalysis {
+    pub envelope: CogEnvelope,
+    pub samples_evaluated: usize,
+    /// Worst case for each sling over all sampled CoG positions
+    pub worst_sling_tensions: Vec<WorstCaseSling>,
+    /// Largest tilt of the load (degrees from level)
+    pub max_tilt_deg: f32,
+    /// CoG position producing the largest tilt
+    pub max_tilt_cog: Point3<f32>,
+    /// Worst case for each piece of rigging hardware
+    pub hardware_utilization: Vec<HardwareUtilization>,
+    pub max_utilization_percent: f32,
+    pub is_safe: bool,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorstCaseSling {
+    pub analysis: SlingTensionAnalysis,
+    /// CoG position producing this sling's highest tension
+    pub cog: Point3<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HardwareUtilization {
+    pub hardware_index: usize,
+    pub hardware_type: HardwareType,
+    pub load_kg: f32,
+    pub rated_capacity_kg: f32,
+    pub utilization_percent: f32,
+    /// CoG position producing the highest hardware load
+    pub cog: Point3<f32>,
+}
+
 #[derive(Debug)]
 pub struct SpreaderBeamAnalysis {
     pub max_bending_moment_nm: f32,
@@ -528,7 +565,7 @@ impl RiggingCalculator {
     }
 
     /// Estimate sling weight based on spec
-    fn estimate_sling_weight(spec: &SlingSpec) -> f32 {
+    pub(crate) fn estimate_sling_weight(spec: &SlingSpec) -> f32 {
         match &spec.material {
             SlingMaterial::WireRope { .. } => {
                 // Approximate: 1m of wire rope weighs ~0.5kg per mm of diameter
@@ -629,6 +666,223 @@ impl RiggingCalculator {
         warnings
     }
 
+    /// Analyze rigging over a CoG uncertainty envelope
+    ///
+    /// The CoG is sampled at the envelope corners, its centre and an interior
+    /// grid with `interior_divisions` cells per axis. Sling loads are found from
+    /// force and moment equilibrium about each sampled CoG, so unlike `analyze`
+    /// the result follows the CoG for any number of slings.
+    pub fn analyze_cog_envelope(
+        config: &RiggingConfiguration,
+        envelope: &CogEnvelope,
+        interior_divisions: usize,
+    ) -> Result<CogEnvelopeAnalysis, RiggingError> {
+        let slings = &config.slings;
+        if slings.is_empty() {
+            return Err(RiggingError::InsufficientPickPoints);
+        }
+
+        let samples = Self::sample_cog_envelope(envelope, interior_divisions);
+        let rigging_weight = Self::calculate_rigging_weight(slings, &config.hardware);
+        let attachment_points: Vec<Point3<f32>> =
+            slings.iter().map(|s| s.attachment_point).collect();
+
+        let mut worst_slings: Vec<Option<WorstCaseSling>> = vec![None; slings.len()];
+        let mut worst_hardware: Vec<Option<HardwareUtilization>> =
+            vec![None; config.hardware.len()];
+        let mut max_tilt_deg = 0.0;
+        let mut max_tilt_cog = envelope.center();
+        let mut slack_cogs = Vec::new();
+
+        for cog in &samples {
+            let reactions = pick_point_reactions(&attachment_points, *cog, config.load.weight_kg)?;
+
+            if reactions.iter().any(|&r| r < 0.0) {
+                slack_cogs.push(*cog);
+            }
+
+            let mut max_tension = 0.0_f32;
+            for (i, (sling, reaction)) in slings.iter().zip(reactions.iter()).enumerate() {
+                // Sling carries the vertical reaction along its own line
+                let angle_rad = Self::calculate_sling_angle(sling).to_radians();
+                let tension_kg = reaction.max(0.0) / angle_rad.cos().max(0.01);
+                max_tension = max_tension.max(tension_kg);
+
+                let analysis = Self::analyze_single_sling(sling, tension_kg)?;
+                let is_worse = worst_slings[i]
+                    .as_ref()
+                    .is_none_or(|w| analysis.tension_kg > w.analysis.tension_kg);
+                if is_worse {
+                    worst_slings[i] = Some(WorstCaseSling {
+                        analysis,
+                        cog: *cog,
+                    });
+                }
+            }
+
+            let tilt_deg = Self::hanging_tilt_deg(config.crane_hook_position, *cog);
+            if tilt_deg > max_tilt_deg {
+                max_tilt_deg = tilt_deg;
+                max_tilt_cog = *cog;
+            }
+
+            for (i, hardware) in config.hardware.iter().enumerate() {
+                // Shackles sit at a sling end, everything else carries the full hook load
+                let load_kg = match hardware.hardware_type {
+                    HardwareType::Shackle { .. } => max_tension,
+                    _ => config.load.weight_kg + rigging_weight,
+                };
+                let utilization_percent = if hardware.rated_capacity_kg > 0.0 {
+                    load_kg / hardware.rated_capacity_kg * 100.0
+                } else {
+                    f32::INFINITY
+                };
+
+                let is_worse = worst_hardware[i]
+                    .as_ref()
+                    .is_none_or(|w| utilization_percent > w.utilization_percent);
+                if is_worse {
+                    worst_hardware[i] = Some(HardwareUtilization {
+                        hardware_index: i,
+                        hardware_type: hardware.hardware_type.clone(),
+                        load_kg,
+                        rated_capacity_kg: hardware.rated_capacity_kg,
+                        utilization_percent,
+                        cog: *cog,
+                    });
+                }
+            }
+        }
+
+        let worst_sling_tensions: Vec<WorstCaseSling> =
+            worst_slings.into_iter().flatten().collect();
+        let hardware_utilization: Vec<HardwareUtilization> =
+            worst_hardware.into_iter().flatten().collect();
+
+        let max_utilization_percent = worst_sling_tensions
+            .iter()
+            .map(|w| w.analysis.utilization_percent)
+            .chain(hardware_utilization.iter().map(|h| h.utilization_percent))
+            .fold(0.0, f32::max);
+
+        let warnings = Self::generate_envelope_warnings(
+            &worst_sling_tensions,
+            &hardware_utilization,
+            max_tilt_deg,
+            &slack_cogs,
+        );
+
+        let is_safe = slack_cogs.is_empty()
+            && worst_sling_tensions.iter().all(|w| w.analysis.is_safe)
+            && hardware_utilization
+                .iter()
+                .all(|h| h.utilization_percent <= 100.0);
+
+        Ok(CogEnvelopeAnalysis {
+            envelope: *envelope,
+            samples_evaluated: samples.len(),
+            worst_sling_tensions,
+            max_tilt_deg,
+            max_tilt_cog,
+            hardware_utilization,
+            max_utilization_percent,
+            is_safe,
+            warnings,
+        })
+    }
+
+    /// CoG sample positions: corners, centre and an interior grid
+    fn sample_cog_envelope(envelope: &CogEnvelope, interior_divisions: usize) -> Vec<Point3<f32>> {
+        let mut samples = envelope.corners().to_vec();
+
+        let n = interior_divisions;
+        // An even grid already has a point at the centre
+        if n < 2 || n % 2 == 1 {
+            samples.push(envelope.center());
+        }
+        if n > 1 {
+            let size = envelope.size();
+            for i in 1..n {
+                for j in 1..n {
+                    for k in 1..n {
+                        let t = Vector3::new(i as f32, j as f32, k as f32) / n as f32;
+                        samples.push(envelope.min + size.component_mul(&t));
+                    }
+                }
+            }
+        }
+
+        samples
+    }
+
+    /// Tilt of a freely hanging load, which rotates until its CoG is under the hook
+    fn hanging_tilt_deg(hook_position: Point3<f32>, cog: Point3<f32>) -> f32 {
+        let horizontal =
+            ((cog.x - hook_position.x).powi(2) + (cog.y - hook_position.y).powi(2)).sqrt();
+        let vertical = hook_position.z - cog.z;
+
+        horizontal.atan2(vertical.max(0.001)).to_degrees()
+    }
+
+    /// Generate warnings for a CoG envelope analysis
+    fn generate_envelope_warnings(
+        slings: &[WorstCaseSling],
+        hardware: &[HardwareUtilization],
+        max_tilt_deg: f32,
+        slack_cogs: &[Point3<f32>],
+    ) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        for worst in slings {
+            let tension = &worst.analysis;
+            if !tension.is_safe {
+                warnings.push(format!(
+                    "Sling '{}' is OVERLOADED ({:.0}% of capacity) with CoG at [{:.2}, {:.2}, {:.2}]",
+                    tension.sling_id,
+                    tension.utilization_percent,
+                    worst.cog.x,
+                    worst.cog.y,
+                    worst.cog.z
+                ));
+            } else if tension.utilization_percent > 90.0 {
+                warnings.push(format!(
+                    "Sling '{}' reaches {:.0}% of capacity within the CoG envelope",
+                    tension.sling_id, tension.utilization_percent
+                ));
+            }
+        }
+
+        for h in hardware {
+            if h.utilization_percent > 100.0 {
+                warnings.push(format!(
+                    "Hardware #{} ({:?}) is OVERLOADED ({:.0}% of capacity)",
+                    h.hardware_index + 1,
+                    h.hardware_type,
+                    h.utilization_percent
+                ));
+            }
+        }
+
+        if max_tilt_deg > 5.0 {
+            warnings.push(format!(
+                "Load may tilt up to {:.1}° within the CoG envelope",
+                max_tilt_deg
+            ));
+        }
+
+        if let Some(cog) = slack_cogs.first() {
+            warnings.push(format!(
+                "CoG at [{:.2}, {:.2}, {:.2}] falls outside the pick points - slings would go slack ({} of the sampled positions)",
+                cog.x,
+                cog.y,
+                cog.z,
+                slack_cogs.len()
+            ));
+        }
+
+        warnings
+    }
+
     pub fn analyze_spreader_beam(
         beam_length_m: f32,
         beam_weight_kg: f32,
@@ -665,6 +919,39 @@ impl RiggingCalculator {
     }
 }
 
+/// Vertical load carried at each attachment point for a given CoG
+///
+/// Solves vertical force and plan moment equilibrium about the CoG. With more
+/// than three points the system is indeterminate and the minimum-norm (most
+/// even) distribution is used. Negative values mean the point would go slack.
+pub(crate) fn pick_point_reactions(
+    points: &[Point3<f32>],
+    cog: Point3<f32>,
+    weight_kg: f32,
+) -> Result<Vec<f32>, RiggingError> {
+    match points.len() {
+        0 => Err(RiggingError::InsufficientPickPoints),
+        1 => Ok(vec![weight_kg]),
+        n => {
+            let mut a_matrix = na::DMatrix::zeros(3, n);
+            for (i, point) in points.iter().enumerate() {
+                a_matrix[(0, i)] = 1.0;
+                a_matrix[(1, i)] = point.x - cog.x;
+                a_matrix[(2, i)] = point.y - cog.y;
+            }
+
+            let b = na::DVector::from_vec(vec![weight_kg, 0.0, 0.0]);
+
+            let svd = a_matrix.svd(true, true);
+            let reactions = svd
+                .solve(&b, 1e-6)
+                .map_err(|_| RiggingError::MathError("SVD solve failed".to_string()))?;
+
+            Ok(reactions.iter().copied().collect())
+        }
+    }
+}
+
 /// Helper functions for rigging design
 pub struct RiggingDesigner;
 
@@ -735,3 +1022,111 @@ impl RiggingDesigner {
         required_capacity * 1.2
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_sling(id: &str, attachment: Point3<f32>, hook: Point3<f32>) -> Sling {
+        Sling {
+            spec: SlingSpec {
+                id: id.to_string(),
+                material: SlingMaterial::Synthetic {
+                    material: SyntheticMaterial::Polyester,
+                },
+                diameter_mm: None,
+                width_mm: Some(90.0),
+                length_m: (hook - attachment).norm(),
+                rated_capacity_kg: 5000.0,
+                safety_factor: 5.0,
+            },
+            hitch_type: HitchType::Vertical,
+            attachment_point: attachment,
+            hook_point: hook,
+            angle_from_vertical: None,
+            tension_kg: None,
+        }
+    }
+
+    fn two_leg_config() -> RiggingConfiguration {
+        let hook = Point3::new(0.0, 0.0, 5.0);
+        RiggingConfiguration {
+            load: Load {
+                weight_kg: 4000.0,
+                center_of_gravity: Point3::new(0.0, 0.0, 0.5),
+                dimensions: Vector3::new(4.0, 1.0, 1.0),
+                pick_points: vec![],
+            },
+            slings: vec![
+                test_sling("rear", Point3::new(-2.0, 0.0, 1.0), hook),
+                test_sling("front", Point3::new(2.0, 0.0, 1.0), hook),
+            ],
+            hardware: vec![RiggingHardware {
+                hardware_type: HardwareType::Shackle { size_mm: 19.0 },
+                rated_capacity_kg: 4750.0,
+                weight_kg: 1.0,
+                position: Point3::new(2.0, 0.0, 1.0),
+            }],
+            crane_hook_position: hook,
+        }
+    }
+
+    #[test]
+    fn test_pick_point_reactions() {
+        let points = [Point3::new(-2.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)];
+        let reactions = pick_point_reactions(&points, Point3::new(0.5, 0.0, 0.0), 1000.0).unwrap();
+
+        assert!((reactions[0] - 375.0).abs() < 0.5);
+        assert!((reactions[1] - 625.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_cog_envelope_worst_case() {
+        let config = two_leg_config();
+        let envelope =
+            CogEnvelope::around(config.load.center_of_gravity, Vector3::new(0.5, 0.2, 0.1));
+
+        let analysis = RiggingCalculator::analyze_cog_envelope(&config, &envelope, 2).unwrap();
+        assert_eq!(analysis.samples_evaluated, 9);
+        assert_eq!(analysis.worst_sling_tensions.len(), 2);
+
+        // Worst case for the front sling is with the CoG shifted towards it
+        let front = &analysis.worst_sling_tensions[1];
+        assert!((front.cog.x - 0.5).abs() < 1e-4);
+        let vertical_share = 4000.0 * 2.5 / 4.0;
+        assert!(front.analysis.tension_kg > vertical_share);
+
+        assert!(analysis.max_tilt_deg > 0.0);
+        assert_eq!(analysis.hardware_utilization.len(), 1);
+        assert!(analysis.hardware_utilization[0].load_kg >= front.analysis.tension_kg - 1e-3);
+    }
+
+    #[test]
+    fn test_cog_envelope_samples_are_distinct() {
+        let envelope = CogEnvelope::around(Point3::new(1.0, 0.0, 0.5), Vector3::new(0.4, 0.2, 0.1));
+
+        // Corners, the centre and the interior grid, each sampled once
+        for (divisions, expected) in [(0, 9), (1, 9), (2, 9), (3, 17), (4, 35)] {
+            let samples = RiggingCalculator::sample_cog_envelope(&envelope, divisions);
+            assert_eq!(samples.len(), expected, "{} divisions", divisions);
+            assert!(
+                samples
+                    .iter()
+                    .any(|p| (p - envelope.center()).norm() < 1e-6)
+            );
+            for (i, a) in samples.iter().enumerate() {
+                assert!(samples[i + 1..].iter().all(|b| (a - b).norm() > 1e-6));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cog_envelope_outside_pick_points() {
+        let config = two_leg_config();
+        let envelope = CogEnvelope::around(Point3::new(2.5, 0.0, 0.5), Vector3::zeros());
+
+        let analysis = RiggingCalculator::analyze_cog_envelope(&config, &envelope, 0).unwrap();
+        assert!(!analysis.is_safe);
+        assert!(analysis.warnings.iter().any(|w| w.contains("slack")));
+    }
+}
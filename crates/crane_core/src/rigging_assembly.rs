@@ -0,0 +1,682 @@
+// crates/crane_core/src/rigging_assembly.rs
+
+use crate::rigging::{
+    HitchType, Load, RiggingCalculator, RiggingConfiguration, RiggingError, RiggingHardware,
+    SlingSpec, pick_point_reactions,
+};
+use nalgebra as na;
+use nalgebra::{Point3, Vector3};
+use serde::{Deserialize, Serialize};
+
+/// Index of a node within a `RiggingAssembly`
+pub type NodeIndex = usize;
+
+/// Role of a connection point in the below-the-hook assembly
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RiggingNodeKind {
+    /// Crane hook (root of the assembly)
+    Hook,
+    /// Master link directly below the hook
+    MasterLink,
+    /// Intermediate connection (sub-link, shackle joint, lifting beam lug)
+    Link,
+    /// End of a spreader beam or frame
+    SpreaderEnd,
+    /// Attachment to the load
+    PickPoint { pick_point_id: String },
+}
+
+/// Connection point in the rigging assembly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiggingNode {
+    pub name: String,
+    pub kind: RiggingNodeKind,
+    /// Position relative to load origin
+    pub position: Point3<f32>,
+}
+
+/// Load-carrying element between two nodes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MemberKind {
+    /// Sling leg (tension only)
+    Sling {
+        spec: SlingSpec,
+        hitch_type: HitchType,
+    },
+    /// In-line hardware such as shackles, links and swivels (tension)
+    Hardware(RiggingHardware),
+    /// Spreader beam or frame strut between two spreader ends (compression)
+    Spreader(RiggingHardware),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiggingMember {
+    pub name: String,
+    pub kind: MemberKind,
+    /// Node nearer the hook (first end for spreaders)
+    pub upper: NodeIndex,
+    /// Node nearer the load (second end for spreaders)
+    pub lower: NodeIndex,
+}
+
+impl RiggingMember {
+    /// Is this member a tension member hanging below `upper`?
+    pub fn is_tension_member(&self) -> bool {
+        !matches!(self.kind, MemberKind::Spreader(_))
+    }
+
+    /// Estimated self weight of the member
+    pub fn weight_kg(&self) -> f32 {
+        match &self.kind {
+            MemberKind::Sling { spec, .. } => RiggingCalculator::estimate_sling_weight(spec),
+            MemberKind::Hardware(hardware) | MemberKind::Spreader(hardware) => hardware.weight_kg,
+        }
+    }
+
+    /// Rated capacity of the member in its current use
+    pub fn capacity_kg(&self) -> f32 {
+        match &self.kind {
+            MemberKind::Sling { spec, hitch_type } => {
+                spec.rated_capacity_kg * hitch_type.capacity_factor()
+            }
+            MemberKind::Hardware(hardware) | MemberKind::Spreader(hardware) => {
+                hardware.rated_capacity_kg
+            }
+        }
+    }
+}
+
+/// Below-the-hook rigging as a tree of nodes and members
+///
+/// Every node except the hook hangs from exactly one tension member (sling or
+/// hardware); spreaders are struts between two spreader ends.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiggingAssembly {
+    pub nodes: Vec<RiggingNode>,
+    pub members: Vec<RiggingMember>,
+}
+
+/// Force in a single member of the assembly
+#[derive(Debug, Clone)]
+pub struct MemberForce {
+    pub member_index: usize,
+    pub name: String,
+    /// Axial force: tension for slings/hardware, compression for spreaders
+    pub force_kg: f32,
+    /// Load compared against capacity (vertical load carried for spreaders)
+    pub design_load_kg: f32,
+    pub angle_from_vertical_deg: f32,
+    pub capacity_kg: f32,
+    pub utilization_percent: f32,
+    pub is_safe: bool,
+}
+
+/// Result of solving a rigging assembly
+#[derive(Debug, Clone)]
+pub struct RiggingAssemblyAnalysis {
+    pub member_forces: Vec<MemberForce>,
+    /// Vertical load at each pick point (pick point id, kg)
+    pub pick_point_loads_kg: Vec<(String, f32)>,
+    /// Total load on the hook including rigging
+    pub hook_load_kg: f32,
+    pub total_rigging_weight_kg: f32,
+    /// Vertical distance from hook to the lowest pick point
+    pub rigging_height_m: f32,
+    pub is_safe: bool,
+    pub warnings: Vec<String>,
+}
+
+impl RiggingAssembly {
+    /// Create an assembly containing only the hook
+    pub fn new(hook_position: Point3<f32>) -> Self {
+        Self {
+            nodes: vec![RiggingNode {
+                name: "Hook".to_string(),
+                kind: RiggingNodeKind::Hook,
+                position: hook_position,
+            }],
+            members: Vec::new(),
+        }
+    }
+
+    /// Index of the hook node
+    pub fn hook(&self) -> Option<NodeIndex> {
+        self.nodes
+            .iter()
+            .position(|n| n.kind == RiggingNodeKind::Hook)
+    }
+
+    pub fn add_node(
+        &mut self,
+        name: &str,
+        kind: RiggingNodeKind,
+        position: Point3<f32>,
+    ) -> NodeIndex {
+        self.nodes.push(RiggingNode {
+            name: name.to_string(),
+            kind,
+            position,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Add a pick point node for a load pick point
+    pub fn add_pick_point(&mut self, pick_point_id: &str, position: Point3<f32>) -> NodeIndex {
+        self.add_node(
+            pick_point_id,
+            RiggingNodeKind::PickPoint {
+                pick_point_id: pick_point_id.to_string(),
+            },
+            position,
+        )
+    }
+
+    pub fn add_sling(
+        &mut self,
+        spec: SlingSpec,
+        hitch_type: HitchType,
+        upper: NodeIndex,
+        lower: NodeIndex,
+    ) -> usize {
+        self.members.push(RiggingMember {
+            name: spec.id.clone(),
+            kind: MemberKind::Sling { spec, hitch_type },
+            upper,
+            lower,
+        });
+        self.members.len() - 1
+    }
+
+    pub fn add_hardware(
+        &mut self,
+        name: &str,
+        hardware: RiggingHardware,
+        upper: NodeIndex,
+        lower: NodeIndex,
+    ) -> usize {
+        self.members.push(RiggingMember {
+            name: name.to_string(),
+            kind: MemberKind::Hardware(hardware),
+            upper,
+            lower,
+        });
+        self.members.len() - 1
+    }
+
+    pub fn add_spreader(
+        &mut self,
+        name: &str,
+        hardware: RiggingHardware,
+        end_a: NodeIndex,
+        end_b: NodeIndex,
+    ) -> usize {
+        self.members.push(RiggingMember {
+            name: name.to_string(),
+            kind: MemberKind::Spreader(hardware),
+            upper: end_a,
+            lower: end_b,
+        });
+        self.members.len() - 1
+    }
+
+    /// Build an assembly from a flat configuration (every sling to the hook)
+    pub fn from_configuration(config: &RiggingConfiguration) -> Self {
+        let mut assembly = Self::new(config.crane_hook_position);
+        let hook = 0;
+
+        for sling in &config.slings {
+            let pick_point_id = config
+                .load
+                .pick_points
+                .iter()
+                .find(|pp| (pp.position - sling.attachment_point).norm() < 0.01)
+                .map(|pp| pp.id.clone())
+                .unwrap_or_else(|| sling.spec.id.clone());
+
+            let node = assembly.add_pick_point(&pick_point_id, sling.attachment_point);
+            assembly.add_sling(sling.spec.clone(), sling.hitch_type, hook, node);
+        }
+
+        assembly
+    }
+
+    /// Tension member a node hangs from
+    fn parent_member(&self, node: NodeIndex) -> Option<usize> {
+        self.members
+            .iter()
+            .position(|m| m.is_tension_member() && m.lower == node)
+    }
+
+    /// Number of tension members between a node and the hook
+    fn depth(&self, node: NodeIndex) -> Result<usize, RiggingError> {
+        let mut depth = 0;
+        let mut current = node;
+
+        while self.nodes[current].kind != RiggingNodeKind::Hook {
+            let member = self.parent_member(current).ok_or_else(|| {
+                RiggingError::InvalidConfiguration(format!(
+                    "Node '{}' is not connected to the hook",
+                    self.nodes[current].name
+                ))
+            })?;
+            current = self.members[member].upper;
+            depth += 1;
+
+            if depth > self.nodes.len() {
+                return Err(RiggingError::InvalidConfiguration(
+                    "Rigging assembly contains a loop".to_string(),
+                ));
+            }
+        }
+
+        Ok(depth)
+    }
+
+    /// Check the assembly is a tree hanging from a single hook
+    pub fn validate(&self) -> Result<(), RiggingError> {
+        let hooks = self
+            .nodes
+            .iter()
+            .filter(|n| n.kind == RiggingNodeKind::Hook)
+            .count();
+        if hooks != 1 {
+            return Err(RiggingError::InvalidConfiguration(format!(
+                "Rigging assembly must have exactly one hook (found {})",
+                hooks
+            )));
+        }
+
+        for member in &self.members {
+            if member.upper >= self.nodes.len() || member.lower >= self.nodes.len() {
+                return Err(RiggingError::InvalidConfiguration(format!(
+                    "Member '{}' references a missing node",
+                    member.name
+                )));
+            }
+            if member.upper == member.lower {
+                return Err(RiggingError::InvalidConfiguration(format!(
+                    "Member '{}' connects a node to itself",
+                    member.name
+                )));
+            }
+            if !member.is_tension_member()
+                && (self.nodes[member.upper].kind != RiggingNodeKind::SpreaderEnd
+                    || self.nodes[member.lower].kind != RiggingNodeKind::SpreaderEnd)
+            {
+                return Err(RiggingError::InvalidConfiguration(format!(
+                    "Spreader '{}' must connect two spreader ends",
+                    member.name
+                )));
+            }
+        }
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let parents = self
+                .members
+                .iter()
+                .filter(|m| m.is_tension_member() && m.lower == i)
+                .count();
+
+            match node.kind {
+                RiggingNodeKind::Hook if parents > 0 => {
+                    return Err(RiggingError::InvalidConfiguration(
+                        "Hook cannot hang from another member".to_string(),
+                    ));
+                }
+                RiggingNodeKind::Hook => {}
+                _ if parents != 1 => {
+                    return Err(RiggingError::InvalidConfiguration(format!(
+                        "Node '{}' must hang from exactly one sling or hardware item (found {})",
+                        node.name, parents
+                    )));
+                }
+                _ => {
+                    self.depth(i)?;
+                }
+            }
+        }
+
+        if !self
+            .nodes
+            .iter()
+            .any(|n| matches!(n.kind, RiggingNodeKind::PickPoint { .. }))
+        {
+            return Err(RiggingError::InsufficientPickPoints);
+        }
+
+        Ok(())
+    }
+
+    /// Vertical distance from hook to the lowest pick point
+    pub fn rigging_height_m(&self) -> f32 {
+        let Some(hook) = self.hook() else {
+            return 0.0;
+        };
+
+        self.nodes
+            .iter()
+            .filter(|n| matches!(n.kind, RiggingNodeKind::PickPoint { .. }))
+            .map(|n| self.nodes[hook].position.z - n.position.z)
+            .fold(0.0, f32::max)
+    }
+
+    /// Total self weight of slings and hardware
+    pub fn total_weight_kg(&self) -> f32 {
+        self.members.iter().map(|m| m.weight_kg()).sum()
+    }
+
+    /// Force exerted on `node` by `member` carrying `force`
+    fn member_force_on_node(
+        &self,
+        member: &RiggingMember,
+        node: NodeIndex,
+        force: f32,
+    ) -> Vector3<f32> {
+        let other = if member.upper == node {
+            member.lower
+        } else {
+            member.upper
+        };
+        let towards_other = (self.nodes[other].position - self.nodes[node].position).normalize();
+
+        if member.is_tension_member() {
+            towards_other * force
+        } else {
+            -towards_other * force
+        }
+    }
+
+    /// Solve forces in every member for the given load
+    ///
+    /// Pick point loads come from equilibrium about the load CoG, then nodes
+    /// are solved from the load upwards. Member self weight is shared equally
+    /// between its two end nodes.
+    pub fn solve(&self, load: &Load) -> Result<RiggingAssemblyAnalysis, RiggingError> {
+        self.validate()?;
+        let hook = self.hook().ok_or(RiggingError::InsufficientPickPoints)?;
+        let mut warnings = Vec::new();
+
+        // External forces on each node (kg, +Z up)
+        let mut external = vec![Vector3::zeros(); self.nodes.len()];
+
+        let pick_nodes: Vec<NodeIndex> = (0..self.nodes.len())
+            .filter(|&i| matches!(self.nodes[i].kind, RiggingNodeKind::PickPoint { .. }))
+            .collect();
+        let pick_positions: Vec<Point3<f32>> =
+            pick_nodes.iter().map(|&i| self.nodes[i].position).collect();
+        let reactions =
+            pick_point_reactions(&pick_positions, load.center_of_gravity, load.weight_kg)?;
+
+        let mut pick_point_loads_kg = Vec::new();
+        for (&node, &reaction) in pick_nodes.iter().zip(reactions.iter()) {
+            if reaction < 0.0 {
+                warnings.push(format!(
+                    "Pick point '{}' would go slack - CoG lies outside the pick points",
+                    self.nodes[node].name
+                ));
+            }
+            let reaction = reaction.max(0.0);
+            external[node].z -= reaction;
+            pick_point_loads_kg.push((self.nodes[node].name.clone(), reaction));
+        }
+
+        for member in &self.members {
+            let half_weight = member.weight_kg() / 2.0;
+            external[member.upper].z -= half_weight;
+            external[member.lower].z -= half_weight;
+        }
+
+        // Solve from the deepest nodes up to the hook
+        let mut order: Vec<(usize, NodeIndex)> = Vec::new();
+        for i in 0..self.nodes.len() {
+            if i != hook {
+                order.push((self.depth(i)?, i));
+            }
+        }
+        order.sort_by_key(|&(depth, _)| std::cmp::Reverse(depth));
+
+        let mut forces: Vec<Option<f32>> = vec![None; self.members.len()];
+
+        for (_, node) in order {
+            let touching: Vec<usize> = (0..self.members.len())
+                .filter(|&m| self.members[m].upper == node || self.members[m].lower == node)
+                .collect();
+
+            let mut known = external[node];
+            let mut unknown = Vec::new();
+            for &m in &touching {
+                match forces[m] {
+                    Some(f) => known += self.member_force_on_node(&self.members[m], node, f),
+                    None => unknown.push(m),
+                }
+            }
+
+            if unknown.is_empty() {
+                continue;
+            }
+
+            // Unit force directions of the unknown members: A * f = -known
+            let mut a_matrix = na::DMatrix::zeros(3, unknown.len());
+            for (col, &m) in unknown.iter().enumerate() {
+                let direction = self.member_force_on_node(&self.members[m], node, 1.0);
+                a_matrix.set_column(col, &direction);
+            }
+            let b = na::DVector::from_column_slice((-known).as_slice());
+
+            let solution = a_matrix
+                .clone()
+                .svd(true, true)
+                .solve(&b, 1e-6)
+                .map_err(|_| RiggingError::MathError("SVD solve failed".to_string()))?;
+
+            let residual = (&a_matrix * &solution - &b).norm();
+            if residual > 0.01 * load.weight_kg.max(1.0) {
+                warnings.push(format!(
+                    "Node '{}' is not in equilibrium ({:.0}kg out of balance) - check rigging geometry",
+                    self.nodes[node].name, residual
+                ));
+            }
+
+            for (&m, &f) in unknown.iter().zip(solution.iter()) {
+                forces[m] = Some(f);
+            }
+        }
+
+        // Hook load is the resultant of everything hanging from it
+        let mut hook_resultant = external[hook];
+        for (m, member) in self.members.iter().enumerate() {
+            if member.upper == hook {
+                hook_resultant += self.member_force_on_node(member, hook, forces[m].unwrap_or(0.0));
+            }
+        }
+        let hook_load_kg = hook_resultant.norm();
+
+        let member_forces: Vec<MemberForce> = self
+            .members
+            .iter()
+            .enumerate()
+            .map(|(m, member)| self.member_result(m, member, &forces))
+            .collect();
+
+        for result in &member_forces {
+            let member = &self.members[result.member_index];
+            if result.force_kg < -0.5 {
+                let state = if member.is_tension_member() {
+                    "compression"
+                } else {
+                    "tension"
+                };
+                warnings.push(format!(
+                    "Member '{}' is in {} ({:.0}kg) - rigging cannot carry this",
+                    result.name,
+                    state,
+                    result.force_kg.abs()
+                ));
+            }
+            if !result.is_safe {
+                warnings.push(format!(
+                    "Member '{}' is OVERLOADED ({:.0}% of capacity)!",
+                    result.name, result.utilization_percent
+                ));
+            } else if result.utilization_percent > 90.0 {
+                warnings.push(format!(
+                    "Member '{}' is highly loaded ({:.0}% of capacity)",
+                    result.name, result.utilization_percent
+                ));
+            }
+        }
+
+        let is_safe = member_forces
+            .iter()
+            .all(|r| r.is_safe && r.force_kg >= -0.5)
+            && reactions.iter().all(|&r| r >= 0.0);
+
+        Ok(RiggingAssemblyAnalysis {
+            member_forces,
+            pick_point_loads_kg,
+            hook_load_kg,
+            total_rigging_weight_kg: self.total_weight_kg(),
+            rigging_height_m: self.rigging_height_m(),
+            is_safe,
+            warnings,
+        })
+    }
+
+    fn member_result(
+        &self,
+        index: usize,
+        member: &RiggingMember,
+        forces: &[Option<f32>],
+    ) -> MemberForce {
+        let force_kg = forces[index].unwrap_or(0.0);
+        let axis = self.nodes[member.upper].position - self.nodes[member.lower].position;
+        let angle_from_vertical_deg = if axis.norm() > 0.001 {
+            (axis.z.abs() / axis.norm())
+                .clamp(-1.0, 1.0)
+                .acos()
+                .to_degrees()
+        } else {
+            0.0
+        };
+
+        // Spreaders are rated on the load they carry, not the strut force
+        let design_load_kg = match member.kind {
+            MemberKind::Spreader(_) => [member.upper, member.lower]
+                .iter()
+                .filter_map(|&end| {
+                    let parent = self.parent_member(end)?;
+                    let f = forces[parent]?;
+                    Some(self.member_force_on_node(&self.members[parent], end, f).z)
+                })
+                .sum(),
+            _ => force_kg.abs(),
+        };
+
+        let capacity_kg = member.capacity_kg();
+        let utilization_percent = if capacity_kg > 0.0 {
+            design_load_kg / capacity_kg * 100.0
+        } else {
+            f32::INFINITY
+        };
+
+        MemberForce {
+            member_index: index,
+            name: member.name.clone(),
+            force_kg,
+            design_load_kg,
+            angle_from_vertical_deg,
+            capacity_kg,
+            utilization_percent,
+            is_safe: utilization_percent <= 100.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rigging::{HardwareType, SlingMaterial, SyntheticMaterial};
+
+    fn sling_spec(id: &str) -> SlingSpec {
+        SlingSpec {
+            id: id.to_string(),
+            material: SlingMaterial::Synthetic {
+                material: SyntheticMaterial::Polyester,
+            },
+            diameter_mm: None,
+            width_mm: None,
+            length_m: 3.0,
+            rated_capacity_kg: 5000.0,
+            safety_factor: 5.0,
+        }
+    }
+
+    fn test_load() -> Load {
+        Load {
+            weight_kg: 4000.0,
+            center_of_gravity: Point3::new(0.0, 0.0, 0.5),
+            dimensions: Vector3::new(4.0, 1.0, 1.0),
+            pick_points: vec![],
+        }
+    }
+
+    #[test]
+    fn test_spreader_beam_assembly() {
+        let mut assembly = RiggingAssembly::new(Point3::new(0.0, 0.0, 6.0));
+        let hook = assembly.hook().unwrap();
+
+        let end_a = assembly.add_node(
+            "Spreader A",
+            RiggingNodeKind::SpreaderEnd,
+            Point3::new(-2.0, 0.0, 3.0),
+        );
+        let end_b = assembly.add_node(
+            "Spreader B",
+            RiggingNodeKind::SpreaderEnd,
+            Point3::new(2.0, 0.0, 3.0),
+        );
+        let pick_a = assembly.add_pick_point("rear", Point3::new(-2.0, 0.0, 1.0));
+        let pick_b = assembly.add_pick_point("front", Point3::new(2.0, 0.0, 1.0));
+
+        assembly.add_sling(sling_spec("top_a"), HitchType::Vertical, hook, end_a);
+        assembly.add_sling(sling_spec("top_b"), HitchType::Vertical, hook, end_b);
+        assembly.add_sling(sling_spec("bottom_a"), HitchType::Vertical, end_a, pick_a);
+        assembly.add_sling(sling_spec("bottom_b"), HitchType::Vertical, end_b, pick_b);
+        let spreader = assembly.add_spreader(
+            "Spreader beam",
+            RiggingHardware {
+                hardware_type: HardwareType::SpreaderBeam { length_m: 4.0 },
+                rated_capacity_kg: 10_000.0,
+                weight_kg: 0.0,
+                position: Point3::new(0.0, 0.0, 3.0),
+            },
+            end_a,
+            end_b,
+        );
+
+        let analysis = assembly.solve(&test_load()).unwrap();
+        assert!(analysis.is_safe, "{:?}", analysis.warnings);
+
+        let top = &analysis.member_forces[0];
+        assert!((top.force_kg - 2000.0 * 13.0_f32.sqrt() / 3.0).abs() < 1.0);
+
+        let bottom = &analysis.member_forces[2];
+        assert!((bottom.force_kg - 2000.0).abs() < 1.0);
+        assert!(bottom.angle_from_vertical_deg.abs() < 0.01);
+
+        let beam = &analysis.member_forces[spreader];
+        assert!((beam.force_kg - 2000.0 * 2.0 / 3.0).abs() < 1.0);
+        assert!((beam.design_load_kg - 4000.0).abs() < 1.0);
+
+        assert!((analysis.hook_load_kg - 4000.0).abs() < 1.0);
+        assert!((analysis.rigging_height_m - 5.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_validate_rejects_disconnected_node() {
+        let mut assembly = RiggingAssembly::new(Point3::new(0.0, 0.0, 5.0));
+        assembly.add_pick_point("loose", Point3::new(0.0, 0.0, 1.0));
+
+        assert!(assembly.validate().is_err());
+    }
+}
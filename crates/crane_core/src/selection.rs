@@ -0,0 +1,373 @@
+// crates/crane_core/src/selection.rs
+
+use crate::crane_data::{CraneConfiguration, CraneSpec, CrawlerCalculator};
+use crate::ground_bearing::{GroundBearingCalculator, PadMaterial};
+use crate::inverse_kinematics::{IkSolution, IkSolver, LiftTarget};
+use nalgebra::Point3;
+use std::collections::HashMap;
+
+/// Lift to be checked against the fleet
+#[derive(Debug, Clone)]
+pub struct LiftRequirement {
+    pub load_kg: f32,
+    /// Hook block, slings, spreaders and shackles
+    pub rigging_weight_kg: f32,
+    pub pick: LiftTarget,
+    pub set: LiftTarget,
+    pub crane_position: Point3<f32>,
+    pub heading_deg: f32,
+}
+
+impl LiftRequirement {
+    pub fn hook_load_kg(&self) -> f32 {
+        self.load_kg + self.rigging_weight_kg
+    }
+}
+
+/// Outrigger extension preset tried during selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutriggerSetup {
+    Full,
+    Intermediate,
+    Minimum,
+}
+
+impl OutriggerSetup {
+    pub fn all() -> [OutriggerSetup; 3] {
+        [Self::Full, Self::Intermediate, Self::Minimum]
+    }
+
+    pub fn apply(&self, config: &mut CraneConfiguration) {
+        match self {
+            Self::Full => config.outriggers.preset_max_extension(),
+            Self::Intermediate => config.outriggers.preset_medium_extension(),
+            Self::Minimum => config.outriggers.preset_min_extension(),
+        }
+    }
+}
+
+impl std::fmt::Display for OutriggerSetup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "Full extension"),
+            Self::Intermediate => write!(f, "Intermediate extension"),
+            Self::Minimum => write!(f, "Minimum extension"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionOrder {
+    /// Rated capacity, smallest first
+    Size,
+    /// Day rate, cheapest first (unpriced cranes last)
+    Cost,
+}
+
+#[derive(Debug, Clone)]
+pub struct SelectionOptions {
+    /// Maximum capacity utilisation at pick and set
+    pub max_utilization_percent: f32,
+    pub order: SelectionOrder,
+    /// Day rate by spec id
+    pub day_rates: HashMap<String, f32>,
+}
+
+impl Default for SelectionOptions {
+    fn default() -> Self {
+        Self {
+            max_utilization_percent: 75.0,
+            order: SelectionOrder::Size,
+            day_rates: HashMap::new(),
+        }
+    }
+}
+
+/// Result of checking one crane model against a lift
+#[derive(Debug, Clone)]
+pub struct CraneSelection {
+    pub spec_id: String,
+    pub model: String,
+    pub max_capacity_kg: f32,
+    pub feasible: bool,
+    /// Lightest counterweight and outrigger setup that does the lift
+    pub counterweight_slabs: usize,
+    pub counterweight_kg: f32,
+    /// None for crawler and tower cranes
+    pub outrigger_setup: Option<OutriggerSetup>,
+    pub solution: Option<IkSolution>,
+    pub utilization_percent: Option<f32>,
+    /// Crane configured for the pick
+    pub configuration: Option<CraneConfiguration>,
+    /// Why the crane cannot do the lift
+    pub reason: Option<String>,
+}
+
+/// Checks a lift against every crane in a fleet
+pub struct CraneSelector;
+
+impl CraneSelector {
+    /// Every crane in `fleet` with its best setup, feasible cranes first
+    pub fn select(
+        fleet: &[CraneSpec],
+        lift: &LiftRequirement,
+        options: &SelectionOptions,
+    ) -> Vec<CraneSelection> {
+        let mut selections: Vec<CraneSelection> = fleet
+            .iter()
+            .map(|spec| Self::evaluate(spec, lift, options))
+            .collect();
+
+        let rate = |s: &CraneSelection| {
+            options
+                .day_rates
+                .get(&s.spec_id)
+                .copied()
+                .unwrap_or(f32::MAX)
+        };
+
+        selections.sort_by(|a, b| {
+            b.feasible
+                .cmp(&a.feasible)
+                .then_with(|| match options.order {
+                    SelectionOrder::Size => a.max_capacity_kg.total_cmp(&b.max_capacity_kg),
+                    SelectionOrder::Cost => rate(a)
+                        .total_cmp(&rate(b))
+                        .then(a.max_capacity_kg.total_cmp(&b.max_capacity_kg)),
+                })
+        });
+
+        selections
+    }
+
+    /// Smallest crane in the fleet that can do the lift
+    pub fn smallest(
+        fleet: &[CraneSpec],
+        lift: &LiftRequirement,
+        options: &SelectionOptions,
+    ) -> Option<CraneSelection> {
+        Self::select(fleet, lift, options)
+            .into_iter()
+            .find(|s| s.feasible)
+    }
+
+    /// Find the lightest counterweight, then the widest outrigger setup, that
+    /// keeps the lift within the utilisation limit and stable
+    pub fn evaluate(
+        spec: &CraneSpec,
+        lift: &LiftRequirement,
+        options: &SelectionOptions,
+    ) -> CraneSelection {
+        let hook_load = lift.hook_load_kg();
+
+        let mut base = CraneConfiguration::new(spec.clone());
+        base.position = lift.crane_position;
+        base.heading_deg = lift.heading_deg;
+
+        let setups: Vec<Option<OutriggerSetup>> = if spec.crawler.is_some() || spec.tower.is_some()
+        {
+            vec![None]
+        } else {
+            OutriggerSetup::all().into_iter().map(Some).collect()
+        };
+
+        let mut selection = CraneSelection {
+            spec_id: spec.id.clone(),
+            model: format!("{} {}", spec.manufacturer, spec.model),
+            max_capacity_kg: spec.max_capacity_kg,
+            feasible: false,
+            counterweight_slabs: 0,
+            counterweight_kg: 0.0,
+            outrigger_setup: None,
+            solution: None,
+            utilization_percent: None,
+            configuration: None,
+            reason: None,
+        };
+
+        // Reduced-span capacity depends on the counterweight through the
+        // tipping load, so solve for every slab count
+        let mut lowest_utilization: Option<f32> = None;
+        for slabs in 0..=spec.counterweight_max_slabs {
+            for setup in &setups {
+                let mut config = base.clone();
+                if let Some(setup) = setup {
+                    setup.apply(&mut config);
+                }
+                if config.counterweight.set_slab_count(slabs).is_err()
+                    || config.counterweight.validate().is_err()
+                {
+                    continue;
+                }
+
+                let solutions = IkSolver::solve(&config, &lift.pick, &lift.set, hook_load);
+                if let Some(best) = solutions.first() {
+                    lowest_utilization =
+                        Some(lowest_utilization.map_or(best.utilization_percent, |u| {
+                            u.min(best.utilization_percent)
+                        }));
+                }
+
+                let stable = solutions
+                    .iter()
+                    .take_while(|s| s.utilization_percent <= options.max_utilization_percent)
+                    .find(|s| Self::is_stable(&config, s, hook_load));
+
+                if let Some(solution) = stable {
+                    selection.feasible = true;
+                    selection.counterweight_slabs = slabs;
+                    selection.counterweight_kg = config.counterweight.get_total_weight_kg();
+                    selection.outrigger_setup = *setup;
+                    selection.utilization_percent = Some(solution.utilization_percent);
+                    selection.configuration = Some(solution.pick_configuration(&config));
+                    selection.solution = Some(solution.clone());
+                    return selection;
+                }
+            }
+        }
+
+        selection.reason = Some(match lowest_utilization {
+            None => "Cannot reach the pick and set within chart capacity".to_string(),
+            Some(u) if u > options.max_utilization_percent => {
+                selection.utilization_percent = Some(u);
+                format!(
+                    "{:.0}% utilisation exceeds the {:.0}% limit",
+                    u, options.max_utilization_percent
+                )
+            }
+            Some(_) => "Unstable with every counterweight and outrigger setup".to_string(),
+        });
+        selection
+    }
+
+    /// No outrigger (or track end) lifts at pick or set, loaded or with an
+    /// empty hook
+    fn is_stable(config: &CraneConfiguration, solution: &IkSolution, hook_load_kg: f32) -> bool {
+        if config.spec.tower.is_some() {
+            return true;
+        }
+
+        [&solution.pick, &solution.set].into_iter().all(|pose| {
+            let mut posed = config.clone();
+            solution.apply(&mut posed, pose);
+
+            [hook_load_kg, 0.0].into_iter().all(|load| {
+                if posed.spec.crawler.is_some() {
+                    return CrawlerCalculator::ground_pressure(&posed, load)
+                        .is_ok_and(|p| p.is_stable);
+                }
+
+                GroundBearingCalculator::outrigger_support_points(
+                    &posed,
+                    load,
+                    1.0,
+                    PadMaterial::Steel,
+                )
+                .is_ok_and(|supports| supports.iter().all(|s| s.load_kg > 0.0))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane_data::{test_library, test_spec};
+
+    fn lift(load_kg: f32) -> LiftRequirement {
+        LiftRequirement {
+            load_kg,
+            rigging_weight_kg: 500.0,
+            pick: LiftTarget::above(Point3::new(0.0, 12.0, 0.0), 3.0),
+            set: LiftTarget::above(Point3::new(12.0, 0.0, 0.0), 5.0),
+            crane_position: Point3::origin(),
+            heading_deg: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_fleet_sorted_by_size() {
+        let fleet: Vec<CraneSpec> = test_library().specs().cloned().collect();
+        let selections =
+            CraneSelector::select(&fleet, &lift(8_000.0), &SelectionOptions::default());
+
+        assert_eq!(selections.len(), fleet.len());
+        let feasible: Vec<_> = selections.iter().filter(|s| s.feasible).collect();
+        assert!(!feasible.is_empty());
+        assert!(
+            feasible
+                .windows(2)
+                .all(|w| w[0].max_capacity_kg <= w[1].max_capacity_kg)
+        );
+
+        // Feasible cranes come first and carry a configuration
+        let first_infeasible = selections.iter().position(|s| !s.feasible);
+        if let Some(i) = first_infeasible {
+            assert!(
+                selections[i..]
+                    .iter()
+                    .all(|s| !s.feasible && s.reason.is_some())
+            );
+        }
+        for selection in feasible {
+            assert!(selection.configuration.is_some());
+            assert!(selection.utilization_percent.unwrap() <= 75.0);
+        }
+    }
+
+    #[test]
+    fn test_counterweight_counts_at_reduced_span() {
+        let spec = test_spec("liebherr_ltm_1100_5_2");
+        let lift = lift(8_000.0);
+        let utilization = |setup: OutriggerSetup, slabs: usize| {
+            let mut config = CraneConfiguration::new(spec.clone());
+            setup.apply(&mut config);
+            config.counterweight.set_slab_count(slabs).unwrap();
+            IkSolver::solve(&config, &lift.pick, &lift.set, lift.hook_load_kg())[0]
+                .utilization_percent
+        };
+
+        // Short legs are derated by stability, which the slabs improve
+        let light = utilization(OutriggerSetup::Intermediate, 1);
+        let ballasted = utilization(OutriggerSetup::Intermediate, spec.counterweight_max_slabs);
+        assert!(ballasted < light - 1.0, "{} vs {}", ballasted, light);
+
+        // The selection reports the utilisation for the counterweight it picked
+        let selection = CraneSelector::evaluate(&spec, &lift, &SelectionOptions::default());
+        let reported = selection.utilization_percent.unwrap();
+        let setup = selection.outrigger_setup.unwrap();
+        let solved = utilization(setup, selection.counterweight_slabs);
+        assert!((reported - solved).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_heavier_lift_needs_more_crane() {
+        let fleet: Vec<CraneSpec> = test_library().specs().cloned().collect();
+        let options = SelectionOptions::default();
+
+        let light = CraneSelector::smallest(&fleet, &lift(5_000.0), &options).unwrap();
+        let heavy = CraneSelector::smallest(&fleet, &lift(40_000.0), &options).unwrap();
+        assert!(heavy.max_capacity_kg >= light.max_capacity_kg);
+
+        let impossible = CraneSelector::smallest(&fleet, &lift(2_000_000.0), &options);
+        assert!(impossible.is_none());
+    }
+
+    #[test]
+    fn test_cost_order() {
+        // Same model from two rental yards
+        let mut other_yard = test_spec("liebherr_ltm_1100_5_2");
+        other_yard.id = "liebherr_ltm_1100_yard_b".to_string();
+        let fleet = vec![test_spec("liebherr_ltm_1100_5_2"), other_yard];
+        let mut options = SelectionOptions {
+            order: SelectionOrder::Cost,
+            ..Default::default()
+        };
+        options.day_rates.insert(fleet[0].id.clone(), 9_000.0);
+        options.day_rates.insert(fleet[1].id.clone(), 4_000.0);
+
+        let selections = CraneSelector::select(&fleet, &lift(5_000.0), &options);
+        assert!(selections.iter().all(|s| s.feasible));
+        assert_eq!(selections[0].spec_id, fleet[1].id);
+    }
+}
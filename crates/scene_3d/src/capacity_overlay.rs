@@ -0,0 +1,154 @@
+// crates/scene_3d/src/capacity_overlay.rs
+
+use bevy::{
+    asset::RenderAssetUsages,
+    mesh::{Indices, PrimitiveTopology},
+    prelude::*,
+};
+use crane_core::{CapacityContour, CapacityContourCalculator, ContourOptions, CraneConfiguration};
+
+use crate::components::Crane;
+
+/// Height of the overlay above the ground, clear of the grid lines
+const OVERLAY_LIFT_M: f32 = 0.03;
+/// Width of the outline drawn at the placement limits
+const OUTLINE_WIDTH_M: f32 = 0.3;
+
+/// Settings for the capacity contour drawn on the ground
+#[derive(Resource)]
+pub struct CapacityOverlay {
+    pub enabled: bool,
+    pub load_kg: f32,
+    /// Configuration to map; the spawned crane's when None
+    pub config: Option<CraneConfiguration>,
+    /// Last contour drawn
+    pub contour: Option<CapacityContour>,
+}
+
+impl Default for CapacityOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            load_kg: 8000.0,
+            config: None,
+            contour: None,
+        }
+    }
+}
+
+/// Marker for the overlay meshes
+#[derive(Component)]
+pub struct CapacityOverlayVisual;
+
+/// Rebuild the contour when the overlay settings or the crane change
+pub fn update_capacity_overlay_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut overlay: ResMut<CapacityOverlay>,
+    crane_query: Query<Ref<Crane>>,
+    visual_query: Query<Entity, With<CapacityOverlayVisual>>,
+) {
+    let crane = crane_query.single().ok();
+    let crane_changed = crane.as_ref().is_some_and(|c| c.is_changed()) && overlay.config.is_none();
+    if !overlay.is_changed() && !crane_changed {
+        return;
+    }
+
+    for entity in visual_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if !overlay.enabled {
+        overlay.bypass_change_detection().contour = None;
+        return;
+    }
+    let config = match (&overlay.config, &crane) {
+        (Some(config), _) => config.clone(),
+        (None, Some(crane)) => crane.config.clone(),
+        (None, None) => return,
+    };
+
+    let contour =
+        CapacityContourCalculator::calculate(&config, overlay.load_kg, &ContourOptions::default());
+
+    let fill = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.2, 0.8, 0.3, 0.3),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        double_sided: true,
+        cull_mode: None,
+        ..default()
+    });
+    let outline = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.1, 0.6, 0.2, 0.9),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        double_sided: true,
+        cull_mode: None,
+        ..default()
+    });
+
+    commands.spawn((
+        Mesh3d(meshes.add(contour_band_mesh(&contour, None))),
+        MeshMaterial3d(fill),
+        Transform::default(),
+        CapacityOverlayVisual,
+        Name::new("Capacity Contour"),
+    ));
+    commands.spawn((
+        Mesh3d(meshes.add(contour_band_mesh(&contour, Some(OUTLINE_WIDTH_M)))),
+        MeshMaterial3d(outline),
+        Transform::default(),
+        CapacityOverlayVisual,
+        Name::new("Capacity Contour Outline"),
+    ));
+
+    overlay.bypass_change_detection().contour = Some(contour);
+}
+
+/// Ground mesh of each sector's radius band, or with `outline` only
+/// strips of that width inside the inner and outer limits
+fn contour_band_mesh(contour: &CapacityContour, outline: Option<f32>) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    let mut add_band = |start_deg: f32, end_deg: f32, inner: f32, outer: f32| {
+        let base = positions.len() as u32;
+        for (swing, radius) in [
+            (start_deg, inner),
+            (start_deg, outer),
+            (end_deg, outer),
+            (end_deg, inner),
+        ] {
+            let p = contour.ground_point(swing, radius);
+            positions.push([p.x, p.z + OVERLAY_LIFT_M, p.y]);
+        }
+        indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+    };
+
+    for sector in &contour.sectors {
+        let Some((min, max)) = sector.radius_m else {
+            continue;
+        };
+        match outline {
+            None => add_band(sector.start_deg, sector.end_deg, min, max),
+            Some(width) => {
+                let width = width.min((max - min) / 2.0);
+                add_band(sector.start_deg, sector.end_deg, max - width, max);
+                if min > 0.0 {
+                    add_band(sector.start_deg, sector.end_deg, min, min + width);
+                }
+            }
+        }
+    }
+
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_indices(Indices::U32(indices))
+}
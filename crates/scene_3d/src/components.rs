@@ -27,6 +27,7 @@ pub struct Crane {
 pub enum CraneVisualPart {
     Body,
     Boom,
+    Jib,
     Cable,
     Hook,
     Outrigger(crane_core::OutriggerPosition),
@@ -40,6 +41,13 @@ pub struct LiftLoad {
     pub is_selected: bool,
 }
 
+/// Individual part of a composite load
+#[derive(Component)]
+pub struct LoadPartVisual {
+    pub name: String,
+    pub mass_kg: f32,
+}
+
 /// Pick point marker
 #[derive(Component)]
 pub struct PickPoint {
@@ -124,14 +124,17 @@ pub fn spawn_crane(
         boom_pivot_height + (crane_config.boom_length_m / 2.0) * boom_angle_rad.sin();
     let boom_center_z = (crane_config.boom_length_m / 2.0) * boom_angle_rad.cos();
 
-    let boom_mesh = meshes.add(Cylinder::new(0.4, crane_config.boom_length_m));
+    // Unit-length mesh scaled to the boom length so telescoping can animate
+    let boom_mesh = meshes.add(Cylinder::new(0.4, 1.0));
     let boom_entity = commands
         .spawn((
             Mesh3d(boom_mesh),
-            MeshMaterial3d(boom_material),
-            Transform::from_xyz(0.0, boom_center_y, boom_center_z).with_rotation(
-                Quat::from_rotation_x(std::f32::consts::FRAC_PI_2 - boom_angle_rad),
-            ),
+            MeshMaterial3d(boom_material.clone()),
+            Transform::from_xyz(0.0, boom_center_y, boom_center_z)
+                .with_rotation(Quat::from_rotation_x(
+                    std::f32::consts::FRAC_PI_2 - boom_angle_rad,
+                ))
+                .with_scale(Vec3::new(1.0, crane_config.boom_length_m, 1.0)),
             CraneVisualPart::Boom,
             Name::new("Boom"),
         ))
@@ -143,11 +146,13 @@ pub fn spawn_crane(
         crane_config.boom_length_m, crane_config.boom_angle_deg, boom_pivot_height
     );
 
-    // Get boom tip and hook positions using crane_core
+    // Get boom tip, sheave and hook positions using crane_core
     let boom_tip = crane_config.get_boom_tip_position();
+    let sheave = crane_config.get_sheave_position();
     let hook_pos = crane_config.get_hook_position();
 
     let boom_tip_bevy = Vec3::new(boom_tip.x, boom_tip.z, boom_tip.y);
+    let sheave_bevy = Vec3::new(sheave.x, sheave.z, sheave.y);
     let hook_pos_bevy = Vec3::new(hook_pos.x, hook_pos.z, hook_pos.y);
 
     println!(
@@ -155,20 +160,47 @@ pub fn spawn_crane(
         boom_tip_bevy.x, boom_tip_bevy.y, boom_tip_bevy.z
     );
 
-    // Hoist cable
-    let cable_direction = hook_pos_bevy - boom_tip_bevy;
+    // Jib (lattice, from boom tip to jib tip)
+    if let (Some(jib_state), Some(jib_tip)) =
+        (&crane_config.jib, crane_config.get_jib_tip_position())
+    {
+        let jib_tip_bevy = Vec3::new(jib_tip.x, jib_tip.z, jib_tip.y);
+
+        // Unit-length mesh scaled to the jib length, like the boom
+        let jib_mesh = meshes.add(Cuboid::new(0.5, 1.0, 0.5));
+        let jib_entity = commands
+            .spawn((
+                Mesh3d(jib_mesh),
+                MeshMaterial3d(boom_material.clone()),
+                jib_transform(boom_tip_bevy, jib_tip_bevy),
+                CraneVisualPart::Jib,
+                Name::new("Jib"),
+            ))
+            .id();
+        commands.entity(crane_entity).add_child(jib_entity);
+
+        println!(
+            "✓ Jib: {:.1}m at {:.0}° offset",
+            jib_state.length_m, jib_state.offset_deg
+        );
+    }
+
+    // Hoist cable (from the jib tip when a jib is rigged)
+    let cable_direction = hook_pos_bevy - sheave_bevy;
     let cable_length = cable_direction.length();
 
     if cable_length > 0.1 {
-        let cable_midpoint = (boom_tip_bevy + hook_pos_bevy) / 2.0;
-        let cable_mesh = meshes.add(Cylinder::new(0.05, cable_length));
+        let cable_midpoint = (sheave_bevy + hook_pos_bevy) / 2.0;
+        let cable_mesh = meshes.add(Cylinder::new(0.05, 1.0));
         let cable_rotation = Quat::from_rotation_arc(Vec3::Y, cable_direction.normalize());
 
         let cable_entity = commands
             .spawn((
                 Mesh3d(cable_mesh),
                 MeshMaterial3d(cable_material),
-                Transform::from_translation(cable_midpoint).with_rotation(cable_rotation),
+                Transform::from_translation(cable_midpoint)
+                    .with_rotation(cable_rotation)
+                    .with_scale(Vec3::new(1.0, cable_length, 1.0)),
                 CraneVisualPart::Cable,
                 Name::new("Hoist Cable"),
             ))
@@ -269,16 +301,42 @@ fn spawn_outrigger(
     system: &crane_core::OutriggerSystem,
     parent: Entity,
 ) {
-    // Get contact point in crane local space
+    // Float centre and beam root in crane local space
     let contact_point = outrigger.get_contact_point(system.crane_base_width_m);
+    let beam_root = outrigger.get_beam_root(system.crane_base_width_m);
 
-    // Convert to Bevy coordinates
-    let pos = Vec3::new(contact_point.x, 0.1, contact_point.y);
+    // Float sits on its cribbing stack at the local ground elevation
+    let pad_base = outrigger.ground_elevation_m + outrigger.cribbing_m;
+    let pos = Vec3::new(contact_point.x, pad_base + 0.1, contact_point.y);
+    let beam_height = 2.0;
 
     // Outrigger pad
     let pad_radius = outrigger.pad_diameter_m.unwrap_or(0.6) / 2.0;
     let pad_mesh = meshes.add(Cylinder::new(pad_radius, 0.2));
 
+    if outrigger.cribbing_m > 0.0 {
+        let cribbing_mesh = meshes.add(Cuboid::new(
+            pad_radius * 3.0,
+            outrigger.cribbing_m,
+            pad_radius * 3.0,
+        ));
+
+        let cribbing_entity = commands
+            .spawn((
+                Mesh3d(cribbing_mesh),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(
+                    contact_point.x,
+                    outrigger.ground_elevation_m + outrigger.cribbing_m / 2.0,
+                    contact_point.y,
+                ),
+                Name::new(format!("Outrigger Cribbing {:?}", outrigger.position)),
+            ))
+            .id();
+
+        commands.entity(parent).add_child(cribbing_entity);
+    }
+
     let outrigger_entity = commands
         .spawn((
             Mesh3d(pad_mesh),
@@ -294,23 +352,45 @@ fn spawn_outrigger(
 
     commands.entity(parent).add_child(outrigger_entity);
 
-    // Outrigger beam (horizontal extension)
-    let beam_length = (contact_point.x.powi(2) + contact_point.y.powi(2)).sqrt();
-    let beam_mesh = meshes.add(Cuboid::new(0.3, 0.3, beam_length));
+    // Outrigger beam from the box on the carrier out to the jack
+    let beam_start = Vec3::new(beam_root.x, beam_height, beam_root.y);
+    let beam_end = Vec3::new(contact_point.x, beam_height, contact_point.y);
+    let beam = beam_end - beam_start;
 
-    let beam_angle = contact_point.y.atan2(contact_point.x);
-    let beam_pos = Vec3::new(contact_point.x / 2.0, 2.0, contact_point.y / 2.0);
+    if beam.length() > 0.01 {
+        let beam_mesh = meshes.add(Cuboid::new(0.3, 0.3, beam.length()));
 
-    let beam_entity = commands
+        let beam_entity = commands
+            .spawn((
+                Mesh3d(beam_mesh),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation((beam_start + beam_end) / 2.0)
+                    .with_rotation(Quat::from_rotation_arc(Vec3::Z, beam.normalize())),
+                Name::new(format!("Outrigger Beam {:?}", outrigger.position)),
+            ))
+            .id();
+
+        commands.entity(parent).add_child(beam_entity);
+    }
+
+    // Jack cylinder down to the float
+    let jack_length = (beam_height - pad_base - 0.2).max(0.1);
+    let jack_mesh = meshes.add(Cylinder::new(0.15, jack_length));
+
+    let jack_entity = commands
         .spawn((
-            Mesh3d(beam_mesh),
+            Mesh3d(jack_mesh),
             MeshMaterial3d(material),
-            Transform::from_translation(beam_pos).with_rotation(Quat::from_rotation_y(beam_angle)),
-            Name::new(format!("Outrigger Beam {:?}", outrigger.position)),
+            Transform::from_xyz(
+                contact_point.x,
+                pad_base + 0.2 + jack_length / 2.0,
+                contact_point.y,
+            ),
+            Name::new(format!("Outrigger Jack {:?}", outrigger.position)),
         ))
         .id();
 
-    commands.entity(parent).add_child(beam_entity);
+    commands.entity(parent).add_child(jack_entity);
 }
 
 fn spawn_counterweight(
@@ -349,40 +429,73 @@ fn spawn_counterweight(
     }
 }
 
+/// Transform stretching the unit jib mesh from the boom tip to the jib tip
+fn jib_transform(boom_tip: Vec3, jib_tip: Vec3) -> Transform {
+    let jib_dir = jib_tip - boom_tip;
+    let jib_length = jib_dir.length();
+
+    let mut transform = Transform::from_translation((boom_tip + jib_tip) / 2.0)
+        .with_scale(Vec3::new(1.0, jib_length.max(0.01), 1.0));
+    if jib_length > 0.01 {
+        transform.rotation = Quat::from_rotation_arc(Vec3::Y, jib_dir / jib_length);
+    }
+    transform
+}
+
 /// System that updates crane visuals when configuration changes
+///
+/// The jib is spawned or despawned as it is rigged or removed.
 pub fn update_crane_visuals_system(
-    crane_query: Query<(&Crane, &Children), Changed<Crane>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    crane_query: Query<(Entity, &Crane, &Children), Changed<Crane>>,
     mut part_query: Query<(&mut Transform, &CraneVisualPart)>,
+    material_query: Query<&MeshMaterial3d<StandardMaterial>>,
 ) {
-    for (crane, children) in crane_query.iter() {
+    for (crane_entity, crane, children) in crane_query.iter() {
         let config = &crane.config;
 
-        let boom_tip = config.get_boom_tip_position();
-        let hook_pos = config.get_hook_position();
+        // Parts are children of the crane root, which sits at the crane position
+        let to_local = |p: nalgebra::Point3<f32>| {
+            let offset = p - config.position;
+            Vec3::new(offset.x, offset.z, offset.y)
+        };
 
-        let boom_tip_bevy = Vec3::new(boom_tip.x, boom_tip.z, boom_tip.y);
-        let hook_pos_bevy = Vec3::new(hook_pos.x, hook_pos.z, hook_pos.y);
+        let pivot_bevy = Vec3::new(0.0, config.spec.boom_pivot_height_m, 0.0);
+        let boom_tip_bevy = to_local(config.get_boom_tip_position());
+        let sheave_bevy = to_local(config.get_sheave_position());
+        let hook_pos_bevy = to_local(config.get_hook_position());
 
-        let boom_pivot_height = config.spec.boom_pivot_height_m;
-        let boom_angle_rad = config.boom_angle_deg.to_radians();
-        let boom_center_y = boom_pivot_height + (config.boom_length_m / 2.0) * boom_angle_rad.sin();
-        let boom_center_z = (config.boom_length_m / 2.0) * boom_angle_rad.cos();
+        let mut boom_entity = None;
+        let mut has_jib = false;
 
         for child in children.iter() {
             if let Ok((mut transform, part)) = part_query.get_mut(child) {
                 match part {
                     CraneVisualPart::Boom => {
-                        transform.translation = Vec3::new(0.0, boom_center_y, boom_center_z);
-                        transform.rotation =
-                            Quat::from_rotation_x(std::f32::consts::FRAC_PI_2 - boom_angle_rad);
+                        let boom_dir = boom_tip_bevy - pivot_bevy;
+
+                        transform.translation = (pivot_bevy + boom_tip_bevy) / 2.0;
+                        transform.rotation = Quat::from_rotation_arc(Vec3::Y, boom_dir.normalize());
+                        transform.scale.y = config.boom_length_m;
+                        boom_entity = Some(child);
+                    }
+                    CraneVisualPart::Jib => {
+                        if config.jib.is_some() {
+                            *transform = jib_transform(boom_tip_bevy, sheave_bevy);
+                            has_jib = true;
+                        } else {
+                            commands.entity(child).despawn();
+                        }
                     }
                     CraneVisualPart::Cable => {
-                        let cable_dir = hook_pos_bevy - boom_tip_bevy;
-                        let cable_midpoint = (boom_tip_bevy + hook_pos_bevy) / 2.0;
+                        let cable_dir = hook_pos_bevy - sheave_bevy;
+                        let cable_midpoint = (sheave_bevy + hook_pos_bevy) / 2.0;
 
                         transform.translation = cable_midpoint;
                         transform.rotation =
                             Quat::from_rotation_arc(Vec3::Y, cable_dir.normalize());
+                        transform.scale.y = cable_dir.length();
                     }
                     CraneVisualPart::Hook => {
                         transform.translation = hook_pos_bevy;
@@ -391,5 +504,22 @@ pub fn update_crane_visuals_system(
                 }
             }
         }
+
+        // Jib newly rigged: spawn it with the boom's material
+        if config.jib.is_some() && !has_jib {
+            let Some(material) = boom_entity.and_then(|boom| material_query.get(boom).ok()) else {
+                continue;
+            };
+            let jib_entity = commands
+                .spawn((
+                    Mesh3d(meshes.add(Cuboid::new(0.5, 1.0, 0.5))),
+                    material.clone(),
+                    jib_transform(boom_tip_bevy, sheave_bevy),
+                    CraneVisualPart::Jib,
+                    Name::new("Jib"),
+                ))
+                .id();
+            commands.entity(crane_entity).add_child(jib_entity);
+        }
     }
 }
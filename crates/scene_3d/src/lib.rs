@@ -1,20 +1,24 @@
 use bevy::prelude::*;
 
 mod camera;
+mod capacity_overlay;
 mod components;
 mod coordinate_conversion;
 mod crane_renderer;
 mod input;
 mod load_renderer;
+mod playback;
 mod resources;
 mod setup;
 mod sling_renderer;
 mod test_scene;
 
+pub use capacity_overlay::*;
 pub use components::*;
 pub use coordinate_conversion::*;
 pub use crane_renderer::*;
 pub use load_renderer::*;
+pub use playback::*;
 pub use resources::*;
 pub use setup::*;
 pub use sling_renderer::*;
@@ -26,7 +30,10 @@ impl Plugin for Scene3DPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SceneState>()
             .init_resource::<CameraController>()
-            .init_resource::<InteractionState>();
+            .init_resource::<InteractionState>()
+            .init_resource::<SiteObstacles>()
+            .init_resource::<LiftTimeline>()
+            .init_resource::<CapacityOverlay>();
         app.add_plugins(input::Scene3dInputPlugin)
             .add_systems(
                 Startup,
@@ -43,8 +50,16 @@ impl Plugin for Scene3DPlugin {
                     camera::camera_controller::update_camera_transform,
                     camera::camera_controller::apply_camera_momentum,
                     camera::camera_controller::camera_preset_views,
-                    // Crane updates
-                    crane_renderer::update_crane_visuals_system,
+                    // Lift playback drives the crane before its visuals update
+                    (
+                        playback::playback_keyboard_system,
+                        playback::advance_timeline_system,
+                        playback::apply_timeline_system,
+                        playback::highlight_failing_frame_system,
+                        crane_renderer::update_crane_visuals_system,
+                    )
+                        .chain(),
+                    capacity_overlay::update_capacity_overlay_system,
                     // Load updates
                     load_renderer::update_load_visual_system,
                     load_renderer::highlight_selected_loads_system,
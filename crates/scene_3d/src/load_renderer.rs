@@ -3,6 +3,7 @@
 use crate::components::*;
 use crate::coordinate_conversion::*;
 use bevy::prelude::*;
+use crane_core::composite_load::{CompositeLoad, CylinderAxis, LoadPart, PartShape};
 use crane_core::rigging::Load;
 
 /// Spawn a load at the origin (will be positioned by parent transform)
@@ -122,6 +123,171 @@ pub fn spawn_load_at_position(
     load_entity
 }
 
+/// Spawn a composite load with each part rendered individually
+///
+/// The entity is placed at the load origin; parts, pick points and the CoG
+/// marker are positioned relative to it.
+pub fn spawn_composite_load_at_position(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    composite: &CompositeLoad,
+    position: Vec3,
+) -> Result<Entity, String> {
+    let load_data = composite.to_load()?;
+
+    println!("\n--- Spawning Composite Load: {} ---", composite.name);
+
+    let part_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.7, 0.5, 0.3),
+        metallic: 0.2,
+        perceptual_roughness: 0.8,
+        reflectance: 0.3,
+        ..default()
+    });
+
+    let point_mass_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.3, 0.3, 1.0),
+        emissive: LinearRgba::rgb(0.0, 0.0, 0.5),
+        metallic: 0.5,
+        perceptual_roughness: 0.3,
+        ..default()
+    });
+
+    let pick_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.3, 1.0, 0.3),
+        emissive: LinearRgba::rgb(0.0, 0.5, 0.0),
+        metallic: 0.7,
+        perceptual_roughness: 0.3,
+        reflectance: 0.6,
+        ..default()
+    });
+
+    let cog_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.3, 0.3),
+        emissive: LinearRgba::rgb(0.5, 0.0, 0.0),
+        metallic: 0.5,
+        perceptual_roughness: 0.3,
+        reflectance: 0.6,
+        ..default()
+    });
+
+    let load_entity = commands
+        .spawn((
+            Transform::from_translation(position),
+            Visibility::default(),
+            LiftLoad {
+                load_data: load_data.clone(),
+                is_selected: false,
+            },
+            Name::new(format!("{} ({:.0}kg)", composite.name, load_data.weight_kg)),
+        ))
+        .id();
+
+    for part in &composite.parts {
+        let material = match part.shape {
+            PartShape::PointMass => point_mass_material.clone(),
+            _ => part_material.clone(),
+        };
+        spawn_load_part(commands, meshes, material, part, load_entity);
+    }
+
+    println!(
+        "✓ Composite load: {} parts, {:.0}kg",
+        composite.parts.len(),
+        load_data.weight_kg
+    );
+    println!(
+        "  CoG: [{:.2}, {:.2}, {:.2}]",
+        load_data.center_of_gravity.x, load_data.center_of_gravity.y, load_data.center_of_gravity.z
+    );
+
+    for pick_point in load_data.pick_points.iter().filter(|pp| pp.active) {
+        spawn_pick_point(
+            commands,
+            meshes,
+            pick_material.clone(),
+            pick_point,
+            load_entity,
+        );
+    }
+
+    spawn_cog_marker(
+        commands,
+        meshes,
+        materials,
+        cog_material,
+        load_data.center_of_gravity,
+        load_entity,
+    );
+
+    // Bounding box is centred on the parts, not on the load origin
+    if let Some((min, max)) = composite.bounding_box() {
+        let center = nalgebra::center(&min, &max).to_bevy();
+        let bbox_entity = commands
+            .spawn((
+                Transform::from_translation(center),
+                Visibility::default(),
+                Name::new("Bounding Box"),
+            ))
+            .id();
+        commands.entity(load_entity).add_child(bbox_entity);
+
+        let dims_bevy = nalgebra_to_bevy_vector(max - min);
+        spawn_bounding_box(commands, meshes, materials, dims_bevy, bbox_entity);
+    }
+
+    Ok(load_entity)
+}
+
+/// Spawn a single composite load part as child of load
+fn spawn_load_part(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    material: Handle<StandardMaterial>,
+    part: &LoadPart,
+    parent: Entity,
+) {
+    let (mesh, rotation) = match &part.shape {
+        PartShape::Box { dimensions } => {
+            let dims_bevy = nalgebra_to_bevy_vector(*dimensions);
+            (
+                meshes.add(Cuboid::new(dims_bevy.x, dims_bevy.y, dims_bevy.z)),
+                Quat::IDENTITY,
+            )
+        }
+        PartShape::Cylinder {
+            radius_m,
+            length_m,
+            axis,
+        } => {
+            // Bevy cylinders are built along +Y (engineering Z)
+            let rotation = match axis {
+                CylinderAxis::X => Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+                CylinderAxis::Y => Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+                CylinderAxis::Z => Quat::IDENTITY,
+            };
+            (meshes.add(Cylinder::new(*radius_m, *length_m)), rotation)
+        }
+        PartShape::PointMass => (meshes.add(Sphere::new(0.15)), Quat::IDENTITY),
+    };
+
+    let part_entity = commands
+        .spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_translation(part.position.to_bevy()).with_rotation(rotation),
+            LoadPartVisual {
+                name: part.name.clone(),
+                mass_kg: part.mass_kg,
+            },
+            Name::new(format!("Load Part: {}", part.name)),
+        ))
+        .id();
+
+    commands.entity(parent).add_child(part_entity);
+}
+
 /// Spawn a pick point as child of load
 fn spawn_pick_point(
     commands: &mut Commands,
@@ -0,0 +1,598 @@
+// crates/scene_3d/src/playback.rs
+
+use crate::components::*;
+use crate::coordinate_conversion::*;
+use bevy::prelude::*;
+use crane_core::{
+    CollisionChecker, CollisionLoad, CollisionObstacle, CollisionOptions, CraneConfiguration,
+    CraneState, CrawlerCalculator, GroundBearingCalculator, GroundConfiguration, LiftPath,
+    PadMaterial, SoilType,
+};
+
+/// Limits each keyframe is checked against
+#[derive(Debug, Clone)]
+pub struct PlaybackChecks {
+    pub max_utilization_percent: f32,
+    pub soil_type: SoilType,
+    pub soil_safety_factor: f32,
+    pub pad_diameter_m: f32,
+    pub pad_material: PadMaterial,
+    pub collision: CollisionOptions,
+    /// Spacing of the checked frames between user steps
+    pub sample_interval_s: f32,
+}
+
+impl Default for PlaybackChecks {
+    fn default() -> Self {
+        Self {
+            max_utilization_percent: 100.0,
+            soil_type: SoilType::MediumGravel,
+            soil_safety_factor: 1.0,
+            pad_diameter_m: 1.0,
+            pad_material: PadMaterial::Composite,
+            collision: CollisionOptions::default(),
+            sample_interval_s: 0.5,
+        }
+    }
+}
+
+/// Capacity, clearance and ground pressure results for one keyframe
+#[derive(Debug, Clone, Default)]
+pub struct FrameCheck {
+    pub utilization_percent: Option<f32>,
+    pub min_clearance_m: Option<f32>,
+    pub max_ground_pressure_kpa: Option<f32>,
+    pub ground_utilization_percent: Option<f32>,
+    pub capacity_failed: bool,
+    pub clearance_failed: bool,
+    pub ground_failed: bool,
+    /// Reasons for each failed check
+    pub failures: Vec<String>,
+}
+
+impl FrameCheck {
+    /// Check one crane state
+    pub fn evaluate(
+        config: &CraneConfiguration,
+        load_kg: f32,
+        load: Option<&CollisionLoad>,
+        obstacles: &[CollisionObstacle],
+        checks: &PlaybackChecks,
+    ) -> Self {
+        let mut frame = FrameCheck::default();
+
+        // Capacity
+        match config.get_loaded_capacity(load_kg).filter(|c| *c > 0.0) {
+            Some(capacity) => {
+                let utilization = load_kg / capacity * 100.0;
+                frame.utilization_percent = Some(utilization);
+                if utilization > checks.max_utilization_percent {
+                    frame.capacity_failed = true;
+                    frame
+                        .failures
+                        .push(format!("Capacity: {:.0}% utilisation", utilization));
+                }
+            }
+            None => {
+                frame.capacity_failed = true;
+                frame.failures.push(format!(
+                    "Capacity: no chart value at {:.1}m",
+                    config.get_loaded_radius(load_kg)
+                ));
+            }
+        }
+
+        // Clearance
+        let clearances =
+            CollisionChecker::check_state(config, load, 0.0, obstacles, &checks.collision);
+        if let Some(((part, clearance), obstacle)) = clearances
+            .iter()
+            .zip(obstacles)
+            .min_by(|a, b| a.0.1.total_cmp(&b.0.1))
+        {
+            frame.min_clearance_m = Some(*clearance);
+            if *clearance <= checks.collision.required_clearance_m {
+                frame.clearance_failed = true;
+                frame.failures.push(format!(
+                    "Clearance: {} within {:.2}m of {}",
+                    part, clearance, obstacle.name
+                ));
+            }
+        }
+
+        // Ground pressure
+        if config.spec.crawler.is_some() {
+            match CrawlerCalculator::ground_pressure(config, load_kg) {
+                Ok(pressure) => {
+                    let utilization =
+                        pressure.utilization_percent(checks.soil_type, checks.soil_safety_factor);
+                    frame.max_ground_pressure_kpa = Some(pressure.max_pressure_kpa);
+                    frame.ground_utilization_percent = Some(utilization);
+                    if !pressure.is_stable || utilization > 100.0 {
+                        frame.ground_failed = true;
+                        frame.failures.push(format!(
+                            "Ground: {:.0} kPa under tracks{}",
+                            pressure.max_pressure_kpa,
+                            if pressure.is_stable { "" } else { ", unstable" }
+                        ));
+                    }
+                }
+                Err(e) => {
+                    frame.ground_failed = true;
+                    frame.failures.push(format!("Ground: {}", e));
+                }
+            }
+        } else if config.spec.tower.is_none() {
+            let analysis = GroundBearingCalculator::outrigger_support_points(
+                config,
+                load_kg,
+                checks.pad_diameter_m,
+                checks.pad_material,
+            )
+            .and_then(|support_points| {
+                let lifted = support_points.iter().any(|s| s.load_kg <= 0.0);
+                GroundBearingCalculator::analyze(&GroundConfiguration {
+                    support_points,
+                    soil_type: checks.soil_type,
+                    safety_factor: checks.soil_safety_factor,
+                })
+                .map(|analysis| (analysis, lifted))
+            });
+
+            match analysis {
+                Ok((analysis, lifted)) => {
+                    let max = analysis
+                        .bearing_pressures
+                        .iter()
+                        .max_by(|a, b| a.pressure_kpa.total_cmp(&b.pressure_kpa));
+                    frame.max_ground_pressure_kpa = max.map(|p| p.pressure_kpa);
+                    frame.ground_utilization_percent = max.map(|p| p.utilization_percent);
+
+                    if !analysis.is_safe {
+                        frame.ground_failed = true;
+                        frame.failures.push(format!(
+                            "Ground: {:.0} kPa under outrigger",
+                            frame.max_ground_pressure_kpa.unwrap_or(0.0)
+                        ));
+                    }
+                    if lifted {
+                        frame.ground_failed = true;
+                        frame
+                            .failures
+                            .push("Ground: outrigger lifts off".to_string());
+                    }
+                }
+                Err(e) => {
+                    frame.ground_failed = true;
+                    frame.failures.push(format!("Ground: {}", e));
+                }
+            }
+        }
+
+        frame
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Crane state at a point in the lift
+#[derive(Debug, Clone)]
+pub struct Keyframe {
+    pub time_s: f32,
+    pub state: CraneState,
+    pub check: FrameCheck,
+}
+
+/// Lift sequence played back in the scene
+#[derive(Resource)]
+pub struct LiftTimeline {
+    pub keyframes: Vec<Keyframe>,
+    /// Load carried through the lift; the scene load follows the hook when set
+    pub load: Option<CollisionLoad>,
+    pub current_time_s: f32,
+    pub playing: bool,
+    /// Playback rate (1.0 = real time)
+    pub speed: f32,
+    pub looping: bool,
+}
+
+impl Default for LiftTimeline {
+    fn default() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            load: None,
+            current_time_s: 0.0,
+            playing: false,
+            speed: 1.0,
+            looping: false,
+        }
+    }
+}
+
+impl LiftTimeline {
+    /// Timeline from `(time, state)` steps, checking every step
+    pub fn from_keyframes(
+        config: &CraneConfiguration,
+        steps: Vec<(f32, CraneState)>,
+        load_kg: f32,
+        load: Option<CollisionLoad>,
+        obstacles: &[CollisionObstacle],
+        checks: &PlaybackChecks,
+    ) -> Self {
+        let mut posed = config.clone();
+        let mut keyframes: Vec<Keyframe> = steps
+            .into_iter()
+            .map(|(time_s, state)| {
+                posed.apply_state(&state);
+                Keyframe {
+                    time_s,
+                    state,
+                    check: FrameCheck::evaluate(&posed, load_kg, load.as_ref(), obstacles, checks),
+                }
+            })
+            .collect();
+        keyframes.sort_by(|a, b| a.time_s.total_cmp(&b.time_s));
+
+        Self {
+            keyframes,
+            load,
+            ..Default::default()
+        }
+    }
+
+    /// Timeline from a planned path
+    pub fn from_path(
+        config: &CraneConfiguration,
+        path: &LiftPath,
+        load_kg: f32,
+        load: Option<CollisionLoad>,
+        obstacles: &[CollisionObstacle],
+        checks: &PlaybackChecks,
+    ) -> Self {
+        let steps = path
+            .trajectory
+            .iter()
+            .map(|p| (p.time_s, p.state))
+            .collect();
+        Self::from_keyframes(config, steps, load_kg, load, obstacles, checks)
+    }
+
+    /// User-authored steps played one after another, `step_duration_s` apart
+    ///
+    /// Moves between steps are sampled every `checks.sample_interval_s` so
+    /// the checks cover the whole motion, not just the steps.
+    pub fn from_steps(
+        config: &CraneConfiguration,
+        states: &[CraneState],
+        step_duration_s: f32,
+        load_kg: f32,
+        load: Option<CollisionLoad>,
+        obstacles: &[CollisionObstacle],
+        checks: &PlaybackChecks,
+    ) -> Self {
+        let samples = (step_duration_s / checks.sample_interval_s.max(0.01))
+            .ceil()
+            .max(1.0) as usize;
+
+        let mut steps: Vec<(f32, CraneState)> =
+            states.first().map(|s| (0.0, *s)).into_iter().collect();
+        for (i, pair) in states.windows(2).enumerate() {
+            let start_s = i as f32 * step_duration_s;
+            steps.extend((1..=samples).map(|k| {
+                let t = k as f32 / samples as f32;
+                (start_s + step_duration_s * t, pair[0].lerp(&pair[1], t))
+            }));
+        }
+        Self::from_keyframes(config, steps, load_kg, load, obstacles, checks)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    pub fn duration_s(&self) -> f32 {
+        self.keyframes.last().map(|k| k.time_s).unwrap_or(0.0)
+    }
+
+    pub fn play(&mut self) {
+        if self.current_time_s >= self.duration_s() {
+            self.current_time_s = 0.0;
+        }
+        self.playing = !self.is_empty();
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    pub fn toggle(&mut self) {
+        if self.playing {
+            self.pause();
+        } else {
+            self.play();
+        }
+    }
+
+    /// Jump to a time (clamped to the timeline)
+    pub fn scrub(&mut self, time_s: f32) {
+        self.current_time_s = time_s.clamp(0.0, self.duration_s());
+    }
+
+    /// Step forward by `dt` of wall-clock time
+    pub fn advance(&mut self, dt: f32) {
+        if !self.playing {
+            return;
+        }
+
+        let duration = self.duration_s();
+        self.current_time_s += dt * self.speed;
+        if self.current_time_s >= duration {
+            if self.looping && duration > 0.0 {
+                self.current_time_s %= duration;
+            } else {
+                self.current_time_s = duration;
+                self.playing = false;
+            }
+        }
+    }
+
+    /// Index of the keyframe at or before `time_s`
+    pub fn frame_index_at(&self, time_s: f32) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(
+            self.keyframes
+                .partition_point(|k| k.time_s <= time_s)
+                .saturating_sub(1),
+        )
+    }
+
+    pub fn current_frame(&self) -> Option<&Keyframe> {
+        self.frame_index_at(self.current_time_s)
+            .map(|i| &self.keyframes[i])
+    }
+
+    /// Interpolated crane state at `time_s`
+    pub fn state_at(&self, time_s: f32) -> Option<CraneState> {
+        let i = self.frame_index_at(time_s)?;
+        let from = &self.keyframes[i];
+        let Some(to) = self.keyframes.get(i + 1) else {
+            return Some(from.state);
+        };
+
+        let span = (to.time_s - from.time_s).max(f32::EPSILON);
+        let t = ((time_s - from.time_s) / span).clamp(0.0, 1.0);
+        Some(from.state.lerp(&to.state, t))
+    }
+
+    /// Indices of keyframes that fail any check
+    pub fn failing_frames(&self) -> Vec<usize> {
+        self.keyframes
+            .iter()
+            .enumerate()
+            .filter(|(_, k)| !k.check.is_ok())
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Advance the timeline clock while playing
+pub fn advance_timeline_system(time: Res<Time>, mut timeline: ResMut<LiftTimeline>) {
+    if timeline.playing {
+        timeline.advance(time.delta_secs());
+    }
+}
+
+/// Space plays/pauses, arrows step a second, Backspace rewinds
+pub fn playback_keyboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut timeline: ResMut<LiftTimeline>,
+) {
+    if timeline.is_empty() {
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Space) {
+        timeline.toggle();
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        let t = timeline.current_time_s + 1.0;
+        timeline.scrub(t);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        let t = timeline.current_time_s - 1.0;
+        timeline.scrub(t);
+    }
+    if keyboard.just_pressed(KeyCode::Backspace) {
+        timeline.scrub(0.0);
+    }
+}
+
+/// Pose the crane (and carry the load) at the timeline's current time
+pub fn apply_timeline_system(
+    timeline: Res<LiftTimeline>,
+    mut crane_query: Query<&mut Crane>,
+    mut load_query: Query<&mut Transform, With<LiftLoad>>,
+) {
+    if !timeline.is_changed() {
+        return;
+    }
+    let Some(state) = timeline.state_at(timeline.current_time_s) else {
+        return;
+    };
+
+    for mut crane in crane_query.iter_mut() {
+        crane.config.apply_state(&state);
+
+        if let Some(load) = &timeline.load {
+            let hook = crane.config.get_hook_position().to_bevy();
+            let drop = load.rigging_height_m + load.height_m / 2.0;
+            for mut transform in load_query.iter_mut() {
+                transform.translation = hook - Vec3::Y * drop;
+            }
+        }
+    }
+}
+
+/// Tint the parts behind a failed check on the current frame
+pub fn highlight_failing_frame_system(
+    timeline: Res<LiftTimeline>,
+    crane_query: Query<&Children, With<Crane>>,
+    part_query: Query<(&CraneVisualPart, &MeshMaterial3d<StandardMaterial>)>,
+    outrigger_query: Query<&MeshMaterial3d<StandardMaterial>, With<OutriggerVisual>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if !timeline.is_changed() {
+        return;
+    }
+
+    let check = timeline
+        .current_frame()
+        .map(|k| k.check.clone())
+        .unwrap_or_default();
+    let warning = LinearRgba::rgb(0.8, 0.0, 0.0);
+
+    for children in crane_query.iter() {
+        for child in children.iter() {
+            let failed = if let Ok((part, _)) = part_query.get(child) {
+                match part {
+                    CraneVisualPart::Boom | CraneVisualPart::Jib => {
+                        check.capacity_failed || check.clearance_failed
+                    }
+                    CraneVisualPart::Cable | CraneVisualPart::Hook => check.clearance_failed,
+                    _ => continue,
+                }
+            } else if outrigger_query.contains(child) {
+                check.ground_failed
+            } else {
+                continue;
+            };
+
+            let handle = part_query
+                .get(child)
+                .map(|(_, m)| m)
+                .or_else(|_| outrigger_query.get(child));
+            if let Ok(handle) = handle
+                && let Some(material) = materials.get_mut(&handle.0)
+            {
+                material.emissive = if failed { warning } else { LinearRgba::BLACK };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crane_core::SpecRegistry;
+
+    fn crane() -> CraneConfiguration {
+        let library =
+            SpecRegistry::load(concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/cranes"));
+        let spec = library.get("liebherr_ltm_1100_5_2").unwrap().clone();
+        let mut config = CraneConfiguration::new(spec);
+        config.outriggers.preset_max_extension();
+        config.counterweight.preset_max().unwrap();
+        config.boom_length_m = 30.0;
+        config.boom_angle_deg = 60.0;
+        config
+    }
+
+    #[test]
+    fn test_playback_interpolates_and_stops() {
+        let config = crane();
+        let start = CraneState::from(&config);
+        let mut end = start;
+        end.swing_angle_deg = 90.0;
+
+        let mut timeline = LiftTimeline::from_steps(
+            &config,
+            &[start, end],
+            10.0,
+            5_000.0,
+            None,
+            &[],
+            &PlaybackChecks::default(),
+        );
+        assert_eq!(timeline.duration_s(), 10.0);
+
+        timeline.scrub(5.0);
+        let mid = timeline.state_at(timeline.current_time_s).unwrap();
+        assert!((mid.swing_angle_deg - 45.0).abs() < 1e-3);
+
+        timeline.play();
+        timeline.advance(20.0);
+        assert!(!timeline.playing);
+        assert_eq!(timeline.current_time_s, 10.0);
+        assert_eq!(
+            timeline.current_frame().unwrap().state.swing_angle_deg,
+            90.0
+        );
+    }
+
+    #[test]
+    fn test_failing_frames_flagged() {
+        let config = crane();
+        let near = CraneState::from(&config);
+        let mut far = near;
+        far.boom_angle_deg = 25.0;
+
+        let timeline = LiftTimeline::from_steps(
+            &config,
+            &[near, far],
+            5.0,
+            20_000.0,
+            None,
+            &[],
+            &PlaybackChecks::default(),
+        );
+
+        // Sampled every 0.5 s between the two steps
+        assert_eq!(timeline.keyframes.len(), 11);
+        let capacity_failed = |i: usize| timeline.keyframes[i].check.capacity_failed;
+        assert!(!capacity_failed(0));
+        assert!(capacity_failed(10));
+        let failing = timeline.failing_frames();
+        assert!(failing.contains(&10));
+    }
+
+    #[test]
+    fn test_clearance_checked_between_steps() {
+        let config = crane();
+        let start = CraneState::from(&config);
+        let mut end = start;
+        end.swing_angle_deg = 90.0;
+
+        // Mast under the boom tip half-way round the slew
+        let radius = config.get_radius();
+        let mast = CollisionObstacle::from_box(
+            "Mast",
+            nalgebra::Point3::new(radius * 0.707, radius * 0.707, 30.0),
+            nalgebra::Vector3::new(2.0, 2.0, 60.0),
+            0.0,
+        );
+
+        let timeline = LiftTimeline::from_steps(
+            &config,
+            &[start, end],
+            10.0,
+            5_000.0,
+            None,
+            std::slice::from_ref(&mast),
+            &PlaybackChecks::default(),
+        );
+
+        let clearance_failed = |k: &Keyframe| k.check.clearance_failed;
+        assert!(!clearance_failed(&timeline.keyframes[0]));
+        assert!(!clearance_failed(timeline.keyframes.last().unwrap()));
+        let hit = timeline
+            .keyframes
+            .iter()
+            .find(|k| clearance_failed(k))
+            .expect("mast should be hit during the slew");
+        assert!(hit.time_s > 2.0 && hit.time_s < 8.0);
+    }
+}
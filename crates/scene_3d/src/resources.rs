@@ -1,6 +1,7 @@
 // crates/scene_3d/src/resources.rs
 
 use bevy::prelude::*;
+use crane_core::CollisionObstacle;
 use crane_core::ground_bearing::*;
 use crane_core::rigging::*;
 
@@ -67,6 +68,12 @@ impl Default for SceneState {
     }
 }
 
+/// Obstacles on site that lifts are checked against
+#[derive(Resource, Default)]
+pub struct SiteObstacles {
+    pub obstacles: Vec<CollisionObstacle>,
+}
+
 #[derive(Resource)]
 pub struct CameraController {
     pub orbit_distance: f32,
@@ -1,12 +1,13 @@
 use crate::*;
-use crane_core::{CraneConfiguration, CraneSpec, rigging::*};
-use nalgebra::Point3;
+use crane_core::{CollisionObstacle, CraneConfiguration, CraneSpec, SiteObstacle, rigging::*};
+use nalgebra::{Point3, Vector3};
 
 pub fn spawn_test_scene(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut scene_state: ResMut<SceneState>,
+    mut site: ResMut<SiteObstacles>,
 ) {
     println!("\n╔═══════════════════════════════════════════╗");
     println!("║     CRANE LIFT PLANNER - TEST SCENE      ║");
@@ -14,7 +15,11 @@ pub fn spawn_test_scene(
 
     // ========== CRANE SETUP ==========
 
-    let crane_spec = CraneSpec::liebherr_ltm_1100();
+    // The UI seeds the selected crane from its spec library
+    let Some(crane_spec) = scene_state.crane_config.as_ref().map(|c| c.spec.clone()) else {
+        eprintln!("✗ No crane selected; the spec library has no cranes");
+        return;
+    };
     print_crane_spec(&crane_spec);
 
     let mut crane_config = CraneConfiguration::new(crane_spec);
@@ -154,6 +159,40 @@ pub fn spawn_test_scene(
         }
     }
 
+    // ========== SITE OBSTACLES ==========
+
+    println!("\n--- Placing Site Obstacles ---");
+
+    // Building beside the crane, clear of the hook at the start position
+    let building = SiteObstacle {
+        name: "Site Building".to_string(),
+        position: Point3::new(20.0, 4.0, 6.0),
+        dimensions: Vector3::new(8.0, 12.0, 12.0),
+    };
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(
+            building.dimensions.x,
+            building.dimensions.z,
+            building.dimensions.y,
+        ))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.55, 0.55, 0.6),
+            perceptual_roughness: 0.9,
+            ..default()
+        })),
+        Transform::from_xyz(
+            building.position.x,
+            building.position.z,
+            building.position.y,
+        ),
+        Name::new(building.name.clone()),
+    ));
+    println!(
+        "✓ {}: {:.0}m × {:.0}m × {:.0}m",
+        building.name, building.dimensions.x, building.dimensions.y, building.dimensions.z
+    );
+    site.obstacles.push(CollisionObstacle::from(&building));
+
     // ========== GROUND BEARING ==========
 
     println!("\n--- Analyzing Ground Bearing ---");
@@ -5,7 +5,14 @@ use bevy_egui::{
     EguiContext, EguiContexts, EguiPlugin, EguiPrimaryContextPass, PrimaryEguiContext,
     egui::{self},
 };
-use crane_core::CraneSpec;
+use crane_core::{
+    ChartFormat, ChartValidator, CollisionLoad, CraneState, LiftPlan, OutriggerPosition,
+    PathPlanner, PlannerOptions, Quantity, RangeCurveKind, RangeDiagramGenerator,
+    RangeDiagramOptions, Severity, UnitSystem,
+};
+use log::{info, warn};
+use nalgebra::Vector3;
+use scene_3d::{CapacityOverlay, Crane, LiftTimeline, PlaybackChecks, SceneState, SiteObstacles};
 use ui_state::UiState;
 
 pub struct UiLayerPlugin;
@@ -18,8 +25,19 @@ impl Plugin for UiLayerPlugin {
         });
         app.add_systems(
             EguiPrimaryContextPass,
-            (ui_system, main_menu_panel, crane_configuration_panel),
+            (
+                ui_system,
+                main_menu_panel,
+                crane_configuration_panel,
+                lift_playback_panel,
+                chart_check_panel,
+                range_diagram_panel,
+                sync_capacity_overlay,
+                crane_selector_panel,
+            ),
         );
+        app.add_systems(Startup, seed_scene_crane.before(scene_3d::spawn_test_scene));
+        app.add_systems(Update, (reload_spec_library, respawn_scene_crane).chain());
     }
 }
 
@@ -122,6 +140,39 @@ fn main_menu_panel(mut contexts: EguiContexts, mut ui_state: ResMut<UiState>) ->
                 ui_state.show_scene_controls = !ui_state.show_scene_controls;
             }
 
+            if ui
+                .button(if ui_state.show_playback_panel {
+                    "✓ Lift Playback"
+                } else {
+                    " Lift Playback"
+                })
+                .clicked()
+            {
+                ui_state.show_playback_panel = !ui_state.show_playback_panel;
+            }
+
+            if ui
+                .button(if ui_state.show_chart_check_panel {
+                    "✓ Load Chart Check"
+                } else {
+                    " Load Chart Check"
+                })
+                .clicked()
+            {
+                ui_state.show_chart_check_panel = !ui_state.show_chart_check_panel;
+            }
+
+            if ui
+                .button(if ui_state.show_range_panel {
+                    "✓ Range Diagram"
+                } else {
+                    " Range Diagram"
+                })
+                .clicked()
+            {
+                ui_state.show_range_panel = !ui_state.show_range_panel;
+            }
+
             ui.add_space(10.0);
             ui.separator();
             ui.add_space(10.0);
@@ -132,15 +183,16 @@ fn main_menu_panel(mut contexts: EguiContexts, mut ui_state: ResMut<UiState>) ->
                 ui_state.show_crane_selector = true;
             }
 
-            if ui.button("Reset All").clicked() {
-                let spec = ui_state.get_selected_crane_spec();
+            if ui.button("Reset All").clicked()
+                && let Some(spec) = ui_state.get_selected_crane_spec()
+            {
                 ui_state.boom_length_m =
                     (spec.boom_length_range.0 + spec.boom_length_range.1) / 2.0;
                 ui_state.boom_angle_deg = 60.0;
                 ui_state.swing_angle_deg = 0.0;
                 ui_state.hoist_length_m =
                     (spec.hoist_length_range.0 + spec.hoist_length_range.1) / 2.0;
-                ui_state.outrigger_extension_pct = 100.0;
+                ui_state.set_all_outrigger_extensions(100.0);
                 ui_state.counterweight_slabs = spec.counterweight_max_slabs / 2;
 
                 ui_state.load_weight_kg = 8000.0;
@@ -165,6 +217,14 @@ fn main_menu_panel(mut contexts: EguiContexts, mut ui_state: ResMut<UiState>) ->
             ui.checkbox(&mut ui_state.show_pick_points, "Show Pick Points");
             ui.checkbox(&mut ui_state.show_cog, "Show Center of Gravity");
             ui.checkbox(&mut ui_state.show_ground_pressure, "Show Ground Pressure");
+            ui.checkbox(&mut ui_state.show_capacity_contour, "Show Capacity Contour");
+
+            ui.horizontal(|ui| {
+                ui.label("Units:");
+                for system in UnitSystem::all() {
+                    ui.selectable_value(&mut ui_state.units, system, system.as_str());
+                }
+            });
 
             ui.add_space(10.0);
             ui.separator();
@@ -194,7 +254,11 @@ fn crane_configuration_panel(mut contexts: EguiContexts, mut ui_state: ResMut<Ui
         .default_pos([10.0, 10.0])
         .open(&mut show_panel)
         .show(ctx, |ui| {
-            let spec = ui_state.get_selected_crane_spec();
+            let Some(spec) = ui_state.get_selected_crane_spec() else {
+                missing_crane_label(ui, &ui_state);
+                return;
+            };
+            let units = ui_state.units;
 
             ui.heading("Crane Model");
             ui.horizontal(|ui| {
@@ -211,16 +275,13 @@ fn crane_configuration_panel(mut contexts: EguiContexts, mut ui_state: ResMut<Ui
             ui.heading("Boom");
             ui.horizontal(|ui| {
                 ui.label("Length:");
-                if ui
-                    .add(
-                        egui::Slider::new(
-                            &mut ui_state.boom_length_m,
-                            spec.boom_length_range.0..=spec.boom_length_range.1,
-                        )
-                        .suffix(" m"),
-                    )
-                    .changed()
-                {
+                if unit_slider(
+                    ui,
+                    &mut ui_state.boom_length_m,
+                    spec.boom_length_range,
+                    Quantity::Length,
+                    units,
+                ) {
                     ui_state.mark_dirty();
                 }
             });
@@ -242,16 +303,13 @@ fn crane_configuration_panel(mut contexts: EguiContexts, mut ui_state: ResMut<Ui
             ui.heading("Hoist");
             ui.horizontal(|ui| {
                 ui.label("Cable Length:");
-                if ui
-                    .add(
-                        egui::Slider::new(
-                            &mut ui_state.hoist_length_m,
-                            spec.hoist_length_range.0..=spec.hoist_length_range.1,
-                        )
-                        .suffix(" m"),
-                    )
-                    .changed()
-                {
+                if unit_slider(
+                    ui,
+                    &mut ui_state.hoist_length_m,
+                    spec.hoist_length_range,
+                    Quantity::Length,
+                    units,
+                ) {
                     ui_state.mark_dirty();
                 }
             });
@@ -261,34 +319,141 @@ fn crane_configuration_panel(mut contexts: EguiContexts, mut ui_state: ResMut<Ui
             ui.add_space(10.0);
 
             ui.heading("Outriggers");
-            ui.horizontal(|ui| {
-                ui.label("Extension:");
-                if ui
-                    .add(
-                        egui::Slider::new(&mut ui_state.outrigger_extension_pct, 0.0..=100.0)
+            for position in OutriggerPosition::all() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}:", position.as_str()));
+                    if ui
+                        .add(
+                            egui::Slider::new(
+                                ui_state.outrigger_extension_mut(position),
+                                0.0..=100.0,
+                            )
                             .suffix("%"),
-                    )
-                    .changed()
-                {
-                    ui_state.mark_dirty();
-                }
-            });
+                        )
+                        .changed()
+                    {
+                        ui_state.mark_dirty();
+                    }
+                });
+            }
 
             ui.horizontal(|ui| {
                 if ui.button("Retract").clicked() {
-                    ui_state.outrigger_extension_pct = 0.0;
+                    ui_state.set_all_outrigger_extensions(0.0);
                     ui_state.mark_dirty();
                 }
                 if ui.button("50%").clicked() {
-                    ui_state.outrigger_extension_pct = 50.0;
+                    ui_state.set_all_outrigger_extensions(50.0);
                     ui_state.mark_dirty();
                 }
                 if ui.button("Full").clicked() {
-                    ui_state.outrigger_extension_pct = 100.0;
+                    ui_state.set_all_outrigger_extensions(100.0);
                     ui_state.mark_dirty();
                 }
             });
 
+            // Variable-base capacity at the current slew
+            let Some(config) = ui_state.build_crane_configuration() else {
+                return;
+            };
+            if config.outriggers.all_deployed() {
+                let radius = config.get_radius();
+                let factor = config.get_outrigger_capacity_factor(radius);
+                if let Some(capacity) = config.get_current_capacity() {
+                    ui.label(format!(
+                        "Capacity: {} ({:.0}% of full span)",
+                        units.mass(capacity),
+                        factor * 100.0
+                    ));
+                }
+                if let Some(edge) = config.outriggers.boom_side_edge(config.swing_angle_deg) {
+                    ui.label(format!(
+                        "Governing edge: {} – {}",
+                        edge.from.as_str(),
+                        edge.to.as_str()
+                    ));
+                }
+            } else {
+                ui.colored_label(
+                    egui::Color32::from_rgb(255, 160, 60),
+                    "Legs below minimum span are retracted (on tires)",
+                );
+            }
+
+            ui.label("Ground under floats:");
+            for position in OutriggerPosition::all() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}:", position.as_str()));
+                    let unit = units.unit(Quantity::Length);
+                    let elevation = ui_state.outrigger_ground_elevation_mut(position);
+                    let mut shown = unit.from_metric(*elevation);
+                    let limit = unit.from_metric(2.0);
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut shown)
+                                .speed(unit.from_metric(0.01))
+                                .range(-limit..=limit)
+                                .suffix(format!(" {}", unit.symbol())),
+                        )
+                        .changed()
+                    {
+                        *elevation = unit.to_metric(shown);
+                        ui_state.mark_dirty();
+                    }
+                });
+            }
+            if ui
+                .checkbox(&mut ui_state.level_on_jacks, "Level on jacks")
+                .changed()
+            {
+                ui_state.mark_dirty();
+            }
+
+            if config.outriggers.all_deployed()
+                && let Some(mut levelled) = ui_state.build_crane_configuration()
+            {
+                let levelling = ui_state.level_on_jacks.then(|| levelled.level_outriggers());
+                match levelling {
+                    Some(Err(e)) => {
+                        ui.colored_label(egui::Color32::RED, e.describe(units));
+                    }
+                    _ => {
+                        for outrigger in &levelled.outriggers.outriggers {
+                            ui.label(format!(
+                                "{}: jack {}, cribbing {}",
+                                outrigger.position.as_str(),
+                                units.format(
+                                    Quantity::Length,
+                                    outrigger.jack_stroke_m().unwrap_or(0.0),
+                                    2
+                                ),
+                                units.format(Quantity::Length, outrigger.cribbing_m, 2)
+                            ));
+                        }
+                    }
+                }
+
+                let tilt = levelled.get_tilt_effect();
+                let tolerance = levelled.spec.levelling_spec().level_tolerance_pct;
+                let radius_unit = units.unit(Quantity::Length);
+                let text = format!(
+                    "Out of level: {:.2}% (tolerance {:.1}%), radius {:+.2}{}",
+                    tilt.tilt_pct,
+                    tolerance,
+                    radius_unit.from_metric(tilt.radius_increase_m),
+                    radius_unit.symbol()
+                );
+                if tilt.tilt_pct > tolerance {
+                    ui.colored_label(egui::Color32::RED, text);
+                    ui.label(format!(
+                        "Capacity derated to {:.0}%",
+                        levelled.get_out_of_level_factor() * 100.0
+                    ));
+                } else {
+                    ui.label(text);
+                }
+            }
+
             ui.add_space(10.0);
             ui.separator();
             ui.add_space(10.0);
@@ -310,7 +475,7 @@ fn crane_configuration_panel(mut contexts: EguiContexts, mut ui_state: ResMut<Ui
             });
 
             let total_cw = ui_state.counterweight_slabs as f32 * spec.counterweight_slab_weight_kg;
-            ui.label(format!("Total: {:.0} kg", total_cw));
+            ui.label(format!("Total: {}", units.mass(total_cw)));
 
             ui.horizontal(|ui| {
                 if ui.button("None").clicked() {
@@ -329,6 +494,793 @@ fn crane_configuration_panel(mut contexts: EguiContexts, mut ui_state: ResMut<Ui
                     ui_state.counterweight_slabs = spec.counterweight_max_slabs;
                     ui_state.mark_dirty();
                 }
-            })
+            });
+        });
+}
+
+fn lift_playback_panel(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<UiState>,
+    mut timeline: ResMut<LiftTimeline>,
+    site: Res<SiteObstacles>,
+    crane_query: Query<&Crane>,
+) {
+    if !ui_state.show_playback_panel {
+        return;
+    }
+
+    let ctx = match contexts.ctx_mut() {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    let Ok(crane) = crane_query.single() else {
+        return;
+    };
+    let config = crane.config.clone();
+
+    let mut show_panel = ui_state.show_playback_panel;
+
+    egui::Window::new("Lift Playback")
+        .default_width(340.0)
+        .default_pos([10.0, 420.0])
+        .open(&mut show_panel)
+        .show(ctx, |ui| {
+            ui.heading("Steps");
+            ui.label(format!("{} steps recorded", ui_state.playback_steps.len()));
+
+            ui.horizontal(|ui| {
+                if ui.button("Add Step").clicked() {
+                    // Step from the configuration sliders
+                    let mut state = CraneState::from(&config);
+                    state.boom_length_m = ui_state.boom_length_m;
+                    state.boom_angle_deg = ui_state.boom_angle_deg;
+                    state.swing_angle_deg = ui_state.swing_angle_deg;
+                    state.hoist_length_m = ui_state.hoist_length_m;
+                    ui_state.playback_steps.push(state);
+                }
+                if ui.button("Clear").clicked() {
+                    ui_state.playback_steps.clear();
+                    *timeline = LiftTimeline::default();
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Step Time:");
+                ui.add(
+                    egui::Slider::new(&mut ui_state.playback_step_duration_s, 1.0..=60.0)
+                        .suffix(" s"),
+                );
+            });
+
+            let load_kg = ui_state.load_weight_kg;
+            let load = CollisionLoad::from_box(
+                Vector3::new(
+                    ui_state.load_length_m,
+                    ui_state.load_width_m,
+                    ui_state.load_height_m,
+                ),
+                ui_state.playback_rigging_height_m,
+            );
+            let checks = PlaybackChecks::default();
+
+            ui.horizontal(|ui| {
+                let enough_steps = ui_state.playback_steps.len() >= 2;
+
+                if ui
+                    .add_enabled(enough_steps, egui::Button::new("Play Steps"))
+                    .clicked()
+                {
+                    *timeline = LiftTimeline::from_steps(
+                        &config,
+                        &ui_state.playback_steps,
+                        ui_state.playback_step_duration_s,
+                        load_kg,
+                        Some(load.clone()),
+                        &site.obstacles,
+                        &checks,
+                    );
+                    ui_state.playback_message = None;
+                }
+
+                if ui
+                    .add_enabled(enough_steps, egui::Button::new("Plan Path"))
+                    .clicked()
+                {
+                    // Plan each leg and join them into one timeline
+                    let options = PlannerOptions::default();
+                    let mut keyframes: Vec<(f32, CraneState)> = Vec::new();
+                    let mut error = None;
+
+                    for (i, leg) in ui_state.playback_steps.windows(2).enumerate() {
+                        match PathPlanner::plan(
+                            &config,
+                            &leg[0],
+                            &leg[1],
+                            load_kg,
+                            Some(&load),
+                            &site.obstacles,
+                            &options,
+                        ) {
+                            Ok(path) => {
+                                let offset = keyframes.last().map(|k| k.0).unwrap_or(0.0);
+                                let skip = usize::from(!keyframes.is_empty());
+                                keyframes.extend(
+                                    path.trajectory
+                                        .iter()
+                                        .skip(skip)
+                                        .map(|p| (offset + p.time_s, p.state)),
+                                );
+                            }
+                            Err(e) => {
+                                error = Some(format!("Step {} → {}: {}", i + 1, i + 2, e));
+                                break;
+                            }
+                        }
+                    }
+
+                    if error.is_none() {
+                        *timeline = LiftTimeline::from_keyframes(
+                            &config,
+                            keyframes,
+                            load_kg,
+                            Some(load.clone()),
+                            &site.obstacles,
+                            &checks,
+                        );
+                    }
+                    ui_state.playback_message = error;
+                }
+            });
+
+            if let Some(message) = &ui_state.playback_message {
+                ui.colored_label(egui::Color32::from_rgb(255, 80, 80), message);
+            }
+
+            ui.add_space(10.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            ui.heading("Playback");
+
+            if timeline.is_empty() {
+                ui.label("No timeline loaded");
+                return;
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("⏮").clicked() {
+                    timeline.scrub(0.0);
+                }
+                if ui
+                    .button(if timeline.playing { "⏸" } else { "▶" })
+                    .clicked()
+                {
+                    timeline.toggle();
+                }
+                ui.checkbox(&mut timeline.looping, "Loop");
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Speed:");
+                ui.add(egui::Slider::new(&mut timeline.speed, 0.25..=8.0).suffix("×"));
+            });
+
+            let duration = timeline.duration_s();
+            let mut time = timeline.current_time_s;
+            ui.horizontal(|ui| {
+                ui.label("Time:");
+                if ui
+                    .add(egui::Slider::new(&mut time, 0.0..=duration).suffix(" s"))
+                    .changed()
+                {
+                    timeline.pause();
+                    timeline.scrub(time);
+                }
+            });
+
+            // Timeline strip with failing frames in red
+            let (rect, _) = ui
+                .allocate_exact_size(egui::vec2(ui.available_width(), 12.0), egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 2.0, egui::Color32::from_rgb(60, 140, 60));
+            let x_at = |t: f32| rect.left() + rect.width() * t / duration.max(f32::EPSILON);
+            for i in timeline.failing_frames() {
+                let from = timeline.keyframes[i].time_s;
+                let to = timeline
+                    .keyframes
+                    .get(i + 1)
+                    .map(|k| k.time_s)
+                    .unwrap_or(from);
+                painter.rect_filled(
+                    egui::Rect::from_x_y_ranges(
+                        x_at(from)..=x_at(to).max(x_at(from) + 2.0),
+                        rect.y_range(),
+                    ),
+                    0.0,
+                    egui::Color32::from_rgb(200, 40, 40),
+                );
+            }
+            let cursor = x_at(timeline.current_time_s);
+            painter.line_segment(
+                [
+                    egui::pos2(cursor, rect.top()),
+                    egui::pos2(cursor, rect.bottom()),
+                ],
+                egui::Stroke::new(2.0, egui::Color32::WHITE),
+            );
+
+            if let Some(frame) = timeline.current_frame() {
+                let check = &frame.check;
+                ui.add_space(5.0);
+                if let Some(utilization) = check.utilization_percent {
+                    ui.label(format!("Utilisation: {:.0}%", utilization));
+                }
+                let units = ui_state.units;
+                if let Some(clearance) = check.min_clearance_m {
+                    ui.label(format!(
+                        "Min clearance: {}",
+                        units.format(Quantity::Length, clearance, 2)
+                    ));
+                }
+                if let Some(pressure) = check.max_ground_pressure_kpa {
+                    ui.label(format!(
+                        "Max ground pressure: {}",
+                        units.format(Quantity::Pressure, pressure, 0)
+                    ));
+                }
+                for failure in &check.failures {
+                    ui.colored_label(egui::Color32::from_rgb(255, 80, 80), failure);
+                }
+            }
+
+            let failing = timeline.failing_frames();
+            if !failing.is_empty() {
+                ui.add_space(5.0);
+                ui.label(format!("{} failing frames", failing.len()));
+                egui::ScrollArea::vertical()
+                    .max_height(120.0)
+                    .show(ui, |ui| {
+                        for i in failing {
+                            let frame = &timeline.keyframes[i];
+                            let time_s = frame.time_s;
+                            let label =
+                                format!("{:.1} s: {}", time_s, frame.check.failures.join(", "));
+                            if ui.link(label).clicked() {
+                                timeline.pause();
+                                timeline.scrub(time_s);
+                            }
+                        }
+                    });
+            }
+        });
+
+    ui_state.show_playback_panel = show_panel;
+}
+
+fn chart_check_panel(mut contexts: EguiContexts, mut ui_state: ResMut<UiState>) {
+    if !ui_state.show_chart_check_panel {
+        return;
+    }
+
+    let ctx = match contexts.ctx_mut() {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    let mut show_panel = ui_state.show_chart_check_panel;
+
+    egui::Window::new("Load Chart Check")
+        .default_width(420.0)
+        .default_pos([360.0, 420.0])
+        .open(&mut show_panel)
+        .show(ctx, |ui| {
+            let Some(spec) = ui_state.get_selected_crane_spec() else {
+                missing_crane_label(ui, &ui_state);
+                return;
+            };
+            let validator = ChartValidator::default();
+
+            if ui
+                .button(format!("Check {} {} charts", spec.manufacturer, spec.model))
+                .clicked()
+            {
+                ui_state.chart_diagnostics = validator.validate_spec(&spec);
+                ui_state.chart_check_title = Some(format!("{} built-in charts", spec.model));
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("File:");
+                ui.text_edit_singleline(&mut ui_state.chart_check_path);
+            });
+            if ui.button("Check file against crane").clicked() {
+                let path = std::path::PathBuf::from(&ui_state.chart_check_path);
+                match std::fs::read_to_string(&path) {
+                    Ok(data) => {
+                        let format = ChartFormat::detect(&path, &data);
+                        let report = validator.validate_source(&data, format, Some(&spec));
+                        ui_state.chart_check_title = Some(format!(
+                            "{}: {} charts read",
+                            path.display(),
+                            report.charts.len()
+                        ));
+                        ui_state.chart_diagnostics = report.diagnostics;
+                    }
+                    Err(e) => {
+                        ui_state.chart_check_title = Some(format!("{}: {}", path.display(), e));
+                        ui_state.chart_diagnostics.clear();
+                    }
+                }
+            }
+
+            ui.separator();
+
+            match &spec.capacity_chart.provenance {
+                Some(provenance) => {
+                    ui.label(format!(
+                        "Chart: {} {} ({})",
+                        provenance.manufacturer,
+                        provenance.describe(),
+                        provenance.units
+                    ));
+                    ui.label(format!("Imported by {}", provenance.importer));
+                    let hash = spec.capacity_chart.content_hash();
+                    let color = if spec.capacity_chart.verify_integrity() {
+                        egui::Color32::GRAY
+                    } else {
+                        egui::Color32::RED
+                    };
+                    ui.colored_label(color, format!("SHA-256 {}", &hash[..16]));
+                }
+                None => {
+                    ui.label("Chart has no source record");
+                }
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("Lift plan:");
+                ui.text_edit_singleline(&mut ui_state.lift_plan_path);
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Save plan").clicked()
+                    && let Some(config) = ui_state.build_crane_configuration()
+                {
+                    let plan = LiftPlan::capture(
+                        &ui_state.lift_plan_path,
+                        &config,
+                        ui_state.load_weight_kg,
+                    );
+                    let result = plan.to_json().and_then(|json| {
+                        std::fs::write(&ui_state.lift_plan_path, json).map_err(|e| e.to_string())
+                    });
+                    ui_state.lift_plan_message = Some(match result {
+                        Ok(()) => match plan.main_chart() {
+                            Some(chart) if plan.charts.len() > 1 => format!(
+                                "Saved against {} rev {} and {} other chart(s)",
+                                chart.document_number,
+                                chart.revision,
+                                plan.charts.len() - 1
+                            ),
+                            Some(chart) => format!(
+                                "Saved against {} rev {}",
+                                chart.document_number, chart.revision
+                            ),
+                            None => "Saved".to_string(),
+                        },
+                        Err(e) => e,
+                    });
+                    ui_state.lift_plan_warnings.clear();
+                }
+                if ui.button("Open plan").clicked() {
+                    let plan = std::fs::read_to_string(&ui_state.lift_plan_path)
+                        .map_err(|e| e.to_string())
+                        .and_then(|data| LiftPlan::from_json(&data));
+                    match plan {
+                        Ok(plan) => ui_state.apply_lift_plan(&plan),
+                        Err(e) => {
+                            ui_state.lift_plan_message = Some(e);
+                            ui_state.lift_plan_warnings.clear();
+                        }
+                    }
+                }
+            });
+            if let Some(message) = &ui_state.lift_plan_message {
+                ui.label(message);
+            }
+            for warning in &ui_state.lift_plan_warnings {
+                ui.colored_label(egui::Color32::from_rgb(255, 160, 60), warning.to_string());
+            }
+
+            ui.separator();
+
+            let Some(title) = &ui_state.chart_check_title else {
+                ui.label("No chart checked yet");
+                return;
+            };
+            ui.label(title);
+
+            let errors = ui_state
+                .chart_diagnostics
+                .iter()
+                .filter(|d| d.severity == Severity::Error)
+                .count();
+            let warnings = ui_state.chart_diagnostics.len() - errors;
+            ui.label(format!("{} errors, {} warnings", errors, warnings));
+
+            egui::ScrollArea::vertical()
+                .max_height(260.0)
+                .show(ui, |ui| {
+                    for diagnostic in &ui_state.chart_diagnostics {
+                        let color = match diagnostic.severity {
+                            Severity::Error => egui::Color32::RED,
+                            Severity::Warning => egui::Color32::from_rgb(255, 160, 60),
+                        };
+                        ui.colored_label(color, diagnostic.to_string());
+                    }
+                });
+        });
+
+    ui_state.show_chart_check_panel = show_panel;
+}
+
+fn range_diagram_panel(mut contexts: EguiContexts, mut ui_state: ResMut<UiState>) {
+    if !ui_state.show_range_panel {
+        return;
+    }
+
+    let ctx = match contexts.ctx_mut() {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    // Regenerate only when the configuration or load changes
+    let key = ui_state.configuration_key();
+    if ui_state.range_diagram.is_none() || ui_state.range_diagram_key != key {
+        let load_kg = ui_state.load_weight_kg;
+        ui_state.range_diagram = ui_state.build_crane_configuration().map(|config| {
+            RangeDiagramGenerator::for_configuration(
+                &config,
+                load_kg,
+                &RangeDiagramOptions::default(),
+            )
+        });
+        ui_state.range_diagram_key = key;
+    }
+
+    let mut show_panel = ui_state.show_range_panel;
+    let units = ui_state.units;
+
+    egui::Window::new("Range Diagram")
+        .default_width(460.0)
+        .default_pos([360.0, 80.0])
+        .open(&mut show_panel)
+        .show(ctx, |ui| {
+            let Some(diagram) = ui_state.range_diagram.clone() else {
+                missing_crane_label(ui, &ui_state);
+                return;
+            };
+
+            match diagram.hook {
+                Some(hook) => {
+                    let (text, color) = if diagram.hook_in_envelope() {
+                        ("within capacity", egui::Color32::GREEN)
+                    } else {
+                        ("outside capacity", egui::Color32::RED)
+                    };
+                    ui.colored_label(
+                        color,
+                        format!(
+                            "Hook {} radius, {} high: {} for {}",
+                            units.length(hook.x),
+                            units.length(hook.y),
+                            text,
+                            units.mass(ui_state.load_weight_kg)
+                        ),
+                    );
+                }
+                None => {
+                    ui.label("No hook position");
+                }
+            }
+
+            let size = egui::vec2(ui.available_width().max(300.0), 320.0);
+            let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+            let rect = response.rect;
+            painter.rect_filled(rect, 2.0, egui::Color32::from_gray(20));
+
+            let (max_radius, max_height) = diagram.extent();
+            let max_radius = max_radius.max(1.0) * 1.05;
+            let max_height = max_height.max(1.0) * 1.05;
+            let scale =
+                ((rect.width() - 20.0) / max_radius).min((rect.height() - 20.0) / max_height);
+            let to_screen = |p: &nalgebra::Point2<f32>| {
+                egui::pos2(
+                    rect.left() + 10.0 + p.x * scale,
+                    rect.bottom() - 10.0 - p.y * scale,
+                )
+            };
+
+            // Capacity-limited region as strips down to the ground
+            let fill = egui::Color32::from_rgba_unmultiplied(46, 157, 75, 70);
+            for run in &diagram.capacity_envelope {
+                for pair in run.windows(2) {
+                    let points = vec![
+                        to_screen(&pair[0]),
+                        to_screen(&pair[1]),
+                        to_screen(&nalgebra::Point2::new(pair[1].x, 0.0)),
+                        to_screen(&nalgebra::Point2::new(pair[0].x, 0.0)),
+                    ];
+                    painter.add(egui::Shape::convex_polygon(
+                        points,
+                        fill,
+                        egui::Stroke::NONE,
+                    ));
+                }
+            }
+
+            painter.line_segment(
+                [
+                    to_screen(&nalgebra::Point2::new(0.0, 0.0)),
+                    to_screen(&nalgebra::Point2::new(max_radius, 0.0)),
+                ],
+                egui::Stroke::new(1.0, egui::Color32::GRAY),
+            );
+
+            for curve in &diagram.curves {
+                let color = match curve.kind {
+                    RangeCurveKind::BoomLength { .. } | RangeCurveKind::Trolley { .. } => {
+                        egui::Color32::from_rgb(90, 150, 230)
+                    }
+                    RangeCurveKind::BoomAngle { .. } => egui::Color32::from_gray(110),
+                    RangeCurveKind::Jib { .. } => egui::Color32::from_rgb(230, 140, 50),
+                };
+                let points: Vec<_> = curve.tip.iter().map(to_screen).collect();
+                painter.add(egui::Shape::line(points, egui::Stroke::new(1.5, color)));
+                if !matches!(curve.kind, RangeCurveKind::BoomAngle { .. }) {
+                    let hook: Vec<_> = curve.hook.iter().map(to_screen).collect();
+                    painter.add(egui::Shape::line(
+                        hook,
+                        egui::Stroke::new(0.5, color.gamma_multiply(0.6)),
+                    ));
+                }
+                if let Some(top) = curve.tip.iter().max_by(|a, b| a.y.total_cmp(&b.y)) {
+                    painter.text(
+                        to_screen(top) + egui::vec2(3.0, -3.0),
+                        egui::Align2::LEFT_BOTTOM,
+                        curve.label(units),
+                        egui::FontId::proportional(10.0),
+                        color,
+                    );
+                }
+            }
+
+            if let Some(hook) = diagram.hook {
+                let color = if diagram.hook_in_envelope() {
+                    egui::Color32::GREEN
+                } else {
+                    egui::Color32::RED
+                };
+                painter.circle_filled(to_screen(&hook), 4.0, color);
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("SVG:");
+                ui.text_edit_singleline(&mut ui_state.range_svg_path);
+                if ui.button("Export").clicked() {
+                    let result = std::fs::write(&ui_state.range_svg_path, diagram.to_svg(units));
+                    ui_state.range_message = Some(match result {
+                        Ok(()) => format!("Wrote {}", ui_state.range_svg_path),
+                        Err(e) => e.to_string(),
+                    });
+                }
+            });
+            if let Some(message) = &ui_state.range_message {
+                ui.label(message);
+            }
         });
+
+    ui_state.show_range_panel = show_panel;
+}
+
+/// Seconds between checks of the spec library for edited files
+const LIBRARY_POLL_S: f32 = 1.0;
+
+/// Hot-reload the crane spec library when its files change
+fn reload_spec_library(
+    time: Res<Time>,
+    mut since_check_s: Local<f32>,
+    mut ui_state: ResMut<UiState>,
+) {
+    *since_check_s += time.delta_secs();
+    if *since_check_s < LIBRARY_POLL_S {
+        return;
+    }
+    *since_check_s = 0.0;
+
+    if ui_state.reload_library_if_changed() {
+        info!(
+            "Reloaded crane library: {} specs, {} issues",
+            ui_state.library.len(),
+            ui_state.library.issues().len()
+        );
+        for issue in ui_state.library.issues() {
+            warn!("{}", issue);
+        }
+    }
+}
+
+/// Hand the selected crane from the spec library to the 3D scene
+fn seed_scene_crane(ui_state: Res<UiState>, mut scene_state: ResMut<SceneState>) {
+    scene_state.crane_config = ui_state.build_crane_configuration();
+    if scene_state.crane_config.is_none() {
+        warn!("{}", ui_state.missing_crane_message());
+    }
+}
+
+/// Rebuild the rendered crane when its model changes or the library reloads
+///
+/// A reloaded crane keeps its pose, outrigger spans and counterweight; a
+/// newly selected model takes the panel settings.
+fn respawn_scene_crane(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut ui_state: ResMut<UiState>,
+    mut scene_state: ResMut<SceneState>,
+    cranes: Query<(Entity, &Crane)>,
+) {
+    if !ui_state.needs_crane_respawn {
+        return;
+    }
+    ui_state.needs_crane_respawn = false;
+
+    let Some(selected) = ui_state.build_crane_configuration() else {
+        warn!("{}", ui_state.missing_crane_message());
+        return;
+    };
+
+    for (entity, crane) in &cranes {
+        let old = &crane.config;
+        let mut config = selected.clone();
+        if old.spec.id == selected.spec.id {
+            config.apply_state(&CraneState::from(old));
+            for position in OutriggerPosition::all() {
+                if let Some(pct) = old.outriggers.extension_pct(position) {
+                    let _ = config.outriggers.set_extension_pct(position, pct);
+                }
+            }
+            let _ = config
+                .counterweight
+                .set_slab_count(old.counterweight.get_slab_count());
+        } else {
+            config.position = old.position;
+        }
+        config.heading_deg = old.heading_deg;
+
+        commands.entity(entity).despawn();
+        scene_3d::spawn_crane(&mut commands, &mut meshes, &mut materials, config.clone());
+        scene_state.crane_config = Some(config);
+    }
+}
+
+fn crane_selector_panel(mut contexts: EguiContexts, mut ui_state: ResMut<UiState>) {
+    if !ui_state.show_crane_selector {
+        return;
+    }
+
+    let ctx = match contexts.ctx_mut() {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    let mut show_panel = ui_state.show_crane_selector;
+    let units = ui_state.units;
+
+    egui::Window::new("Crane Library")
+        .default_width(360.0)
+        .default_pos([340.0, 10.0])
+        .open(&mut show_panel)
+        .show(ctx, |ui| {
+            if ui_state.library.is_builtin() {
+                ui.label(format!(
+                    "{} built-in cranes ({} can't be read)",
+                    ui_state.library.len(),
+                    ui_state.library.dir().display()
+                ));
+            } else {
+                ui.label(format!(
+                    "{} cranes in {}",
+                    ui_state.library.len(),
+                    ui_state.library.dir().display()
+                ));
+            }
+            if ui.button("Reload").clicked() {
+                ui_state.library.reload();
+                ui_state.library_generation += 1;
+                ui_state.mark_crane_respawn();
+                ui_state.mark_dirty();
+            }
+
+            ui.separator();
+
+            let mut picked = None;
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    for spec in ui_state.library.specs() {
+                        let selected = spec.id == ui_state.selected_crane_id;
+                        let label = format!(
+                            "{} {} ({}, {})",
+                            spec.manufacturer,
+                            spec.model,
+                            spec.crane_type,
+                            units.mass(spec.max_capacity_kg)
+                        );
+                        if ui.selectable_label(selected, label).clicked() {
+                            picked = Some(spec.clone());
+                        }
+                    }
+                });
+            if let Some(spec) = picked {
+                ui_state.select_crane(&spec);
+            }
+
+            for issue in ui_state.library.issues() {
+                ui.colored_label(egui::Color32::RED, issue.to_string());
+            }
+        });
+
+    ui_state.show_crane_selector = show_panel;
+}
+
+/// Push the slider configuration and load to the ground contour
+fn sync_capacity_overlay(mut ui_state: ResMut<UiState>, mut overlay: ResMut<CapacityOverlay>) {
+    let key = ui_state.configuration_key();
+    let enabled = ui_state.show_capacity_contour;
+    if overlay.enabled == enabled && (!enabled || ui_state.capacity_contour_key == key) {
+        return;
+    }
+
+    overlay.enabled = enabled;
+    overlay.load_kg = ui_state.load_weight_kg;
+    overlay.config = enabled
+        .then(|| ui_state.build_crane_configuration())
+        .flatten();
+    ui_state.capacity_contour_key = key;
+}
+
+/// Report a selected crane the library doesn't have, with the load issues
+fn missing_crane_label(ui: &mut egui::Ui, ui_state: &UiState) {
+    ui.colored_label(egui::Color32::RED, ui_state.missing_crane_message());
+    for issue in ui_state.library.issues() {
+        ui.colored_label(egui::Color32::RED, issue.to_string());
+    }
+}
+
+/// Slider over a stored metric value, shown in the selected units
+///
+/// The value is only written back when the slider moves, so switching
+/// units never changes it.
+fn unit_slider(
+    ui: &mut egui::Ui,
+    value: &mut f32,
+    range: (f32, f32),
+    quantity: Quantity,
+    units: UnitSystem,
+) -> bool {
+    let unit = units.unit(quantity);
+    let mut shown = unit.from_metric(*value);
+    let changed = ui
+        .add(
+            egui::Slider::new(
+                &mut shown,
+                unit.from_metric(range.0)..=unit.from_metric(range.1),
+            )
+            .suffix(format!(" {}", unit.symbol())),
+        )
+        .changed();
+    if changed {
+        *value = unit.to_metric(shown);
+    }
+    changed
 }
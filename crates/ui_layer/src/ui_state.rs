@@ -1,15 +1,28 @@
 use bevy::prelude::*;
-use crane_core::CraneSpec;
+use crane_core::{
+    ChartDiagnostic, CraneConfiguration, CraneSpec, CraneState, LiftPlan, OutriggerPosition,
+    PlanWarning, RangeDiagram, SPEC_DIR_ENV, SpecRegistry, UnitSystem,
+};
 
 #[derive(Resource)]
 pub struct UiState {
+    // Crane library
+    pub library: SpecRegistry,
+    /// Bumped on every library reload
+    pub library_generation: u64,
+
     // Crane configuration
     pub selected_crane_id: String,
     pub boom_length_m: f32,
     pub boom_angle_deg: f32,
     pub swing_angle_deg: f32,
     pub hoist_length_m: f32,
-    pub outrigger_extension_pct: f32,
+    /// Per-leg extension, in `OutriggerPosition::all()` order
+    pub outrigger_extension_pct: [f32; 4],
+    /// Ground under each float relative to the crane, same order
+    pub outrigger_ground_elevation_m: [f32; 4],
+    /// Level the carrier on its jacks (otherwise equal strokes)
+    pub level_on_jacks: bool,
     pub counterweight_slabs: usize,
 
     // Load configuration
@@ -19,11 +32,37 @@ pub struct UiState {
     pub load_height_m: f32,
     pub num_pick_points: usize,
 
+    // Lift playback
+    pub playback_steps: Vec<CraneState>,
+    pub playback_step_duration_s: f32,
+    pub playback_rigging_height_m: f32,
+    pub playback_message: Option<String>,
+
+    // Load chart check
+    pub chart_check_path: String,
+    pub chart_check_title: Option<String>,
+    pub chart_diagnostics: Vec<ChartDiagnostic>,
+
+    // Lift plan file
+    pub lift_plan_path: String,
+    pub lift_plan_message: Option<String>,
+    pub lift_plan_warnings: Vec<PlanWarning>,
+
+    // Range diagram
+    pub range_svg_path: String,
+    pub range_message: Option<String>,
+    /// Diagram for the settings in `range_diagram_key`
+    pub range_diagram: Option<RangeDiagram>,
+    pub range_diagram_key: String,
+
     // UI state
     pub show_crane_panel: bool,
     pub show_load_panel: bool,
     pub show_analysis_panel: bool,
     pub show_scene_controls: bool,
+    pub show_playback_panel: bool,
+    pub show_chart_check_panel: bool,
+    pub show_range_panel: bool,
     pub show_crane_selector: bool,
     pub show_main_menu: bool,
     pub viewport_width: f32,
@@ -37,6 +76,12 @@ pub struct UiState {
     pub show_pick_points: bool,
     pub show_cog: bool,
     pub show_ground_pressure: bool,
+    /// Ground rings where the load can be placed
+    pub show_capacity_contour: bool,
+    /// Settings the ground contour was last built for
+    pub capacity_contour_key: String,
+    /// Units lengths, weights and pressures are shown in
+    pub units: UnitSystem,
 
     // Flags
     pub needs_recalculation: bool,
@@ -46,13 +91,18 @@ pub struct UiState {
 impl Default for UiState {
     fn default() -> Self {
         Self {
+            library: SpecRegistry::load_default(),
+            library_generation: 0,
+
             // Crane defaults (Liebherr LTM 1100)
             selected_crane_id: "liebherr_ltm_1100_5_2".to_string(),
             boom_length_m: 35.0,
             boom_angle_deg: 65.0,
             swing_angle_deg: 0.0,
             hoist_length_m: 12.0,
-            outrigger_extension_pct: 100.0,
+            outrigger_extension_pct: [100.0; 4],
+            outrigger_ground_elevation_m: [0.0; 4],
+            level_on_jacks: true,
             counterweight_slabs: 8,
 
             // Load defaults
@@ -62,11 +112,36 @@ impl Default for UiState {
             load_height_m: 1.2,
             num_pick_points: 4,
 
+            // Playback defaults
+            playback_steps: Vec::new(),
+            playback_step_duration_s: 10.0,
+            playback_rigging_height_m: 3.0,
+            playback_message: None,
+
             // UI panels
+            // Load chart check
+            chart_check_path: "assets/load_charts/liebherr_ltm_1100.csv".to_string(),
+            chart_check_title: None,
+            chart_diagnostics: Vec::new(),
+
+            // Lift plan file
+            lift_plan_path: "lift_plan.json".to_string(),
+            lift_plan_message: None,
+            lift_plan_warnings: Vec::new(),
+
+            // Range diagram
+            range_svg_path: "range_diagram.svg".to_string(),
+            range_message: None,
+            range_diagram: None,
+            range_diagram_key: String::new(),
+
             show_crane_panel: true,
             show_load_panel: true,
             show_analysis_panel: true,
             show_scene_controls: false,
+            show_playback_panel: false,
+            show_chart_check_panel: false,
+            show_range_panel: false,
             show_crane_selector: false,
 
             // Display toggles
@@ -77,6 +152,9 @@ impl Default for UiState {
             show_pick_points: true,
             show_cog: true,
             show_ground_pressure: false,
+            show_capacity_contour: false,
+            capacity_contour_key: String::new(),
+            units: UnitSystem::Metric,
             show_main_menu: false,
             viewport_width: 1280.0,
             viewport_height: 760.0,
@@ -99,11 +177,159 @@ impl UiState {
         self.needs_crane_respawn = true;
     }
 
-    /// Get currently selected crane spec
-    pub fn get_selected_crane_spec(&self) -> CraneSpec {
-        CraneSpec::all_specs()
+    /// Get currently selected crane spec, if the library has it
+    pub fn get_selected_crane_spec(&self) -> Option<CraneSpec> {
+        self.library.get(&self.selected_crane_id).cloned()
+    }
+
+    /// Why the selected crane can't be shown, for panels to report
+    pub fn missing_crane_message(&self) -> String {
+        format!(
+            "Crane '{}' is not in the library at {} (set {} to another directory)",
+            self.selected_crane_id,
+            self.library.dir().display(),
+            SPEC_DIR_ENV
+        )
+    }
+
+    /// Switch crane model, keeping the sliders inside its ranges
+    pub fn select_crane(&mut self, spec: &CraneSpec) {
+        if spec.id == self.selected_crane_id {
+            return;
+        }
+        self.selected_crane_id = spec.id.clone();
+        self.boom_length_m = self
+            .boom_length_m
+            .clamp(spec.boom_length_range.0, spec.boom_length_range.1);
+        self.boom_angle_deg = self
+            .boom_angle_deg
+            .clamp(spec.min_boom_angle_deg, spec.max_boom_angle_deg);
+        self.hoist_length_m = self
+            .hoist_length_m
+            .clamp(spec.hoist_length_range.0, spec.hoist_length_range.1);
+        self.counterweight_slabs = self.counterweight_slabs.min(spec.counterweight_max_slabs);
+        self.mark_crane_respawn();
+        self.mark_dirty();
+    }
+
+    /// Pick up added, removed or edited spec files
+    pub fn reload_library_if_changed(&mut self) -> bool {
+        if !self.library.reload_if_changed() {
+            return false;
+        }
+        self.library_generation += 1;
+        self.mark_crane_respawn();
+        self.mark_dirty();
+        true
+    }
+
+    /// Extension slider for one leg
+    pub fn outrigger_extension_mut(&mut self, position: OutriggerPosition) -> &mut f32 {
+        let index = OutriggerPosition::all()
+            .iter()
+            .position(|p| *p == position)
+            .unwrap_or(0);
+        &mut self.outrigger_extension_pct[index]
+    }
+
+    /// Ground elevation entry for one leg
+    pub fn outrigger_ground_elevation_mut(&mut self, position: OutriggerPosition) -> &mut f32 {
+        let index = OutriggerPosition::all()
+            .iter()
+            .position(|p| *p == position)
+            .unwrap_or(0);
+        &mut self.outrigger_ground_elevation_m[index]
+    }
+
+    /// Set every leg to the same extension
+    pub fn set_all_outrigger_extensions(&mut self, pct: f32) {
+        self.outrigger_extension_pct = [pct; 4];
+    }
+
+    /// Crane configuration from the current slider values
+    ///
+    /// Legs below their minimum span are left retracted. With levelling on,
+    /// jack strokes and cribbing are solved for the ground under each float.
+    /// None when the selected crane is missing from the library.
+    pub fn build_crane_configuration(&self) -> Option<CraneConfiguration> {
+        let mut config = CraneConfiguration::new(self.get_selected_crane_spec()?);
+        config.boom_length_m = self.boom_length_m;
+        config.boom_angle_deg = self.boom_angle_deg;
+        config.swing_angle_deg = self.swing_angle_deg;
+        config.hoist_length_m = self.hoist_length_m;
+        let _ = config
+            .counterweight
+            .set_slab_count(self.counterweight_slabs);
+
+        for (position, pct) in OutriggerPosition::all()
             .into_iter()
-            .find(|spec| spec.id == self.selected_crane_id)
-            .unwrap_or_else(|| CraneSpec::liebherr_ltm_1100())
+            .zip(self.outrigger_extension_pct)
+        {
+            let _ = config.outriggers.set_extension_pct(position, pct);
+        }
+
+        for (position, elevation) in OutriggerPosition::all()
+            .into_iter()
+            .zip(self.outrigger_ground_elevation_m)
+        {
+            config.outriggers.set_ground_elevation(position, elevation);
+        }
+        if self.level_on_jacks && config.outriggers.all_deployed() {
+            let _ = config.level_outriggers();
+        }
+
+        Some(config)
+    }
+
+    /// Everything the configuration and load depend on, to tell when
+    /// derived views are stale
+    pub fn configuration_key(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {:?} {:?} {} {} {}",
+            self.library_generation,
+            self.selected_crane_id,
+            self.boom_length_m,
+            self.boom_angle_deg,
+            self.swing_angle_deg,
+            self.hoist_length_m,
+            self.outrigger_extension_pct,
+            self.outrigger_ground_elevation_m,
+            self.level_on_jacks,
+            self.counterweight_slabs,
+            self.load_weight_kg
+        )
+    }
+
+    /// Load a saved plan into the sliders, keeping its chart warnings
+    pub fn apply_lift_plan(&mut self, plan: &LiftPlan) {
+        let Some(spec) = self.library.get(&plan.crane_id).cloned() else {
+            self.lift_plan_message = Some(format!("Unknown crane '{}'", plan.crane_id));
+            self.lift_plan_warnings.clear();
+            return;
+        };
+
+        if spec.id != self.selected_crane_id {
+            self.selected_crane_id = spec.id.clone();
+            self.mark_crane_respawn();
+        }
+        let (config, warnings) = plan.restore(spec);
+
+        self.boom_length_m = config.boom_length_m;
+        self.boom_angle_deg = config.boom_angle_deg;
+        self.swing_angle_deg = config.swing_angle_deg;
+        self.hoist_length_m = config.hoist_length_m;
+        self.counterweight_slabs = config.counterweight.get_slab_count();
+        self.load_weight_kg = plan.load_kg;
+        for (index, position) in OutriggerPosition::all().into_iter().enumerate() {
+            self.outrigger_extension_pct[index] =
+                config.outriggers.extension_pct(position).unwrap_or(0.0);
+            if let Some(outrigger) = config.outriggers.get_outrigger(position) {
+                self.outrigger_ground_elevation_m[index] = outrigger.ground_elevation_m;
+            }
+        }
+
+        self.lift_plan_message = Some(format!("Opened '{}'", plan.name));
+        self.lift_plan_warnings = warnings;
+        self.mark_dirty();
     }
 }